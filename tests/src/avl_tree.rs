@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, HashMap};
+
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+/// Checks the AVL invariant at every node, returning the height of the tree.
+///
+/// The postorder traversal guarantees that both children of a node have been
+/// visited (and their heights recorded) before the node itself.
+fn assert_balanced<K, V>(tree: &AvlTree<K, V>) -> usize {
+    let root = match tree.root() {
+        Some(root) => root,
+        None => return 0,
+    };
+
+    let mut heights: HashMap<Vec<usize>, usize> = HashMap::new();
+    let mut iter = root.dfs_postorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        let mut child_path = context.path().to_vec();
+        child_path.push(0);
+        let left = heights.remove(&child_path).unwrap_or_default();
+        *child_path.last_mut().unwrap() = 1;
+        let right = heights.remove(&child_path).unwrap_or_default();
+
+        assert!(
+            left.abs_diff(right) <= 1,
+            "node at {:?} is unbalanced: {} vs {}",
+            context.path(),
+            left,
+            right
+        );
+        heights.insert(context.path().to_vec(), 1 + left.max(right));
+    }
+
+    assert_eq!(1, heights.len());
+    heights.into_values().next().unwrap()
+}
+
+fn assert_matches<K, V>(expected: &BTreeMap<K, V>, tree: &AvlTree<K, V>)
+where
+    K: Ord + std::fmt::Debug,
+    V: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(expected.len(), tree.len());
+    assert_eq!(expected.is_empty(), tree.is_empty());
+    assert_eq!(
+        expected.iter().collect::<Vec<_>>(),
+        tree.root()
+            .map(|root| root.dfs_inorder_iter().collect::<Vec<_>>())
+            .unwrap_or_default()
+    );
+}
+
+/// The maximum height of an AVL tree with `len` nodes.
+fn max_avl_height(len: usize) -> usize {
+    (1.45 * ((len + 2) as f64).log2()) as usize
+}
+
+#[test]
+fn sequential_inserts_stay_balanced() {
+    let mut tree = AvlTree::new();
+    let mut expected = BTreeMap::new();
+    for i in 0..1000 {
+        assert_eq!(expected.insert(i, i * 2), tree.insert(i, i * 2));
+        let height = assert_balanced(&tree);
+        assert!(height <= max_avl_height(tree.len()));
+    }
+    assert_matches(&expected, &tree);
+
+    for i in (0..1000).rev() {
+        assert_eq!(expected.insert(i, i), tree.insert(i, i));
+    }
+    assert_eq!(1000, tree.len());
+    assert_matches(&expected, &tree);
+}
+
+#[test]
+fn mixed_inserts_and_removes_stay_balanced() {
+    let mut state = 12345_u64;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (state >> 33) % 500
+    };
+
+    let mut tree = AvlTree::new();
+    let mut expected = BTreeMap::new();
+    for _ in 0..3000 {
+        let key = next();
+        if next() % 3 == 0 {
+            assert_eq!(expected.remove(&key), tree.remove(&key));
+        } else {
+            assert_eq!(expected.insert(key, key), tree.insert(key, key));
+        }
+        let height = assert_balanced(&tree);
+        assert!(height <= max_avl_height(tree.len()));
+    }
+    assert_matches(&expected, &tree);
+
+    for key in 0..500 {
+        assert_eq!(expected.get(&key), tree.get(&key));
+        assert_eq!(expected.contains_key(&key), tree.contains_key(&key));
+    }
+
+    let keys = expected.keys().copied().collect::<Vec<_>>();
+    for key in keys {
+        assert_eq!(expected.remove_entry(&key), tree.remove_entry(&key));
+        assert_balanced(&tree);
+    }
+    assert!(tree.is_empty());
+    assert!(tree.root().is_none());
+}
+
+#[test]
+fn avl_nodes_work_with_binary_tree_apis() {
+    let mut tree = ["d", "b", "f", "a", "c", "e", "g"]
+        .into_iter()
+        .map(|key| (key.to_string(), key.len()))
+        .collect::<AvlTree<_, _>>();
+
+    for (key, value) in tree.root_mut().unwrap().dfs_preorder_iter_mut() {
+        *value = key.as_bytes()[0] as usize;
+    }
+    *tree.get_mut("a").unwrap() += 100;
+    assert_eq!(Some(&197), tree.get("a"));
+
+    let root = tree.root().unwrap();
+    assert_eq!("d", root.key());
+    assert_eq!(
+        vec!["d", "b", "f", "a", "c", "e", "g"],
+        root.bfs_iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let pruned = root.prune_ref(|(key, _)| key.as_str() == "b").unwrap();
+    assert_eq!(
+        vec!["d", "f", "e", "g"],
+        pruned
+            .dfs_preorder_iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let sum = root.fold_ref(|children: [Option<usize>; 2], (_, value)| {
+        children.into_iter().flatten().sum::<usize>() + value
+    });
+    assert_eq!((b'a'..=b'g').map(usize::from).sum::<usize>() + 100, sum);
+    assert_eq!(2, root.height());
+}
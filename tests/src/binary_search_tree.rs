@@ -0,0 +1,178 @@
+use std::collections::BTreeSet;
+use streaming_iterator::StreamingIterator;
+
+use tree_iterators_rs::prelude::*;
+
+/// A deterministic sequence of values with plenty of duplicates.
+fn pseudo_random_values() -> Vec<usize> {
+    let mut state = 17_usize;
+    (0..200)
+        .map(|_| {
+            state = (state * 31 + 7) % 101;
+            state
+        })
+        .collect()
+}
+
+fn inorder(tree: &BinaryTree<usize>) -> Vec<usize> {
+    tree.dfs_inorder_iter().copied().collect()
+}
+
+#[test]
+fn insert_and_remove_match_btree_set() {
+    let values = pseudo_random_values();
+    let mut expected = BTreeSet::new();
+    expected.insert(50);
+    let mut tree = BinaryTree {
+        value: 50,
+        left: None,
+        right: None,
+    };
+
+    for value in values.iter().copied() {
+        assert_eq!(expected.insert(value), tree.insert(value));
+    }
+    assert!(tree.is_valid_bst());
+    assert_eq!(expected.iter().copied().collect::<Vec<_>>(), inorder(&tree));
+
+    let mut tree = Some(tree);
+    for &value in values.iter().rev().step_by(3) {
+        let (remaining, removed) = tree.unwrap().remove(&value);
+        assert_eq!(expected.take(&value), removed);
+        let remaining = remaining.unwrap();
+        assert!(remaining.is_valid_bst());
+        assert_eq!(
+            expected.iter().copied().collect::<Vec<_>>(),
+            inorder(&remaining)
+        );
+        tree = Some(remaining);
+    }
+
+    let mut tree = tree.unwrap();
+    for value in 0..=101 {
+        assert_eq!(expected.contains(&value), tree.contains(&value));
+        assert_eq!(expected.get(&value), tree.get(&value));
+    }
+
+    for value in expected.clone() {
+        let (remaining, removed) = tree.remove(&value);
+        assert_eq!(Some(value), removed);
+        expected.remove(&value);
+        match remaining {
+            Some(remaining) => tree = remaining,
+            None => {
+                assert!(expected.is_empty());
+                return;
+            }
+        }
+    }
+    panic!("removing every value should empty the tree");
+}
+
+#[test]
+fn range_matches_btree_set() {
+    let expected = pseudo_random_values().into_iter().collect::<BTreeSet<_>>();
+    let tree = BinaryTree::from_sorted_iter(expected.iter().copied()).unwrap();
+
+    for start in [0, 10, 37, 100] {
+        for end in [0, 11, 40, 101, 200] {
+            if start > end {
+                continue;
+            }
+
+            assert_eq!(
+                expected.range(start..end).collect::<Vec<_>>(),
+                tree.range(start..end).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                expected.range(start..=end).collect::<Vec<_>>(),
+                tree.range(start..=end).collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(
+            expected.range(start..).collect::<Vec<_>>(),
+            tree.range(start..).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected.range(..start).collect::<Vec<_>>(),
+            tree.range(..start).collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(
+        expected.iter().collect::<Vec<_>>(),
+        tree.range::<usize, _>(..).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn from_sorted_iter_is_balanced() {
+    assert!(BinaryTree::<usize>::from_sorted_iter(Vec::new()).is_none());
+
+    for len in 1..70_usize {
+        let tree = BinaryTree::from_sorted_iter(0..len).unwrap();
+        assert!(tree.is_valid_bst());
+        assert_eq!((0..len).collect::<Vec<_>>(), inorder(&tree));
+        assert_eq!(len.ilog2() as usize, tree.height());
+        assert_eq!(&0, tree.min());
+        assert_eq!(&(len - 1), tree.max());
+    }
+}
+
+#[test]
+fn successor_and_predecessor_walk_inorder() {
+    let tree = BinaryTree::from_sorted_iter(0..20).unwrap();
+    let mut paths = Vec::new();
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        paths.push((
+            **context.ancestors().last().unwrap(),
+            context.path().to_vec(),
+        ));
+    }
+    paths.sort();
+
+    for (i, (_, path)) in paths.iter().enumerate() {
+        assert_eq!(
+            paths.get(i + 1).map(|(_, path)| path.clone()),
+            tree.successor(path)
+        );
+        assert_eq!(
+            i.checked_sub(1).map(|i| paths[i].1.clone()),
+            tree.predecessor(path)
+        );
+    }
+
+    assert_eq!(None, tree.successor(&[0, 0, 0, 0, 0, 0]));
+    assert_eq!(None, tree.predecessor(&[2]));
+}
+
+#[test]
+fn is_valid_bst_checks_every_ancestor() {
+    // 5 is in the left subtree of 4, so this is not a valid BST even though
+    // every parent/child pair is ordered correctly.
+    let tree = BinaryTree {
+        value: 4,
+        left: Some(Box::new(BinaryTree {
+            value: 2,
+            left: None,
+            right: Some(Box::new(BinaryTree {
+                value: 5,
+                left: None,
+                right: None,
+            })),
+        })),
+        right: None,
+    };
+    assert!(!tree.is_valid_bst());
+
+    let duplicate = BinaryTree {
+        value: 1,
+        left: None,
+        right: Some(Box::new(BinaryTree {
+            value: 1,
+            left: None,
+            right: None,
+        })),
+    };
+    assert!(!duplicate.is_valid_bst());
+}
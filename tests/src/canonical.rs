@@ -0,0 +1,220 @@
+use std::collections::hash_map::RandomState;
+
+use tree_iterators_rs::prelude::*;
+
+use super::create_tree_for_testing;
+
+fn leaf<T>(value: T) -> Tree<T> {
+    Tree {
+        value,
+        children: Vec::new(),
+    }
+}
+
+/// Reverses the order of the children of every node in the tree.
+fn mirror<T>(tree: Tree<T>) -> Tree<T> {
+    tree.fold(|mut children, value| {
+        children.reverse();
+        Tree { value, children }
+    })
+}
+
+#[test]
+fn canonicalizing_ignores_child_order() {
+    let tree = create_tree_for_testing();
+    let mirrored = mirror(create_tree_for_testing());
+    assert_ne!(tree, mirrored);
+
+    let canonical = tree.clone().canonicalize_by(|left, right| left.cmp(right));
+    assert_eq!(canonical, tree.clone());
+    assert_eq!(
+        canonical,
+        mirrored
+            .clone()
+            .canonicalize_by(|left, right| left.cmp(right))
+    );
+    assert_eq!(
+        mirrored
+            .clone()
+            .canonicalize_by(|left, right| right.cmp(left)),
+        tree.canonicalize_by_ref(|left, right| right.cmp(left))
+            .map(|value| *value)
+    );
+}
+
+#[test]
+fn canonicalizing_breaks_ties_by_children() {
+    let tree = Tree {
+        value: 0,
+        children: vec![
+            Tree {
+                value: 1,
+                children: vec![leaf(3), leaf(2)],
+            },
+            Tree {
+                value: 1,
+                children: vec![leaf(2)],
+            },
+            Tree {
+                value: 1,
+                children: vec![leaf(2), leaf(2)],
+            },
+        ],
+    };
+
+    assert_eq!(
+        Tree {
+            value: 0,
+            children: vec![
+                Tree {
+                    value: 1,
+                    children: vec![leaf(2)],
+                },
+                Tree {
+                    value: 1,
+                    children: vec![leaf(2), leaf(2)],
+                },
+                Tree {
+                    value: 1,
+                    children: vec![leaf(2), leaf(3)],
+                },
+            ],
+        },
+        tree.canonicalize_by(|left, right| left.cmp(right))
+    );
+}
+
+#[test]
+fn unordered_eq_matches_canonical_forms() {
+    let tree = create_tree_for_testing();
+    let mirrored = mirror(create_tree_for_testing());
+    assert!(tree.unordered_eq(&mirrored));
+    assert!(mirrored.unordered_eq(&tree));
+
+    let mut changed = mirror(create_tree_for_testing());
+    changed.children[0].value = 100;
+    assert!(!tree.unordered_eq(&changed));
+
+    let mut moved = create_tree_for_testing();
+    let node = moved.children[0].children.pop().unwrap();
+    moved.children[1].children.push(node);
+    assert!(!tree.unordered_eq(&moved));
+
+    let refs = tree.map_ref(|value| value);
+    assert!(refs.unordered_eq(&mirrored.map_ref(|value| value)));
+}
+
+#[test]
+fn unordered_eq_works_on_wide_trees() {
+    let star = |leaves: Vec<usize>| Tree {
+        value: 0,
+        children: vec![Tree {
+            value: 1,
+            children: leaves.into_iter().map(leaf).collect(),
+        }],
+    };
+    let tree = star((0..100_000).collect());
+    let reversed = star((0..100_000).rev().collect());
+    let different = star((1..100_001).collect());
+
+    assert!(tree.unordered_eq(&reversed));
+    assert!(!tree.unordered_eq(&different));
+}
+
+#[test]
+fn unordered_hashes_and_encodings_ignore_child_order() {
+    let build_hasher = RandomState::new();
+    let tree = create_tree_for_testing();
+    let mirrored = mirror(create_tree_for_testing());
+
+    assert_eq!(
+        tree.unordered_hash(&build_hasher),
+        mirrored.unordered_hash(&build_hasher)
+    );
+    assert_eq!(tree.ahu_encoding(), mirrored.ahu_encoding());
+
+    let relabeled = create_tree_for_testing().map(|value| value * 2);
+    assert_ne!(
+        tree.unordered_hash(&build_hasher),
+        relabeled.unordered_hash(&build_hasher)
+    );
+    assert_eq!(tree.ahu_encoding(), relabeled.ahu_encoding());
+
+    let mut reshaped = create_tree_for_testing();
+    let node = reshaped.children[0].children.pop().unwrap();
+    reshaped.children[1].children.push(node);
+    assert_ne!(
+        tree.unordered_hash(&build_hasher),
+        reshaped.unordered_hash(&build_hasher)
+    );
+    assert_ne!(tree.ahu_encoding(), reshaped.ahu_encoding());
+}
+
+/// Builds the AHU encoding by sorting the encodings of each node's children.
+fn reference_ahu_encoding<T>(tree: &Tree<T>) -> String {
+    let mut children = tree
+        .children
+        .iter()
+        .map(reference_ahu_encoding)
+        .collect::<Vec<_>>();
+    children.sort();
+    format!("({})", children.concat())
+}
+
+#[test]
+fn ahu_encodings_sort_children_by_their_encodings() {
+    // siblings of different heights, and siblings where one's children are a
+    // prefix of the other's.
+    let trees = [
+        create_tree_for_testing(),
+        Tree {
+            value: 0,
+            children: vec![
+                Tree {
+                    value: 0,
+                    children: vec![leaf(0), leaf(0), leaf(0)],
+                },
+                leaf(0),
+                Tree {
+                    value: 0,
+                    children: vec![leaf(0), leaf(0)],
+                },
+                Tree {
+                    value: 0,
+                    children: vec![Tree {
+                        value: 0,
+                        children: vec![leaf(0)],
+                    }],
+                },
+            ],
+        },
+    ];
+
+    for tree in &trees {
+        assert_eq!(reference_ahu_encoding(tree), tree.ahu_encoding());
+    }
+}
+
+#[test]
+fn canonical_forms_work_on_deep_trees() {
+    let deep_tree = |reversed: bool| {
+        let mut tree = leaf(0);
+        for value in 1..5000 {
+            let mut children = vec![tree, leaf(value % 3)];
+            if reversed {
+                children.reverse();
+            }
+            tree = Tree { value, children };
+        }
+        tree
+    };
+
+    let tree = deep_tree(false);
+    let reversed = deep_tree(true);
+    assert_eq!(
+        tree.canonicalize_by_ref(|left, right| left.cmp(right)),
+        reversed.canonicalize_by_ref(|left, right| left.cmp(right))
+    );
+    assert_eq!(tree.ahu_encoding(), reversed.ahu_encoding());
+    assert_eq!(2 * tree.size(), tree.ahu_encoding().len());
+}
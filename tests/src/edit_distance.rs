@@ -0,0 +1,176 @@
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+fn leaf<T>(value: T) -> Tree<T> {
+    Tree {
+        value,
+        children: Vec::new(),
+    }
+}
+
+fn node<T>(value: T, children: Vec<Tree<T>>) -> Tree<T> {
+    Tree { value, children }
+}
+
+/// Checks that the result accounts for every node exactly once, that its
+/// mapping keeps both ancestry and sibling order intact, and that the costs of
+/// its operations add up to the reported distance.
+fn assert_consistent<T: PartialEq>(left: &Tree<T>, right: &Tree<T>, result: &TreeEditDistance) {
+    let renames = result
+        .mapping
+        .iter()
+        .filter(|(left_path, right_path)| {
+            left.at_path_ref(left_path).unwrap().value
+                != right.at_path_ref(right_path).unwrap().value
+        })
+        .count();
+    assert_eq!(
+        result.distance,
+        result.deleted.len() + result.inserted.len() + renames
+    );
+
+    let mut left_paths = result
+        .mapping
+        .iter()
+        .map(|(path, _)| path.clone())
+        .chain(result.deleted.iter().cloned())
+        .collect::<Vec<_>>();
+    left_paths.sort();
+    let mut expected = left.dfs_preorder_iter().attach_context();
+    let mut expected_paths = Vec::new();
+    while let Some(context) = expected.next() {
+        expected_paths.push(context.path().to_vec());
+    }
+    assert_eq!(expected_paths, left_paths);
+    assert_eq!(left.size(), left_paths.len());
+    assert_eq!(right.size(), result.mapping.len() + result.inserted.len());
+
+    for (left_a, right_a) in &result.mapping {
+        for (left_b, right_b) in &result.mapping {
+            assert_eq!(left_b.starts_with(left_a), right_b.starts_with(right_a));
+            assert_eq!(left_a < left_b, right_a < right_b);
+        }
+    }
+}
+
+#[test]
+fn identical_trees_have_no_distance() {
+    let tree = create_tree_for_testing();
+    let result = tree.tree_edit_distance(&create_tree_for_testing(), &EditCosts::new());
+    assert_eq!(0, result.distance);
+    assert!(result.deleted.is_empty());
+    assert!(result.inserted.is_empty());
+    assert!(result
+        .mapping
+        .iter()
+        .all(|(left_path, right_path)| left_path == right_path));
+    assert_consistent(&tree, &tree, &result);
+}
+
+#[test]
+fn matches_the_zhang_shasha_example() {
+    let left = node(
+        'f',
+        vec![
+            node('d', vec![leaf('a'), node('c', vec![leaf('b')])]),
+            leaf('e'),
+        ],
+    );
+    let right = node(
+        'f',
+        vec![
+            node('c', vec![node('d', vec![leaf('a'), leaf('b')])]),
+            leaf('e'),
+        ],
+    );
+
+    let result = left.tree_edit_distance(&right, &EditCosts::new());
+    assert_eq!(2, result.distance);
+    assert_eq!(vec![vec![0, 1]], result.deleted);
+    assert_eq!(vec![vec![0]], result.inserted);
+    assert_consistent(&left, &right, &result);
+
+    let reversed = right.tree_edit_distance(&left, &EditCosts::new());
+    assert_eq!(2, reversed.distance);
+    assert_consistent(&right, &left, &reversed);
+}
+
+#[test]
+fn unit_costs_are_consistent_across_many_edits() {
+    let tree = create_tree_for_testing();
+    let edits = [
+        leaf(0),
+        node(0, vec![leaf(1), leaf(2)]),
+        create_tree_for_testing().map(|value| value % 4),
+        create_tree_for_testing().fold(|mut children, value| {
+            children.reverse();
+            Tree { value, children }
+        }),
+        node(
+            100,
+            vec![create_tree_for_testing(), create_tree_for_testing()],
+        ),
+    ];
+
+    for edited in edits {
+        let result = tree.tree_edit_distance(&edited, &EditCosts::new());
+        assert_consistent(&tree, &edited, &result);
+        assert_eq!(
+            result.distance,
+            edited.tree_edit_distance(&tree, &EditCosts::new()).distance
+        );
+    }
+
+    let result = tree.tree_edit_distance(&leaf(0), &EditCosts::new());
+    assert_eq!(10, result.distance);
+    assert_eq!(vec![(vec![], vec![])], result.mapping);
+}
+
+#[test]
+fn custom_costs_change_the_cheapest_edit() {
+    let left = node("a", vec![leaf("b")]);
+    let right = node("a", vec![leaf("c")]);
+
+    let result = left.tree_edit_distance(&right, &EditCosts::new());
+    assert_eq!(1, result.distance);
+    assert_eq!(vec![(vec![], vec![]), (vec![0], vec![0])], result.mapping);
+
+    let costs = EditCosts::new()
+        .insert_cost(|_| 2)
+        .delete_cost(|_| 3)
+        .rename_cost(|left: &&&str, right: &&&str| if left == right { 0 } else { 10 });
+    let result = left.tree_edit_distance(&right, &costs);
+    assert_eq!(5, result.distance);
+    assert_eq!(vec![(vec![], vec![])], result.mapping);
+    assert_eq!(vec![vec![0]], result.deleted);
+    assert_eq!(vec![vec![0]], result.inserted);
+
+    let strings = node("a".to_string(), vec![leaf("cc".to_string())]);
+    let lengths = EditCosts::with_rename_cost(|left: &&&str, right: &&String| {
+        left.len().abs_diff(right.len())
+    });
+    assert_eq!(1, left.tree_edit_distance(&strings, &lengths).distance);
+}
+
+#[test]
+fn binary_trees_use_slot_paths() {
+    let tree = create_binary_tree_for_testing();
+    assert_eq!(
+        0,
+        tree.tree_edit_distance(&create_binary_tree_for_testing(), &EditCosts::new())
+            .distance
+    );
+
+    let mut pruned = create_binary_tree_for_testing();
+    pruned.left = None;
+    let result = tree.tree_edit_distance(&pruned, &EditCosts::new());
+    assert_eq!(3, result.distance);
+    assert_eq!(vec![vec![0], vec![0, 0], vec![0, 1]], result.deleted);
+    assert!(result.inserted.is_empty());
+    assert!(result
+        .mapping
+        .iter()
+        .all(|(left_path, right_path)| left_path == right_path));
+}
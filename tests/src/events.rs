@@ -0,0 +1,147 @@
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// Collects every event along with the path the iterator reports for it.
+macro_rules! collect_events {
+    ($iter: expr, $value: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(context) = iter.next() {
+            assert_eq!(context.path().len(), context.depth());
+            let value: usize = $value(context.event().value());
+            let event = if context.event().is_enter() {
+                TreeEvent::Enter(value)
+            } else {
+                TreeEvent::Exit(value)
+            };
+            result.push((event, context.path().to_vec()));
+        }
+        assert!(iter.next().is_none());
+        result
+    }};
+}
+
+/// Collects the value and path of every node in the order the given context
+/// iterator visits them.
+macro_rules! visited {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push((
+                **context.ancestors().last().unwrap(),
+                context.path().to_vec(),
+            ));
+        }
+        result
+    }};
+}
+
+macro_rules! assert_events {
+    ($tree: expr) => {{
+        let mut tree = $tree;
+        let preorder = visited!(tree.dfs_preorder_iter().attach_context());
+        let postorder = visited!(tree.dfs_postorder_iter().attach_context());
+
+        let borrowed = collect_events!(tree.events_iter(), |value: &&usize| **value);
+        let mut_borrowed = collect_events!(tree.events_iter_mut(), |value: &&mut usize| **value);
+        let owned = collect_events!(tree.clone().events(), |value: &usize| *value);
+        assert_eq!(borrowed, mut_borrowed);
+        assert_eq!(borrowed, owned);
+        assert_eq!(preorder.len() * 2, borrowed.len());
+
+        let enters = borrowed
+            .iter()
+            .filter(|(event, _)| event.is_enter())
+            .map(|(event, path)| (*event.value(), path.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(preorder, enters);
+
+        let exits = borrowed
+            .iter()
+            .filter(|(event, _)| event.is_exit())
+            .map(|(event, path)| (*event.value(), path.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(postorder, exits);
+    }};
+}
+
+#[test]
+fn events_follow_preorder_and_postorder() {
+    assert_events!(create_tree_for_testing());
+}
+
+#[test]
+fn binary_events_follow_preorder_and_postorder() {
+    assert_events!(create_binary_tree_for_testing());
+}
+
+#[test]
+fn events_are_balanced() {
+    let tree = create_tree_for_testing();
+    let mut iter = tree.events_iter();
+    let mut open = Vec::new();
+    while let Some(context) = iter.next() {
+        match context.event() {
+            TreeEvent::Enter(value) => {
+                assert_eq!(open.len(), context.depth());
+                open.push(**value);
+            }
+            TreeEvent::Exit(value) => {
+                assert_eq!(Some(**value), open.pop());
+                assert_eq!(open.len(), context.depth());
+            }
+        }
+    }
+    assert!(open.is_empty());
+}
+
+#[test]
+fn changes_on_enter_are_visible_on_exit() {
+    let mut tree = create_binary_tree_for_testing();
+    let mut iter = tree.events_iter_mut();
+    while let Some(context) = iter.next_mut() {
+        match context.event_mut() {
+            TreeEvent::Enter(value) => **value *= 10,
+            TreeEvent::Exit(value) => assert_eq!(0, **value % 10),
+        }
+    }
+    drop(iter);
+    assert_eq!(
+        create_binary_tree_for_testing()
+            .dfs_preorder()
+            .map(|value| value * 10)
+            .collect::<Vec<_>>(),
+        tree.dfs_preorder().collect::<Vec<_>>()
+    );
+
+    let mut iter = create_tree_for_testing().events();
+    let mut result = Vec::new();
+    while let Some(context) = iter.next_mut() {
+        match context.event_mut() {
+            TreeEvent::Enter(value) => *value += 100,
+            TreeEvent::Exit(value) => result.push(*value),
+        }
+    }
+    assert_eq!(
+        create_tree_for_testing()
+            .dfs_postorder()
+            .map(|value| value + 100)
+            .collect::<Vec<_>>(),
+        result
+    );
+}
+
+#[test]
+fn leaf_root_yields_one_enter_and_one_exit() {
+    let tree = Tree {
+        value: 7,
+        children: Vec::new(),
+    };
+    assert_eq!(
+        vec![(TreeEvent::Enter(7), vec![]), (TreeEvent::Exit(7), vec![])],
+        collect_events!(tree.events_iter(), |value: &&usize| **value)
+    );
+}
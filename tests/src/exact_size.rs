@@ -0,0 +1,133 @@
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// Walks the iterator to the end, checking after every step that its
+/// `size_hint` never promises more items than are actually left.
+fn assert_size_hints_are_valid<Iter: Iterator>(mut iter: Iter) {
+    let mut hints = Vec::new();
+    loop {
+        hints.push(iter.size_hint());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+
+    let total = hints.len() - 1;
+    for (yielded, (lower, upper)) in hints.into_iter().enumerate() {
+        let left = total - yielded;
+        assert!(
+            lower <= left,
+            "lower bound {lower} exceeds remaining {left}"
+        );
+        if let Some(upper) = upper {
+            assert!(
+                upper >= left,
+                "upper bound {upper} is below remaining {left}"
+            );
+        }
+    }
+}
+
+/// Checks that an exact size iterator reports the number of items it has
+/// left before every call to next.
+fn assert_exact<Iter: ExactSizeIterator>(mut iter: Iter, expected_len: usize) {
+    for remaining in (0..=expected_len).rev() {
+        assert_eq!(remaining, iter.len());
+        assert_eq!((remaining, Some(remaining)), iter.size_hint());
+        assert_eq!(remaining == 0, iter.next().is_none());
+    }
+}
+
+macro_rules! assert_all_sizes {
+    ($tree: expr, $($iter: ident, $iter_mut: ident, $into_iter: ident),*) => {{
+        let mut tree = $tree;
+        let len = tree.bfs_iter().count();
+        $(
+            assert_size_hints_are_valid(tree.$iter());
+            assert_size_hints_are_valid(tree.$iter_mut());
+            assert_size_hints_are_valid(tree.clone().$into_iter());
+
+            assert_exact(tree.$iter().exact_size(), len);
+            assert_exact(tree.$iter_mut().exact_size(), len);
+            assert_exact(tree.clone().$into_iter().exact_size(), len);
+        )*
+    }};
+}
+
+#[test]
+fn tree_iterators_report_valid_sizes() {
+    assert_all_sizes!(
+        create_tree_for_testing(),
+        bfs_iter,
+        bfs_iter_mut,
+        bfs,
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder,
+        dfs_postorder_iter,
+        dfs_postorder_iter_mut,
+        dfs_postorder
+    );
+}
+
+#[test]
+fn binary_tree_iterators_report_valid_sizes() {
+    assert_all_sizes!(
+        create_binary_tree_for_testing(),
+        bfs_iter,
+        bfs_iter_mut,
+        bfs,
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder,
+        dfs_postorder_iter,
+        dfs_postorder_iter_mut,
+        dfs_postorder,
+        dfs_inorder_iter,
+        dfs_inorder_iter_mut,
+        dfs_inorder
+    );
+}
+
+#[test]
+fn exact_size_collects_the_same_values() {
+    let tree = create_tree_for_testing();
+    assert_eq!(11, tree.subtree_size());
+    assert_eq!(
+        tree.dfs_postorder_iter().collect::<Vec<_>>(),
+        tree.dfs_postorder_iter().exact_size().collect::<Vec<_>>()
+    );
+
+    let binary_tree = create_binary_tree_for_testing();
+    assert_eq!(11, binary_tree.subtree_size());
+    let collected = binary_tree
+        .dfs_inorder_iter()
+        .exact_size()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        binary_tree.dfs_inorder_iter().collect::<Vec<_>>(),
+        collected
+    );
+    assert!(collected.capacity() >= 11);
+}
+
+#[test]
+fn generic_size_hints_are_lower_bounds() {
+    let tree = create_tree_for_testing();
+    assert_eq!((1, None), tree.dfs_preorder_iter().size_hint());
+
+    let mut iter = tree.bfs_iter();
+    iter.next();
+    assert_eq!((2, None), iter.size_hint());
+    assert_eq!(10, iter.count());
+}
+
+#[test]
+#[should_panic]
+fn exact_size_after_next_panics() {
+    let tree = create_tree_for_testing();
+    let mut iter = tree.dfs_preorder_iter();
+    iter.next();
+    let _ = iter.exact_size();
+}
@@ -0,0 +1,172 @@
+use tree_iterators_rs::prelude::*;
+
+use super::create_tree_for_testing;
+
+fn leaf<T>(value: T) -> Tree<T> {
+    Tree {
+        value,
+        children: Vec::new(),
+    }
+}
+
+#[test]
+fn merging_identical_forests_merges_every_node() {
+    let forest = vec![create_tree_for_testing(), leaf(11)];
+    let merged = forest
+        .clone()
+        .map_each(|value| (value, 1))
+        .collect::<Vec<_>>()
+        .merge_forests_by_key(
+            forest.map_each(|value| (value, 1)).collect::<Vec<_>>(),
+            |(value, _)| *value,
+            |(value, left), (_, right)| (value, left + right),
+        );
+
+    let expected = vec![create_tree_for_testing(), leaf(11)]
+        .map_each(|value| (value, 2))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, merged);
+}
+
+#[test]
+fn merging_unifies_siblings_recursively() {
+    let left = vec![Tree {
+        value: 'a',
+        children: vec![
+            Tree {
+                value: 'b',
+                children: vec![leaf('c')],
+            },
+            leaf('b'),
+        ],
+    }];
+    let right = vec![
+        leaf('x'),
+        Tree {
+            value: 'a',
+            children: vec![Tree {
+                value: 'b',
+                children: vec![leaf('d'), leaf('c')],
+            }],
+        },
+    ];
+
+    let merged = left.merge_forests_by_key(right, |value| *value, |left, _| left);
+    assert_eq!(
+        vec![
+            Tree {
+                value: 'a',
+                children: vec![Tree {
+                    value: 'b',
+                    children: vec![leaf('c'), leaf('d')],
+                }],
+            },
+            leaf('x'),
+        ],
+        merged
+    );
+}
+
+#[test]
+fn flatten_and_group_round_trip() {
+    let forest = vec![
+        create_tree_for_testing(),
+        leaf(11),
+        create_tree_for_testing(),
+    ];
+    let flattened = forest.clone().flatten_forest();
+    assert_eq!(
+        forest.clone().dfs_preorder_each().collect::<Vec<_>>(),
+        flattened
+            .iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>()
+    );
+
+    let regrouped = flattened
+        .into_iter()
+        .enumerate()
+        .map(|(index, (parent, value))| leaf((index, parent, value)))
+        .collect::<Vec<_>>()
+        .group_into_tree(|(index, _, _)| *index, |(_, parent, _)| *parent)
+        .map_each(|(_, _, value)| value)
+        .collect::<Vec<_>>();
+    assert_eq!(forest, regrouped);
+}
+
+#[test]
+fn group_into_tree_handles_orphans_and_cycles() {
+    // (key, parent key)
+    let rows = vec![
+        (1, Some(3)),
+        (2, Some(1)),
+        (3, Some(2)),
+        (4, Some(99)),
+        (5, Some(4)),
+        (6, Some(6)),
+    ];
+
+    let grouped = rows
+        .into_iter()
+        .map(leaf)
+        .collect::<Vec<_>>()
+        .group_into_tree(|(key, _)| *key, |(_, parent)| *parent)
+        .map_each(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            Tree {
+                value: 4,
+                children: vec![leaf(5)],
+            },
+            leaf(6),
+            Tree {
+                value: 1,
+                children: vec![Tree {
+                    value: 2,
+                    children: vec![leaf(3)],
+                }],
+            },
+        ],
+        grouped
+    );
+}
+
+fn value_at<T: OwnedTreeNode>(tree: T, path: &[usize]) -> Option<T::OwnedValue> {
+    tree.at_path(path)
+        .map(|node| node.get_value_and_children().0)
+}
+
+#[test]
+fn as_single_tree_matches_forest_paths() {
+    let mut forest = vec![create_tree_for_testing(), leaf(11)];
+    assert_eq!(Some(&11), value_at(forest.as_single_tree_ref(&100), &[1]));
+    assert_eq!(
+        forest.at_path_ref(&[0, 1, 1]).map(|node| &node.value),
+        value_at(forest.as_single_tree_ref(&100), &[0, 1, 1])
+    );
+    assert_eq!(
+        forest.dfs_preorder_each_iter().count() + 1,
+        forest.as_single_tree_ref(&100).dfs_preorder().count()
+    );
+
+    let mut root = 100;
+    for value in forest.as_single_tree_mut(&mut root).dfs_preorder() {
+        *value += 1;
+    }
+    assert_eq!(101, root);
+    assert_eq!(Some(&12), forest.at_path_ref(&[1]).map(|node| &node.value));
+
+    let tree = forest.clone().as_single_tree(100);
+    assert_eq!(vec![100, 1, 12], tree.bfs().take(3).collect::<Vec<_>>());
+
+    let tree = forest.clone().as_single_tree(100).map(|value| value);
+    assert_eq!(
+        Tree {
+            value: 100,
+            children: forest
+        },
+        tree
+    );
+}
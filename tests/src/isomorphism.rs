@@ -0,0 +1,316 @@
+use tree_iterators_rs::prelude::{
+    BinaryTree, BorrowedBinaryTreeNode, BorrowedTreeNode, OwnedBinaryTreeNode, OwnedTreeNode, Tree,
+};
+
+use crate::{create_binary_tree_for_testing, create_tree_for_testing};
+
+fn leaf<T>(value: T) -> Tree<T> {
+    Tree {
+        value,
+        children: Vec::new(),
+    }
+}
+
+fn binary_leaf<T>(value: T) -> Option<Box<BinaryTree<T>>> {
+    Some(Box::new(BinaryTree {
+        value,
+        left: None,
+        right: None,
+    }))
+}
+
+fn reverse_children<T>(tree: Tree<T>) -> Tree<T> {
+    tree.fold(|mut children, value| {
+        children.reverse();
+        Tree { value, children }
+    })
+}
+
+fn swap_children<T>(tree: BinaryTree<T>) -> BinaryTree<T> {
+    tree.fold(|[left, right], value| BinaryTree {
+        value,
+        left: right.map(Box::new),
+        right: left.map(Box::new),
+    })
+}
+
+#[test]
+fn tree_is_isomorphic_to_itself() {
+    let tree = create_tree_for_testing();
+    let other = create_tree_for_testing();
+    assert!(tree.is_isomorphic(&other, |a, b| a == b, true));
+    assert!(tree.is_isomorphic(&other, |a, b| a == b, false));
+}
+
+#[test]
+fn tree_reordered_children() {
+    let tree = create_tree_for_testing();
+    let reversed = reverse_children(create_tree_for_testing());
+    assert!(!tree.is_isomorphic(&reversed, |a, b| a == b, true));
+    assert!(tree.is_isomorphic(&reversed, |a, b| a == b, false));
+    assert!(reversed.is_isomorphic(&tree, |a, b| a == b, false));
+}
+
+#[test]
+fn tree_is_isomorphic_across_value_types() {
+    let tree = create_tree_for_testing();
+    let strings = create_tree_for_testing().map(|value| value.to_string());
+    assert!(tree.is_isomorphic(&strings, |a, b| a.to_string() == **b, true));
+    assert!(!tree.is_isomorphic(&strings, |_, b| b.as_str() == "0", true));
+}
+
+#[test]
+fn tree_shape_mismatch() {
+    let tree = create_tree_for_testing();
+    let mut other = create_tree_for_testing();
+    other.children[0].children.pop();
+    assert!(!tree.is_isomorphic(&other, |a, b| a == b, true));
+    assert!(!tree.is_isomorphic(&other, |a, b| a == b, false));
+    assert!(!tree.is_isomorphic(&leaf(0), |a, b| a == b, false));
+
+    // same values and child counts, but the children can't be paired up.
+    let left = Tree {
+        value: 0,
+        children: vec![
+            Tree {
+                value: 1,
+                children: vec![leaf(2)],
+            },
+            leaf(1),
+        ],
+    };
+    let right = Tree {
+        value: 0,
+        children: vec![
+            Tree {
+                value: 1,
+                children: vec![leaf(3)],
+            },
+            leaf(1),
+        ],
+    };
+    assert!(!left.is_isomorphic(&right, |a, b| a == b, false));
+}
+
+#[test]
+fn unordered_matching_requires_distinct_pairs() {
+    let left = Tree {
+        value: 0,
+        children: vec![leaf(1), leaf(1), leaf(2)],
+    };
+    let right = Tree {
+        value: 0,
+        children: vec![leaf(1), leaf(2), leaf(2)],
+    };
+    assert!(!left.is_isomorphic(&right, |a, b| a == b, false));
+
+    // a wildcard forces the matching to reassign an earlier pairing.
+    let pattern = Tree {
+        value: Some(0),
+        children: vec![leaf(None), leaf(Some(1))],
+    };
+    let tree = Tree {
+        value: 0,
+        children: vec![leaf(1), leaf(2)],
+    };
+    let eq = |a: &&usize, b: &&Option<usize>| b.is_none_or(|b| **a == b);
+    assert!(tree.is_isomorphic(&pattern, eq, false));
+    assert!(!tree.is_isomorphic(&pattern, eq, true));
+}
+
+#[test]
+fn tree_find_subtree() {
+    let tree = create_tree_for_testing();
+    let pattern = Tree {
+        value: 2,
+        children: vec![leaf(5)],
+    };
+    assert_eq!(
+        Vec::<Vec<usize>>::new(),
+        tree.find_subtree(&pattern, |a, b| a == b)
+    );
+
+    let pattern = Tree {
+        value: 1,
+        children: vec![leaf(3), leaf(4)],
+    };
+    assert_eq!(vec![vec![0]], tree.find_subtree(&pattern, |a, b| a == b));
+
+    // any chain of 4 nodes.
+    let pattern = Tree {
+        value: (),
+        children: vec![Tree {
+            value: (),
+            children: vec![Tree {
+                value: (),
+                children: vec![leaf(())],
+            }],
+        }],
+    };
+    assert_eq!(
+        vec![vec![1, 1, 0]],
+        tree.find_subtree(&pattern, |_, _| true)
+    );
+
+    let pattern = leaf(());
+    assert_eq!(
+        vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1, 0, 0, 0, 0]],
+        tree.find_subtree(&pattern, |_, _| true)
+    );
+}
+
+#[test]
+fn tree_find_subtree_unordered() {
+    let tree = create_tree_for_testing();
+    let pattern = Tree {
+        value: 1,
+        children: vec![leaf(4), leaf(3)],
+    };
+    assert_eq!(
+        Vec::<Vec<usize>>::new(),
+        tree.find_subtree(&pattern, |a, b| a == b)
+    );
+    assert_eq!(
+        vec![vec![0]],
+        tree.find_subtree_unordered(&pattern, |a, b| a == b)
+    );
+
+    let pattern = Tree {
+        value: (),
+        children: vec![leaf(()), leaf(())],
+    };
+    assert_eq!(
+        vec![vec![0]],
+        tree.find_subtree_unordered(&pattern, |_, _| true)
+    );
+}
+
+#[test]
+fn binary_tree_is_isomorphic() {
+    let tree = create_binary_tree_for_testing();
+    let other = create_binary_tree_for_testing();
+    assert!(tree.is_isomorphic(&other, |a, b| a == b, true));
+    assert!(tree.is_isomorphic(&other, |a, b| a == b, false));
+
+    let swapped = swap_children(create_binary_tree_for_testing());
+    assert!(!tree.is_isomorphic(&swapped, |a, b| a == b, true));
+    assert!(tree.is_isomorphic(&swapped, |a, b| a == b, false));
+
+    let mut other = create_binary_tree_for_testing();
+    other.right.as_mut().unwrap().left = None;
+    assert!(!tree.is_isomorphic(&other, |a, b| a == b, true));
+    assert!(!tree.is_isomorphic(&other, |a, b| a == b, false));
+}
+
+#[test]
+fn binary_tree_empty_slots_matter() {
+    let left_only = BinaryTree {
+        value: 0,
+        left: binary_leaf(1),
+        right: None,
+    };
+    let right_only = BinaryTree {
+        value: 0,
+        left: None,
+        right: binary_leaf(1),
+    };
+    assert!(!left_only.is_isomorphic(&right_only, |a, b| a == b, true));
+    assert!(left_only.is_isomorphic(&right_only, |a, b| a == b, false));
+}
+
+#[test]
+fn binary_tree_find_subtree() {
+    let tree = create_binary_tree_for_testing();
+
+    // 7 -> right 8 -> left 9 -> right 10
+    let pattern = BinaryTree {
+        value: 8,
+        left: Some(Box::new(BinaryTree {
+            value: 9,
+            left: None,
+            right: binary_leaf(10),
+        })),
+        right: None,
+    };
+    assert_eq!(
+        vec![vec![1, 1, 0, 1]],
+        tree.find_subtree(&pattern, |a, b| a == b)
+    );
+
+    let flipped = BinaryTree {
+        value: 8,
+        left: None,
+        right: Some(Box::new(BinaryTree {
+            value: 9,
+            left: binary_leaf(10),
+            right: None,
+        })),
+    };
+    assert_eq!(
+        Vec::<Vec<usize>>::new(),
+        tree.find_subtree(&flipped, |a, b| a == b)
+    );
+    assert_eq!(
+        vec![vec![1, 1, 0, 1]],
+        tree.find_subtree_unordered(&flipped, |a, b| a == b)
+    );
+
+    let pattern = BinaryTree {
+        value: (),
+        left: binary_leaf(()),
+        right: binary_leaf(()),
+    };
+    assert_eq!(vec![vec![0]], tree.find_subtree(&pattern, |_, _| true));
+}
+
+fn deep_trees(depth: usize) -> (Tree<usize>, BinaryTree<usize>) {
+    let mut tree = leaf(0);
+    let mut binary = BinaryTree {
+        value: 0,
+        left: None,
+        right: None,
+    };
+    for value in 1..depth {
+        tree = Tree {
+            value,
+            children: vec![tree, leaf(value)],
+        };
+        binary = BinaryTree {
+            value,
+            left: Some(Box::new(binary)),
+            right: binary_leaf(value),
+        };
+    }
+    (tree, binary)
+}
+
+#[test]
+fn unordered_isomorphism_works_on_deep_trees() {
+    let (tree, binary) = deep_trees(5000);
+    let (reversed, swapped) = deep_trees(5000);
+    let reversed = reverse_children(reversed);
+    let swapped = swap_children(swapped);
+
+    assert!(tree.is_isomorphic(&reversed, |a, b| a == b, false));
+    assert!(tree.unordered_eq(&reversed));
+    assert!(!tree.is_isomorphic(&reversed, |a, b| a == b && **a != 1, false));
+
+    assert!(binary.is_isomorphic(&swapped, |a, b| a == b, false));
+    assert!(!binary.is_isomorphic(&swapped, |a, b| a == b && **a != 1, false));
+}
+
+#[test]
+fn unordered_isomorphism_works_on_wide_trees() {
+    let star = |leaves: Vec<usize>| Tree {
+        value: 0,
+        children: leaves.into_iter().map(leaf).collect(),
+    };
+    let tree = star((0..100_000).map(|value| value % 3).collect());
+    let reversed = star((0..100_000).rev().map(|value| value % 3).collect());
+    let different = star((1..100_001).map(|value| value % 3).collect());
+
+    assert!(tree.is_isomorphic(&reversed, |a, b| a == b, false));
+    assert!(tree.unordered_eq(&reversed));
+    assert!(!tree.is_isomorphic(&different, |a, b| a == b, false));
+    assert!(!tree.unordered_eq(&different));
+}
@@ -0,0 +1,162 @@
+use serde_json::{json, Value};
+use tree_iterators_rs::prelude::*;
+
+fn document() -> Value {
+    json!({
+        "name": "tree_iterators_rs",
+        "version": 3.7,
+        "offset": -12,
+        "published": true,
+        "license": null,
+        "authors": [{ "name": "Adam", "emails": [] }],
+        "matrix": [[1, 2], [], [3, [4, { "deep": "ünïcode" }]]],
+        "empty": {},
+    })
+}
+
+fn leaf(value: JsonNode) -> Tree<JsonNode> {
+    Tree {
+        value,
+        children: Vec::new(),
+    }
+}
+
+#[test]
+fn round_trips_without_loss() {
+    assert_eq!(Ok(document()), Tree::from_json(document()).into_json());
+
+    for scalar in [json!(null), json!(false), json!(1.5), json!("text")] {
+        assert_eq!(Ok(scalar.clone()), Tree::from_json(scalar).into_json());
+    }
+    assert_eq!(Ok(json!([])), Tree::from_json(json!([])).into_json());
+}
+
+#[test]
+fn documents_have_the_documented_shape() {
+    let tree = Tree::from_json(json!({ "a": [true, null] }));
+    assert_eq!(
+        Tree {
+            value: JsonNode::Object,
+            children: vec![Tree {
+                value: JsonNode::Key("a".to_string()),
+                children: vec![Tree {
+                    value: JsonNode::Array,
+                    children: vec![
+                        Tree {
+                            value: JsonNode::Index(0),
+                            children: vec![leaf(JsonNode::Bool(true))],
+                        },
+                        Tree {
+                            value: JsonNode::Index(1),
+                            children: vec![leaf(JsonNode::Null)],
+                        },
+                    ],
+                }],
+            }],
+        },
+        tree
+    );
+
+    let tree = Tree::from_json(document());
+    let keys = tree
+        .children
+        .iter()
+        .map(|child| match &child.value {
+            JsonNode::Key(key) => key.as_str(),
+            _ => panic!("expected a key"),
+        })
+        .collect::<Vec<_>>();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(sorted, keys);
+
+    let matrix = keys.iter().position(|key| *key == "matrix").unwrap();
+    assert_eq!(
+        Some(&JsonNode::String("ünïcode".to_string())),
+        tree.at_path_ref(&[matrix, 0, 2, 0, 1, 0, 1, 0, 0, 0])
+            .map(|node| &node.value)
+    );
+
+    let strings = tree.fold_ref(|children: Vec<usize>, value| {
+        children.into_iter().sum::<usize>() + usize::from(matches!(value, JsonNode::String(_)))
+    });
+    assert_eq!(3, strings);
+}
+
+#[test]
+fn edits_are_carried_back_into_json() {
+    let edited = Tree::from_json(document())
+        .prune_path(|path, value| {
+            path.len() == 1 && !matches!(value, JsonNode::Key(key) if key == "matrix")
+        })
+        .unwrap()
+        .map(|value| match value {
+            JsonNode::Number(number) => JsonNode::Number((number.as_i64().unwrap() * 10).into()),
+            value => value,
+        });
+    assert_eq!(
+        Ok(json!({ "matrix": [[10, 20], [], [30, [40, { "deep": "ünïcode" }]]] })),
+        edited.into_json()
+    );
+
+    let mut tree = Tree::from_json(json!(["a", "b", "c"]));
+    tree.children.swap(0, 2);
+    tree.children.remove(1);
+    assert_eq!(Ok(json!(["c", "a"])), tree.into_json());
+}
+
+#[test]
+fn malformed_trees_are_rejected() {
+    let entry = |value, children| Tree { value, children };
+    let key = |name: &str| JsonNode::Key(name.to_string());
+
+    let cases = [
+        (
+            entry(key("a"), vec![leaf(JsonNode::Null)]),
+            JsonTreeError::MisplacedEntry,
+        ),
+        (
+            entry(
+                JsonNode::Array,
+                vec![entry(key("a"), vec![leaf(JsonNode::Null)])],
+            ),
+            JsonTreeError::MisplacedEntry,
+        ),
+        (
+            entry(JsonNode::Object, vec![leaf(JsonNode::Null)]),
+            JsonTreeError::MissingEntry,
+        ),
+        (
+            entry(JsonNode::Object, vec![leaf(key("a"))]),
+            JsonTreeError::InvalidEntryValue,
+        ),
+        (
+            entry(
+                JsonNode::Array,
+                vec![entry(
+                    JsonNode::Index(0),
+                    vec![leaf(JsonNode::Null), leaf(JsonNode::Null)],
+                )],
+            ),
+            JsonTreeError::InvalidEntryValue,
+        ),
+        (
+            entry(
+                JsonNode::Object,
+                vec![
+                    entry(key("a"), vec![leaf(JsonNode::Null)]),
+                    entry(key("a"), vec![leaf(JsonNode::Bool(true))]),
+                ],
+            ),
+            JsonTreeError::DuplicateKey,
+        ),
+        (
+            entry(JsonNode::Bool(true), vec![leaf(JsonNode::Null)]),
+            JsonTreeError::ScalarWithChildren,
+        ),
+    ];
+
+    for (tree, error) in cases {
+        assert_eq!(Err(error), tree.into_json());
+    }
+}
@@ -0,0 +1,556 @@
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+use tree_iterators_rs::prelude::*;
+
+fn boxed(tree: KaryTree<usize, 4>) -> Option<Box<KaryTree<usize, 4>>> {
+    Some(Box::new(tree))
+}
+
+/// Creates a quadtree with the following shape, where each edge is labeled
+/// with the slot its child occupies.
+/// ```text
+///          0
+///      [0]/ \[3]
+///        1   2
+///     [1]| [0]/ \[2]
+///        3   4   5
+///                |[3]
+///                6
+/// ```
+fn create_kary_tree_for_testing() -> KaryTree<usize, 4> {
+    let mut five = KaryTree::new(5);
+    five.children[3] = boxed(KaryTree::new(6));
+
+    let mut two = KaryTree::new(2);
+    two.children[0] = boxed(KaryTree::new(4));
+    two.children[2] = boxed(five);
+
+    let mut one = KaryTree::new(1);
+    one.children[1] = boxed(KaryTree::new(3));
+
+    let mut root = KaryTree::new(0);
+    root.children[0] = boxed(one);
+    root.children[3] = boxed(two);
+    root
+}
+
+fn get_expected_path(value: usize) -> &'static [usize] {
+    match value {
+        0 => &[],
+        1 => &[0],
+        2 => &[3],
+        3 => &[0, 1],
+        4 => &[3, 0],
+        5 => &[3, 2],
+        6 => &[3, 2, 3],
+        _ => panic!("unexpected value"),
+    }
+}
+
+fn get_expected_ancestors(value: usize) -> &'static [usize] {
+    match value {
+        0 => &[0],
+        1 => &[0, 1],
+        2 => &[0, 2],
+        3 => &[0, 1, 3],
+        4 => &[0, 2, 4],
+        5 => &[0, 2, 5],
+        6 => &[0, 2, 5, 6],
+        _ => panic!("unexpected value"),
+    }
+}
+
+/// Gets the sibling index and sibling count of each node. Empty slots are not
+/// counted, so 5 is the second of two siblings even though it sits in slot 2.
+fn get_expected_siblings(value: usize) -> (usize, usize) {
+    match value {
+        0 | 3 | 6 => (0, 1),
+        1 | 4 => (0, 2),
+        2 | 5 => (1, 2),
+        _ => panic!("unexpected value"),
+    }
+}
+
+/// Checks every context from the given iterator against the test tree and
+/// returns the values in the order they were visited.
+fn check_contexts<Value, Children, Iter>(mut iter: Iter, get: fn(&Value) -> usize) -> Vec<usize>
+where
+    Iter: StreamingIterator<Item = TreeContext<Value, Children>>,
+{
+    let mut visited = Vec::new();
+    while let Some(context) = iter.next() {
+        let ancestors = context.ancestors().iter().map(get).collect::<Vec<_>>();
+        let value = *ancestors.last().unwrap();
+        assert_eq!(get_expected_ancestors(value), ancestors);
+        assert_eq!(get_expected_path(value), context.path());
+        assert_eq!(
+            get_expected_siblings(value),
+            (context.sibling_index(), context.sibling_count())
+        );
+        visited.push(value);
+    }
+    visited
+}
+
+/// Checks every ancestor list from the given iterator against the test tree
+/// and returns the values in the order they were visited.
+fn check_ancestors<Value, Iter>(mut iter: Iter, get: fn(&Value) -> usize) -> Vec<usize>
+where
+    Iter: StreamingIterator<Item = [Value]>,
+{
+    let mut visited = Vec::new();
+    while let Some(ancestors) = iter.next() {
+        let ancestors = ancestors.iter().map(get).collect::<Vec<_>>();
+        let value = *ancestors.last().unwrap();
+        assert_eq!(get_expected_ancestors(value), ancestors);
+        visited.push(value);
+    }
+    visited
+}
+
+#[test]
+fn kary_traversals_follow_slot_order() {
+    let mut tree = create_kary_tree_for_testing();
+    let preorder = vec![0, 1, 3, 2, 4, 5, 6];
+    let bfs = vec![0, 1, 2, 3, 4, 5, 6];
+    let postorder = vec![3, 1, 4, 6, 5, 2, 0];
+
+    assert_eq!(
+        preorder,
+        tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(bfs, tree.bfs_iter().copied().collect::<Vec<_>>());
+    assert_eq!(
+        postorder,
+        tree.dfs_postorder_iter().copied().collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        preorder,
+        tree.dfs_preorder_iter_mut().map(|v| *v).collect::<Vec<_>>()
+    );
+    assert_eq!(bfs, tree.bfs_iter_mut().map(|v| *v).collect::<Vec<_>>());
+    assert_eq!(
+        postorder,
+        tree.dfs_postorder_iter_mut()
+            .map(|v| *v)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(preorder, tree.clone().dfs_preorder().collect::<Vec<_>>());
+    assert_eq!(bfs, tree.clone().bfs().collect::<Vec<_>>());
+    assert_eq!(postorder, tree.dfs_postorder().collect::<Vec<_>>());
+}
+
+#[test]
+fn kary_paths_preserve_empty_slots() {
+    let mut tree = create_kary_tree_for_testing();
+    for value in 0..=6 {
+        let path = get_expected_path(value);
+        assert_eq!(Some(&value), tree.at_path_ref(path).map(|node| &node.value));
+        assert_eq!(
+            Some(value),
+            tree.clone().at_path(path).map(|node| node.value)
+        );
+    }
+
+    assert!(tree.at_path_ref(&[1]).is_none());
+    assert!(tree.at_path_ref(&[0, 1, 0]).is_none());
+    assert!(tree.at_path_ref(&[4]).is_none());
+
+    tree.at_path_mut(&[3, 2, 3]).unwrap().value = 60;
+    assert_eq!(
+        Some(&60),
+        tree.at_path_ref(&[3, 2, 3]).map(|node| &node.value)
+    );
+
+    let mut tree = create_kary_tree_for_testing();
+    let paths = tree.map_path_ref(|path, _| path.to_vec());
+    for value in 0..=6 {
+        assert_eq!(
+            get_expected_path(value),
+            paths.at_path_ref(get_expected_path(value)).unwrap().value
+        );
+    }
+
+    tree.map_path_mut(|path, value| {
+        assert_eq!(get_expected_path(*value), path);
+    });
+    tree.map_path(|path, value| {
+        assert_eq!(get_expected_path(value), path);
+    });
+}
+
+#[test]
+fn kary_prune_leaves_slots_empty() {
+    let tree = create_kary_tree_for_testing();
+
+    let pruned = tree.prune_ref(|value| **value == 5).unwrap();
+    assert_eq!(
+        vec![0, 1, 3, 2, 4],
+        pruned.dfs_preorder_iter().map(|v| **v).collect::<Vec<_>>()
+    );
+    assert!(pruned.at_path_ref(&[3, 2]).is_none());
+    assert_eq!(Some(&&4), pruned.at_path_ref(&[3, 0]).map(|n| &n.value));
+
+    assert!(tree.prune_ref(|value| **value == 0).is_none());
+
+    let pruned = tree
+        .prune_path_ref(|path, _| path.first() == Some(&0))
+        .unwrap();
+    assert!(pruned.children[0].is_none());
+    assert_eq!(Some(&&2), pruned.children[3].as_ref().map(|n| &n.value));
+
+    let pruned = tree.prune_depth_ref(1);
+    assert_eq!(
+        vec![0, 1, 2],
+        pruned.bfs_iter().map(|v| **v).collect::<Vec<_>>()
+    );
+    assert_eq!(Some(&&2), pruned.at_path_ref(&[3]).map(|n| &n.value));
+
+    assert_eq!(
+        Some(tree.clone().prune_depth(2)),
+        tree.clone().prune(|value| *value == 6)
+    );
+}
+
+#[test]
+fn kary_map_and_fold_work() {
+    let mut tree = create_kary_tree_for_testing();
+
+    let mapped = tree.map_ref(|value| value * 10);
+    assert_eq!(
+        vec![0, 10, 30, 20, 40, 50, 60],
+        mapped.dfs_preorder().collect::<Vec<_>>()
+    );
+    assert_eq!(tree, tree.map_ref(|value| value).cloned());
+
+    let sum = tree.fold_ref(|children: [Option<usize>; 4], value| {
+        children.into_iter().flatten().sum::<usize>() + value
+    });
+    assert_eq!(21, sum);
+
+    let occupied_slots = tree.fold_mut(|children: [Option<Vec<usize>>; 4], _| {
+        let mut result = Vec::new();
+        for (slot, child) in children.into_iter().enumerate() {
+            if let Some(child) = child {
+                result.push(slot);
+                result.extend(child);
+            }
+        }
+        result
+    });
+    assert_eq!(vec![0, 1, 3, 0, 2, 3], occupied_slots);
+
+    let depth_sum = tree
+        .clone()
+        .fold_path(|children: [Option<usize>; 4], path, _| {
+            children.into_iter().flatten().sum::<usize>() + path.len()
+        });
+    assert_eq!(11, depth_sum);
+
+    for value in tree.dfs_preorder_iter_mut() {
+        *value += 1;
+    }
+    assert_eq!(
+        vec![1, 2, 4, 3, 5, 6, 7],
+        tree.dfs_preorder().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn kary_pipeline_chains_operations() {
+    let tree = create_kary_tree_for_testing();
+
+    let result = tree
+        .into_pipeline_ref()
+        .prune_path(|path, _| path == [0])
+        .map_tree(|value| *value + 100)
+        .prune_depth(1)
+        .collect_tree()
+        .unwrap();
+
+    let mut expected = KaryTree::new(100);
+    expected.children[3] = Some(Box::new(KaryTree::new(102)));
+    assert_eq!(expected, result);
+
+    let folded = tree.into_pipeline().prune(|value| *value == 2).fold_tree(
+        |children: [Option<usize>; 4], _| children.into_iter().flatten().sum::<usize>() + 1,
+    );
+    assert_eq!(Some(3), folded);
+}
+
+#[test]
+fn kary_leaves_follow_slot_order() {
+    let mut tree = create_kary_tree_for_testing();
+    let leaves = vec![3, 4, 6];
+
+    assert_eq!(
+        leaves,
+        tree.bfs_iter().leaves().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        leaves,
+        tree.dfs_preorder_iter()
+            .leaves()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        leaves,
+        tree.dfs_postorder_iter()
+            .leaves()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        leaves,
+        tree.bfs_iter_mut().leaves().map(|v| *v).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        leaves,
+        tree.dfs_preorder_iter_mut()
+            .leaves()
+            .map(|v| *v)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        leaves,
+        tree.dfs_postorder_iter_mut()
+            .leaves()
+            .map(|v| *v)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(leaves, tree.clone().bfs().leaves().collect::<Vec<_>>());
+    assert_eq!(
+        leaves,
+        tree.clone().dfs_preorder().leaves().collect::<Vec<_>>()
+    );
+    assert_eq!(leaves, tree.dfs_postorder().leaves().collect::<Vec<_>>());
+}
+
+#[test]
+fn kary_attach_context_reports_slot_paths() {
+    let mut tree = create_kary_tree_for_testing();
+    let preorder = vec![0, 1, 3, 2, 4, 5, 6];
+    let bfs = vec![0, 1, 2, 3, 4, 5, 6];
+    let postorder = vec![3, 1, 4, 6, 5, 2, 0];
+
+    assert_eq!(
+        bfs,
+        check_contexts(tree.bfs_iter().attach_context(), |v| **v)
+    );
+    assert_eq!(
+        preorder,
+        check_contexts(tree.dfs_preorder_iter().attach_context(), |v| **v)
+    );
+    assert_eq!(
+        postorder,
+        check_contexts(tree.dfs_postorder_iter().attach_context(), |v| **v)
+    );
+
+    assert_eq!(
+        bfs,
+        check_contexts(tree.bfs_iter_mut().attach_context(), |v| **v)
+    );
+    assert_eq!(
+        preorder,
+        check_contexts(tree.dfs_preorder_iter_mut().attach_context(), |v| **v)
+    );
+    assert_eq!(
+        postorder,
+        check_contexts(tree.dfs_postorder_iter_mut().attach_context(), |v| **v)
+    );
+
+    assert_eq!(
+        bfs,
+        check_contexts(tree.clone().bfs().attach_context(), |v| *v)
+    );
+    assert_eq!(
+        preorder,
+        check_contexts(tree.clone().dfs_preorder().attach_context(), |v| *v)
+    );
+    assert_eq!(
+        postorder,
+        check_contexts(tree.dfs_postorder().attach_context(), |v| *v)
+    );
+}
+
+#[test]
+fn kary_context_exposes_child_slots() {
+    let mut tree = create_kary_tree_for_testing();
+    let mut iter = tree.dfs_postorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        if **context.ancestors().last().unwrap() == 2 {
+            let slots = context
+                .children()
+                .map(|child| child.map(|child| child.value));
+            assert_eq!([Some(4), None, Some(5), None], slots);
+        }
+    }
+    drop(iter);
+
+    let mut iter = tree.dfs_preorder_iter_mut().attach_context();
+    while let Some(context) = iter.next_mut() {
+        if let Some(child) = context.children_mut()[3].as_mut() {
+            child.value += 10;
+        }
+    }
+    drop(iter);
+    assert_eq!(Some(&12), tree.at_path_ref(&[3]).map(|node| &node.value));
+    assert_eq!(
+        Some(&16),
+        tree.at_path_ref(&[3, 2, 3]).map(|node| &node.value)
+    );
+}
+
+#[test]
+fn kary_context_leaves_report_slot_paths() {
+    let mut tree = create_kary_tree_for_testing();
+    let leaves = vec![3, 4, 6];
+
+    assert_eq!(
+        leaves,
+        check_contexts(tree.bfs_iter().attach_context().leaves(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(tree.dfs_preorder_iter().attach_context().leaves(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(tree.dfs_postorder_iter().attach_context().leaves(), |v| **v)
+    );
+
+    assert_eq!(
+        leaves,
+        check_contexts(tree.bfs_iter_mut().attach_context().leaves(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(
+            tree.dfs_preorder_iter_mut().attach_context().leaves(),
+            |v| **v
+        )
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(
+            tree.dfs_postorder_iter_mut().attach_context().leaves(),
+            |v| **v
+        )
+    );
+
+    assert_eq!(
+        leaves,
+        check_contexts(tree.clone().bfs().attach_context().leaves(), |v| *v)
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(tree.clone().dfs_preorder().attach_context().leaves(), |v| {
+            *v
+        })
+    );
+    assert_eq!(
+        leaves,
+        check_contexts(tree.dfs_postorder().attach_context().leaves(), |v| *v)
+    );
+}
+
+#[test]
+fn kary_attach_ancestors_works() {
+    let mut tree = create_kary_tree_for_testing();
+    let preorder = vec![0, 1, 3, 2, 4, 5, 6];
+    let bfs = vec![0, 1, 2, 3, 4, 5, 6];
+    let postorder = vec![3, 1, 4, 6, 5, 2, 0];
+    let leaves = vec![3, 4, 6];
+
+    assert_eq!(
+        bfs,
+        check_ancestors(tree.bfs_iter().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        preorder,
+        check_ancestors(tree.dfs_preorder_iter().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        postorder,
+        check_ancestors(tree.dfs_postorder_iter().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.bfs_iter().attach_ancestors().leaves(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.dfs_preorder_iter().attach_ancestors().leaves(), |v| {
+            **v
+        })
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.dfs_postorder_iter().attach_ancestors().leaves(), |v| {
+            **v
+        })
+    );
+
+    assert_eq!(
+        bfs,
+        check_ancestors(tree.bfs_iter_mut().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        preorder,
+        check_ancestors(tree.dfs_preorder_iter_mut().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        postorder,
+        check_ancestors(tree.dfs_postorder_iter_mut().attach_ancestors(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.bfs_iter_mut().attach_ancestors().leaves(), |v| **v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(
+            tree.dfs_preorder_iter_mut().attach_ancestors().leaves(),
+            |v| **v
+        )
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(
+            tree.dfs_postorder_iter_mut().attach_ancestors().leaves(),
+            |v| **v
+        )
+    );
+
+    assert_eq!(
+        bfs,
+        check_ancestors(tree.clone().bfs().attach_ancestors(), |v| *v)
+    );
+    assert_eq!(
+        preorder,
+        check_ancestors(tree.clone().dfs_preorder().attach_ancestors(), |v| *v)
+    );
+    assert_eq!(
+        postorder,
+        check_ancestors(tree.clone().dfs_postorder().attach_ancestors(), |v| *v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.clone().bfs().attach_ancestors().leaves(), |v| *v)
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(
+            tree.clone().dfs_preorder().attach_ancestors().leaves(),
+            |v| *v
+        )
+    );
+    assert_eq!(
+        leaves,
+        check_ancestors(tree.dfs_postorder().attach_ancestors().leaves(), |v| *v)
+    );
+}
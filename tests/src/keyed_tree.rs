@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+use tree_iterators_rs::prelude::*;
+
+fn leaf(value: usize) -> KeyedTree<String, usize> {
+    KeyedTree {
+        value,
+        children: BTreeMap::new(),
+    }
+}
+
+fn node(value: usize, children: Vec<(&str, KeyedTree<String, usize>)>) -> KeyedTree<String, usize> {
+    KeyedTree {
+        value,
+        children: children
+            .into_iter()
+            .map(|(key, child)| (key.to_string(), child))
+            .collect(),
+    }
+}
+
+/// Creates a file-system-like tree. Children are deliberately inserted out of
+/// key order to show that traversal follows the key order.
+fn create_keyed_tree_for_testing() -> KeyedTree<String, usize> {
+    node(
+        0,
+        vec![
+            ("usr", node(4, vec![("lib", leaf(6)), ("bin", leaf(5))])),
+            (
+                "etc",
+                node(1, vec![("ssh", node(2, vec![("config", leaf(3))]))]),
+            ),
+        ],
+    )
+}
+
+fn get_expected_key_paths() -> Vec<Vec<&'static str>> {
+    vec![
+        vec![],
+        vec!["etc"],
+        vec!["etc", "ssh"],
+        vec!["etc", "ssh", "config"],
+        vec!["usr"],
+        vec!["usr", "bin"],
+        vec!["usr", "lib"],
+    ]
+}
+
+#[test]
+fn at_key_path_works() {
+    let mut tree = create_keyed_tree_for_testing();
+
+    assert_eq!(
+        Some(&3),
+        tree.at_key_path_ref(&["etc", "ssh", "config"])
+            .map(|node| &node.value)
+    );
+    assert_eq!(
+        Some(&0),
+        tree.at_key_path_ref::<str>(&[]).map(|node| &node.value)
+    );
+    assert!(tree.at_key_path_ref(&["etc", "config"]).is_none());
+
+    tree.at_key_path_mut(&["usr", "bin"]).unwrap().value = 50;
+    assert_eq!(50, tree.children["usr"].children["bin"].value);
+
+    let owned_path = ["usr".to_string(), "lib".to_string()];
+    assert_eq!(
+        Some(6),
+        tree.at_key_path(&owned_path.iter().collect::<Vec<_>>())
+            .map(|node| node.value)
+    );
+}
+
+#[test]
+fn get_keyed_child_works() {
+    let mut tree = create_keyed_tree_for_testing();
+
+    assert_eq!(
+        Some(&4),
+        tree.get_keyed_child_ref("usr").map(|node| &node.value)
+    );
+    assert!(tree.get_keyed_child_ref("ssh").is_none());
+
+    tree.get_keyed_child_mut("etc").unwrap().value = 10;
+    assert_eq!(10, tree.children["etc"].value);
+
+    let key = "usr".to_string();
+    assert_eq!(Some(4), tree.get_keyed_child(&key).map(|node| node.value));
+}
+
+#[test]
+fn positional_traversals_follow_key_order() {
+    let tree = create_keyed_tree_for_testing();
+    assert_eq!(
+        (0..=6).collect::<Vec<_>>(),
+        tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![0, 1, 4, 2, 5, 6, 3], tree.bfs().collect::<Vec<_>>());
+}
+
+#[test]
+fn dfs_preorder_keyed_matches_dfs_preorder() {
+    let mut tree = create_keyed_tree_for_testing();
+    let expected = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+
+    assert_eq!(
+        expected,
+        tree.dfs_preorder_keyed_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        expected,
+        tree.dfs_preorder_keyed_iter_mut()
+            .map(|value| *value)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(expected, tree.dfs_preorder_keyed().collect::<Vec<_>>());
+}
+
+#[test]
+fn dfs_preorder_keyed_attach_context_tracks_key_paths() {
+    let mut tree = create_keyed_tree_for_testing();
+    let expected = get_expected_key_paths();
+
+    let mut i = 0;
+    let mut iter = tree.dfs_preorder_keyed_iter().attach_context();
+    while let Some(context) = iter.next() {
+        assert_eq!(i, **context.ancestors().last().unwrap());
+        assert_eq!(context.depth(), context.key_path().len());
+        assert_eq!(expected[i], context.key_path());
+        if i == 0 {
+            assert_eq!(
+                vec!["etc", "usr"],
+                context.children().keys().collect::<Vec<_>>()
+            );
+        }
+        i += 1;
+    }
+    assert_eq!(expected.len(), i);
+
+    let mut i = 0;
+    let mut iter = tree.dfs_preorder_keyed_iter_mut().attach_context();
+    while let Some(context) = iter.next_mut() {
+        assert_eq!(expected[i], context.key_path());
+        **context.ancestors_mut().last_mut().unwrap() += 10;
+        i += 1;
+    }
+    assert_eq!(expected.len(), i);
+
+    let mut i = 0;
+    let mut iter = tree.dfs_preorder_keyed().attach_context();
+    while let Some(context) = iter.next() {
+        assert_eq!(i + 10, *context.ancestors().last().unwrap());
+        assert_eq!(expected[i], context.key_path());
+        i += 1;
+    }
+    assert_eq!(expected.len(), i);
+}
+
+#[test]
+fn keyed_tree_round_trips_through_tree() {
+    let keyed = create_keyed_tree_for_testing();
+    let tree = keyed.clone().into_tree("/".to_string());
+
+    assert_eq!(("/".to_string(), 0), tree.value);
+    assert_eq!(
+        vec!["etc", "usr"],
+        tree.children
+            .iter()
+            .map(|child| child.value.0.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        (0..=6).collect::<Vec<_>>(),
+        tree.dfs_preorder_iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>()
+    );
+
+    let (root_key, round_trip) = KeyedTree::from_tree(tree);
+    assert_eq!("/", root_key);
+    assert_eq!(keyed, round_trip);
+}
+
+#[test]
+fn from_tree_keeps_last_duplicate_key() {
+    let tree = Tree {
+        value: ("root", 0),
+        children: vec![
+            Tree {
+                value: ("a", 1),
+                children: Vec::new(),
+            },
+            Tree {
+                value: ("a", 2),
+                children: Vec::new(),
+            },
+        ],
+    };
+
+    let (_, keyed) = KeyedTree::from_tree(tree);
+    assert_eq!(1, keyed.children.len());
+    assert_eq!(2, keyed.children["a"].value);
+}
@@ -0,0 +1,207 @@
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// Collects the left edge, right edge and y coordinate of every node, grouped
+/// by depth, and checks that each parent is centered above its children.
+fn collect_levels<T>(
+    tree: &Tree<(T, Position)>,
+    width: &dyn Fn(&T) -> f64,
+    depth: usize,
+    levels: &mut Vec<Vec<(f64, f64, f64)>>,
+) {
+    let (value, position) = &tree.value;
+    if levels.len() <= depth {
+        levels.push(Vec::new());
+    }
+    let half = width(value) / 2.0;
+    levels[depth].push((position.x - half, position.x + half, position.y));
+
+    if let (Some(first), Some(last)) = (tree.children.first(), tree.children.last()) {
+        assert_eq!(position.x, (first.value.1.x + last.value.1.x) / 2.0);
+    }
+    for child in &tree.children {
+        collect_levels(child, width, depth + 1, levels);
+    }
+}
+
+fn assert_tidy<T>(
+    tree: &Tree<(T, Position)>,
+    width: &dyn Fn(&T) -> f64,
+    separation: f64,
+    level: f64,
+) {
+    let mut levels = Vec::new();
+    collect_levels(tree, width, 0, &mut levels);
+
+    let mut leftmost = f64::INFINITY;
+    for (depth, nodes) in levels.iter().enumerate() {
+        for pair in nodes.windows(2) {
+            assert!(pair[1].0 - pair[0].1 >= separation - 1e-9);
+        }
+        for (left, _, y) in nodes {
+            assert_eq!(depth as f64 * level, *y);
+            leftmost = leftmost.min(*left);
+        }
+    }
+    assert_eq!(0.0, leftmost);
+}
+
+#[test]
+fn default_layout_is_tidy() {
+    let tree = create_tree_for_testing();
+    let laid_out = tree.layout_ref(&LayoutOptions::new());
+    assert_eq!(
+        tree.dfs_preorder_iter().collect::<Vec<_>>(),
+        laid_out
+            .dfs_preorder_iter()
+            .map(|(value, _)| *value)
+            .collect::<Vec<_>>()
+    );
+    assert_tidy(&laid_out, &|_| 40.0, 20.0, 60.0);
+}
+
+#[test]
+fn custom_widths_and_separations_are_respected() {
+    let tree = Tree {
+        value: 100,
+        children: vec![
+            create_tree_for_testing(),
+            Tree {
+                value: 7,
+                children: Vec::new(),
+            },
+            create_tree_for_testing().map(|value| value * 3),
+        ],
+    };
+    let width = |value: &&usize| 5.0 + **value as f64;
+    let options = LayoutOptions::new()
+        .node_width(width)
+        .node_height(10.0)
+        .sibling_separation(3.0)
+        .level_separation(15.0);
+
+    let laid_out = tree.layout_ref(&options);
+    assert_tidy(&laid_out, &width, 3.0, 25.0);
+
+    // identical subtrees are drawn identically
+    let first = laid_out.children[0].children[1].value.1.x - laid_out.children[0].value.1.x;
+    let again = tree.children[0].layout_ref(&options);
+    assert_eq!(again.children[1].value.1.x - again.value.1.x, first);
+}
+
+#[test]
+fn binary_children_stay_on_their_side() {
+    let tree = create_binary_tree_for_testing();
+    let options = LayoutOptions::new();
+    let laid_out = tree.layout_ref(&options);
+
+    let mut stack = vec![&laid_out];
+    while let Some(node) = stack.pop() {
+        let x = node.value.1.x;
+        if let Some(left) = &node.left {
+            assert!(left.value.1.x < x);
+            stack.push(left);
+        }
+        if let Some(right) = &node.right {
+            assert!(right.value.1.x > x);
+            stack.push(right);
+        }
+    }
+
+    assert_eq!(
+        tree.dfs_inorder_iter().collect::<Vec<_>>(),
+        laid_out
+            .dfs_inorder_iter()
+            .map(|(value, _)| *value)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn svg_draws_every_node_and_edge() {
+    let tree = create_tree_for_testing();
+    let options = LayoutOptions::new();
+    let laid_out = tree.layout_ref(&options);
+    let svg = laid_out.to_svg(&options, |value| format!("<{value}>"));
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(11, svg.matches("<rect").count());
+    assert_eq!(10, svg.matches("<line").count());
+    assert_eq!(11, svg.matches("<text").count());
+    assert!(svg.contains(">&lt;10&gt;</text>"));
+    assert!(!svg.contains("<10>"));
+
+    let width = laid_out
+        .dfs_preorder_iter()
+        .map(|(_, position)| position.x + 20.0)
+        .fold(0.0, f64::max);
+    assert!(svg.contains(&format!(
+        "width=\"{width}\" height=\"{}\"",
+        6.0 * 60.0 + 20.0
+    )));
+}
+
+/// A path where every node also has a leaf child, either before or after the
+/// rest of the path.
+fn caterpillar(depth: usize, leaf_first: bool) -> Tree<usize> {
+    let mut tree = Tree {
+        value: depth,
+        children: Vec::new(),
+    };
+    for value in (0..depth).rev() {
+        let leaf = Tree {
+            value,
+            children: Vec::new(),
+        };
+        let children = if leaf_first {
+            vec![leaf, tree]
+        } else {
+            vec![tree, leaf]
+        };
+        tree = Tree { value, children };
+    }
+    tree
+}
+
+#[test]
+fn contours_are_threaded_through_shallower_siblings() {
+    for leaf_first in [true, false] {
+        let tree = Tree {
+            value: 0,
+            children: vec![
+                caterpillar(10, leaf_first),
+                caterpillar(3, !leaf_first),
+                caterpillar(6, leaf_first),
+            ],
+        };
+        let laid_out = tree.layout_ref(&LayoutOptions::new());
+        assert_tidy(&laid_out, &|_| 40.0, 20.0, 60.0);
+    }
+}
+
+#[test]
+fn layout_works_on_deep_trees() {
+    for leaf_first in [true, false] {
+        let tree = caterpillar(5000, leaf_first);
+        let laid_out = tree.layout_ref(&LayoutOptions::new());
+
+        // breadth first order visits each level from left to right
+        let mut previous: Option<Position> = None;
+        let mut leftmost = f64::INFINITY;
+        for (_, position) in laid_out.bfs_iter() {
+            if let Some(previous) = previous {
+                if previous.y == position.y {
+                    assert!(position.x - previous.x >= 60.0 - 1e-9);
+                } else {
+                    assert_eq!(previous.y + 60.0, position.y);
+                }
+            }
+            leftmost = leftmost.min(position.x - 20.0);
+            previous = Some(*position);
+        }
+        assert_eq!(0.0, leftmost);
+        assert_eq!(5000.0 * 60.0, previous.unwrap().y);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{create_binary_tree_for_testing, create_tree_for_testing};
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+fn deep_tree(depth: usize) -> Tree<usize> {
+    let mut tree = Tree {
+        value: depth,
+        children: Vec::new(),
+    };
+    for value in (0..depth).rev() {
+        tree = Tree {
+            value,
+            children: vec![
+                tree,
+                Tree {
+                    value: depth + value + 1,
+                    children: Vec::new(),
+                },
+            ],
+        };
+    }
+    tree
+}
+
+#[test]
+fn lcrs_round_trips() {
+    for tree in [create_tree_for_testing(), deep_tree(2000)] {
+        let binary = tree.clone().into_lcrs_binary();
+        assert!(binary.right.is_none());
+        assert_eq!(
+            tree.dfs_preorder_iter().collect::<Vec<_>>(),
+            binary.dfs_preorder_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            tree.dfs_postorder_iter().collect::<Vec<_>>(),
+            binary.dfs_inorder_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(binary, BinaryTree::from_lcrs(tree.clone()));
+        assert_eq!(vec![tree], binary.into_lcrs_forest());
+    }
+}
+
+#[test]
+fn lcrs_encoding_matches_siblings() {
+    let binary = create_tree_for_testing().into_lcrs_binary();
+    let tree = create_tree_for_testing();
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        // a node's first child is its left child and every later child
+        // hangs off the right of its previous sibling.
+        let lcrs_path = context
+            .path()
+            .iter()
+            .flat_map(|&index| std::iter::once(0).chain(std::iter::repeat_n(1, index)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Some(*context.ancestors().last().unwrap()),
+            binary.at_path_ref(&lcrs_path).map(|node| &node.value)
+        );
+    }
+}
+
+#[test]
+fn lcrs_forest_includes_root_siblings() {
+    let forest = create_binary_tree_for_testing().into_lcrs_forest();
+    assert_eq!(
+        vec![vec![0, 1, 3, 4], vec![2, 5], vec![6, 7, 8, 9, 10]],
+        forest
+            .iter()
+            .map(|tree| tree.dfs_preorder_iter().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![vec![1, 4], vec![5], vec![7, 8]],
+        forest
+            .iter()
+            .map(|tree| tree
+                .children
+                .iter()
+                .map(|child| child.value)
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tree_with_slots_preserves_paths() {
+    let binary = create_binary_tree_for_testing();
+    let tree = binary.clone().into_tree_with_slots();
+
+    let mut iter = binary.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        assert_eq!(
+            Some(&Some(**context.ancestors().last().unwrap())),
+            tree.at_path_ref(context.path()).map(|node| &node.value)
+        );
+    }
+
+    // 7 has no left child but has a right child, so its left slot is kept.
+    assert_eq!(
+        Some(&None),
+        tree.at_path_ref(&[1, 1, 0, 0]).map(|node| &node.value)
+    );
+    assert_eq!(
+        binary.dfs_preorder_iter().count() + 2,
+        tree.dfs_preorder_iter().count()
+    );
+    assert_eq!(
+        binary.dfs_preorder_iter().copied().collect::<Vec<_>>(),
+        tree.dfs_preorder_iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tree_with_slots_works_on_deep_trees() {
+    let mut binary = BinaryTree {
+        value: 0,
+        left: None,
+        right: None,
+    };
+    for value in 1..5000 {
+        binary = BinaryTree {
+            value,
+            left: None,
+            right: Some(Box::new(binary)),
+        };
+    }
+
+    let tree = binary.into_tree_with_slots();
+    assert_eq!(
+        (0..5000).rev().collect::<Vec<_>>(),
+        tree.dfs_preorder_iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        4999,
+        tree.dfs_preorder_iter()
+            .leaves()
+            .filter(|value| value.is_none())
+            .count()
+    );
+}
@@ -0,0 +1,307 @@
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// The parts of a leaf's context that every leaves iterator can provide.
+type LeafSummary = (Vec<usize>, Vec<usize>, Vec<usize>, Vec<usize>, usize);
+
+macro_rules! summarize {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result: Vec<LeafSummary> = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push((
+                context.ancestors().iter().map(|value| **value).collect(),
+                context.path().to_vec(),
+                (0..=context.depth())
+                    .map(|depth| usize::from(context.ancestor_has_next_sibling(depth)))
+                    .collect(),
+                vec![context.sibling_index(), context.sibling_count()],
+                context.index(),
+            ));
+        }
+        result
+    }};
+}
+
+macro_rules! summarize_owned {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result: Vec<LeafSummary> = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push((
+                context.ancestors().to_vec(),
+                context.path().to_vec(),
+                (0..=context.depth())
+                    .map(|depth| usize::from(context.ancestor_has_next_sibling(depth)))
+                    .collect(),
+                vec![context.sibling_index(), context.sibling_count()],
+                context.index(),
+            ));
+        }
+        result
+    }};
+}
+
+/// Builds the expected leaves by filtering the output of a regular context
+/// iterator down to the nodes without children.
+macro_rules! expected_leaves {
+    ($iter: expr, $is_leaf: expr) => {{
+        let mut iter = $iter;
+        let mut result: Vec<LeafSummary> = Vec::new();
+        while let Some(context) = iter.next() {
+            if !$is_leaf(context.children()) {
+                continue;
+            }
+
+            result.push((
+                context.ancestors().iter().map(|value| **value).collect(),
+                context.path().to_vec(),
+                (0..=context.depth())
+                    .map(|depth| usize::from(context.ancestor_has_next_sibling(depth)))
+                    .collect(),
+                vec![context.sibling_index(), context.sibling_count()],
+                result.len(),
+            ));
+        }
+        result
+    }};
+}
+
+fn is_tree_leaf(children: &&Vec<Tree<usize>>) -> bool {
+    children.is_empty()
+}
+
+fn is_binary_leaf(children: &[Option<&BinaryTree<usize>>; 2]) -> bool {
+    children.iter().all(Option::is_none)
+}
+
+fn create_wide_tree_for_testing() -> Tree<usize> {
+    let mut next = 0;
+    let mut leaf = || {
+        next += 1;
+        Tree {
+            value: next,
+            children: Vec::new(),
+        }
+    };
+
+    let mut middle = Tree {
+        value: 100,
+        children: vec![leaf(), leaf(), leaf()],
+    };
+    middle.children[1].children.push(leaf());
+    Tree {
+        value: 0,
+        children: vec![
+            leaf(),
+            middle,
+            Tree {
+                value: 200,
+                children: vec![Tree {
+                    value: 201,
+                    children: vec![leaf(), leaf()],
+                }],
+            },
+            leaf(),
+        ],
+    }
+}
+
+fn create_trees() -> Vec<Tree<usize>> {
+    vec![
+        create_tree_for_testing(),
+        create_wide_tree_for_testing(),
+        Tree {
+            value: 42,
+            children: Vec::new(),
+        },
+    ]
+}
+
+fn create_binary_trees() -> Vec<BinaryTree<usize>> {
+    let mut unbalanced = create_binary_tree_for_testing();
+    unbalanced.left.as_mut().unwrap().right = None;
+    vec![
+        create_binary_tree_for_testing(),
+        unbalanced,
+        BinaryTree {
+            value: 42,
+            left: None,
+            right: None,
+        },
+    ]
+}
+
+#[test]
+fn dfs_leaves_match_filtered_context() {
+    for mut tree in create_trees() {
+        let expected = expected_leaves!(tree.dfs_preorder_iter().attach_context(), is_tree_leaf);
+        assert!(!expected.is_empty());
+        assert_eq!(
+            expected,
+            expected_leaves!(tree.dfs_postorder_iter().attach_context(), is_tree_leaf)
+        );
+
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_preorder_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_postorder_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_preorder_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_postorder_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.clone().dfs_preorder().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.dfs_postorder().attach_context().leaves())
+        );
+    }
+}
+
+#[test]
+fn bfs_leaves_match_filtered_context() {
+    for mut tree in create_trees() {
+        let expected = expected_leaves!(tree.bfs_iter().attach_context(), is_tree_leaf);
+        assert!(!expected.is_empty());
+
+        assert_eq!(
+            expected,
+            summarize!(tree.bfs_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.bfs_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.bfs().attach_context().leaves())
+        );
+    }
+}
+
+#[test]
+fn binary_dfs_leaves_match_filtered_context() {
+    for mut tree in create_binary_trees() {
+        let expected = expected_leaves!(tree.dfs_preorder_iter().attach_context(), is_binary_leaf);
+        assert!(!expected.is_empty());
+        assert_eq!(
+            expected,
+            expected_leaves!(tree.dfs_inorder_iter().attach_context(), is_binary_leaf)
+        );
+        assert_eq!(
+            expected,
+            expected_leaves!(tree.dfs_postorder_iter().attach_context(), is_binary_leaf)
+        );
+
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_preorder_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_inorder_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_postorder_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_preorder_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_inorder_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.dfs_postorder_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.clone().dfs_preorder().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.clone().dfs_inorder().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.dfs_postorder().attach_context().leaves())
+        );
+    }
+}
+
+#[test]
+fn binary_bfs_leaves_match_filtered_context() {
+    for mut tree in create_binary_trees() {
+        let expected = expected_leaves!(tree.bfs_iter().attach_context(), is_binary_leaf);
+        assert!(!expected.is_empty());
+
+        assert_eq!(
+            expected,
+            summarize!(tree.bfs_iter().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize!(tree.bfs_iter_mut().attach_context().leaves())
+        );
+        assert_eq!(
+            expected,
+            summarize_owned!(tree.bfs().attach_context().leaves())
+        );
+    }
+}
+
+#[test]
+fn leaves_with_context_can_be_mutated() {
+    let mut tree = create_tree_for_testing();
+    {
+        let mut iter = tree.dfs_postorder_iter_mut().attach_context().leaves();
+        while let Some(context) = iter.next_mut() {
+            let depth = context.depth();
+            **context.ancestors_mut().last_mut().unwrap() = depth;
+        }
+    }
+
+    assert_eq!(
+        vec![0, 1, 2, 2, 2, 2, 6, 7, 8, 9, 6],
+        tree.dfs_preorder().collect::<Vec<_>>()
+    );
+
+    let mut tree = create_binary_tree_for_testing();
+    {
+        let mut iter = tree.bfs_iter_mut().attach_context().leaves();
+        while let Some(context) = iter.next_mut() {
+            let index = context.index();
+            **context.ancestors_mut().last_mut().unwrap() = 100 + index;
+        }
+    }
+
+    assert_eq!(
+        vec![0, 1, 100, 101, 2, 102, 6, 7, 8, 9, 103],
+        tree.dfs_preorder().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic]
+fn leaves_with_context_panics_mid_traversal() {
+    let tree = create_tree_for_testing();
+    let mut iter = tree.bfs_iter().attach_context();
+    iter.next();
+    iter.leaves();
+}
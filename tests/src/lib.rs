@@ -4,15 +4,41 @@
 use tree_iterators_rs::prelude::*;
 
 mod ancestors_leaves;
+mod avl_tree;
 mod bfs;
+mod binary_search_tree;
+mod canonical;
 mod dfs_inorder;
 mod dfs_postorder;
 mod dfs_preorder;
+mod edit_distance;
+mod events;
+mod exact_size;
+mod forest;
 mod get_at_path;
+mod isomorphism;
+#[cfg(feature = "json")]
+mod json;
+mod kary_tree;
+mod keyed_tree;
+mod layout;
+mod lcrs;
 mod prune;
+mod reconstruction;
+mod resume;
+mod search;
+mod selectors;
+mod shared_tree;
+mod skip;
+mod subtree_hashes;
+mod tree_context;
+mod trie;
 mod map;
+mod metrics;
+mod morris;
 mod fold;
 mod leaves;
+mod leaves_context;
 
 #[cfg(test)]
 extern crate std;
@@ -293,3 +293,42 @@ fn map_binary_tree_collection() {
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn map_path_passes_current_path() {
+    let value_to_path = crate::get_value_to_path_map();
+    let tree = crate::create_tree_for_testing();
+    tree.map_path_ref(|path, value| assert_eq!(value_to_path[value], path));
+
+    let value_to_path = crate::get_value_to_path_map_binary();
+    let tree = crate::create_binary_tree_for_testing();
+    tree.map_path_ref(|path, value| assert_eq!(value_to_path[value], path));
+}
+
+#[test]
+fn map_path_each_passes_current_path() {
+    let value_to_path = crate::get_value_to_path_map();
+    let trees = vec![
+        crate::create_tree_for_testing(),
+        crate::create_tree_for_testing(),
+    ];
+    let results = trees
+        // paths into a collection start with the index of the tree
+        .map_path_each_ref(|path, value| (path[0], path[1..] == value_to_path[value][..]))
+        .collect::<Vec<_>>();
+    for (index, tree) in results.into_iter().enumerate() {
+        assert!(tree.dfs_preorder().all(|result| result == (index, true)));
+    }
+
+    let value_to_path = crate::get_value_to_path_map_binary();
+    let trees = vec![
+        crate::create_binary_tree_for_testing(),
+        crate::create_binary_tree_for_testing(),
+    ];
+    let results = trees
+        .map_path_each_ref(|path, value| (path[0], path[1..] == value_to_path[value][..]))
+        .collect::<Vec<_>>();
+    for (index, tree) in results.into_iter().enumerate() {
+        assert!(tree.dfs_preorder().all(|result| result == (index, true)));
+    }
+}
@@ -0,0 +1,125 @@
+use tree_iterators_rs::prelude::{
+    BinaryTree, BorrowedBinaryTreeNode, BorrowedTreeNode, Metrics, Tree,
+};
+
+use crate::{create_binary_tree_for_testing, create_tree_for_testing};
+
+#[test]
+fn tree_metrics() {
+    let tree = create_tree_for_testing();
+    assert_eq!(11, tree.size());
+    assert_eq!(6, tree.height());
+    assert_eq!(4, tree.leaf_count());
+    assert_eq!(4, tree.max_width());
+
+    let expected_widths = [1, 2, 4, 1, 1, 1, 1, 0, 0];
+    for (depth, expected) in expected_widths.into_iter().enumerate() {
+        assert_eq!(expected, tree.width_at_depth(depth), "depth {}", depth);
+    }
+}
+
+#[test]
+fn binary_tree_metrics() {
+    let tree = create_binary_tree_for_testing();
+    assert_eq!(11, tree.size());
+    assert_eq!(6, tree.height());
+    assert_eq!(4, tree.leaf_count());
+    assert_eq!(4, tree.max_width());
+
+    let expected_widths = [1, 2, 4, 1, 1, 1, 1, 0, 0];
+    for (depth, expected) in expected_widths.into_iter().enumerate() {
+        assert_eq!(expected, tree.width_at_depth(depth), "depth {}", depth);
+    }
+}
+
+#[test]
+fn single_node_metrics() {
+    let tree = Tree {
+        value: 0,
+        children: Vec::new(),
+    };
+    assert_eq!(1, tree.size());
+    assert_eq!(0, tree.height());
+    assert_eq!(1, tree.leaf_count());
+    assert_eq!(1, tree.max_width());
+    assert_eq!(
+        Metrics {
+            size: 1,
+            height: 0,
+            leaf_count: 1,
+            max_branching_factor: 0,
+        },
+        tree.annotate_metrics_ref().value.1
+    );
+
+    let binary_tree = BinaryTree {
+        value: 0,
+        left: None,
+        right: None,
+    };
+    assert_eq!(1, binary_tree.size());
+    assert_eq!(0, binary_tree.height());
+    assert_eq!(1, binary_tree.leaf_count());
+    assert_eq!(1, binary_tree.max_width());
+}
+
+#[test]
+fn annotate_metrics_matches_individual_metrics() {
+    let tree = create_tree_for_testing();
+    let annotated = tree.annotate_metrics_ref();
+
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+    for value in tree.dfs_preorder_iter() {
+        let path = crate::get_value_to_path_map()[value].clone();
+        let subtree = tree.at_path_ref(&path).unwrap();
+        expected.push((
+            *value,
+            subtree.size(),
+            subtree.height(),
+            subtree.leaf_count(),
+        ));
+    }
+
+    for (value, metrics) in annotated.dfs_preorder_iter() {
+        actual.push((**value, metrics.size, metrics.height, metrics.leaf_count));
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(2, annotated.value.1.max_branching_factor);
+}
+
+#[test]
+fn annotate_metrics_matches_individual_metrics_binary() {
+    let tree = create_binary_tree_for_testing();
+    let annotated = tree.annotate_metrics_ref();
+
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+    for value in tree.dfs_preorder_iter() {
+        let path = crate::get_value_to_path_map_binary()[value].clone();
+        let subtree = tree.at_path_ref(&path).unwrap();
+        expected.push((
+            *value,
+            subtree.size(),
+            subtree.height(),
+            subtree.leaf_count(),
+        ));
+    }
+
+    for (value, metrics) in annotated.dfs_preorder_iter() {
+        actual.push((**value, metrics.size, metrics.height, metrics.leaf_count));
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(2, annotated.value.1.max_branching_factor);
+    assert_eq!(
+        1,
+        annotated
+            .at_path_ref(&[1, 1])
+            .unwrap()
+            .value
+            .1
+            .max_branching_factor
+    );
+}
@@ -0,0 +1,181 @@
+use crate::create_binary_tree_for_testing;
+use tree_iterators_rs::prelude::*;
+
+fn create_deep_binary_tree(depth: usize) -> BinaryTree<usize> {
+    // a zig-zagging tree exercises both threads into the left subtrees and
+    // long chains of right children.
+    let mut tree = BinaryTree {
+        value: 0,
+        left: None,
+        right: None,
+    };
+    for value in 1..depth {
+        let child = Some(Box::new(tree));
+        tree = BinaryTree {
+            value,
+            left: None,
+            right: None,
+        };
+        if value % 3 == 0 {
+            tree.right = child;
+        } else {
+            tree.left = child;
+        }
+    }
+    tree
+}
+
+fn trees_for_testing() -> Vec<BinaryTree<usize>> {
+    vec![
+        create_binary_tree_for_testing(),
+        BinaryTree {
+            value: 0,
+            left: None,
+            right: None,
+        },
+        BinaryTree::from_sorted_iter(0..100).unwrap(),
+        create_deep_binary_tree(100),
+    ]
+}
+
+#[test]
+fn morris_inorder_matches_dfs_inorder() {
+    for mut tree in trees_for_testing() {
+        let expected = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+        let original = tree.clone();
+
+        assert_eq!(
+            expected,
+            tree.morris_inorder_iter_mut()
+                .map(|value| *value)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(original, tree);
+
+        for value in tree.morris_inorder_iter_mut() {
+            *value += 1;
+        }
+        assert_eq!(
+            expected.iter().map(|value| value + 1).collect::<Vec<_>>(),
+            tree.dfs_inorder_iter().copied().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn morris_preorder_matches_dfs_preorder() {
+    for mut tree in trees_for_testing() {
+        let expected = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+        let original = tree.clone();
+
+        assert_eq!(
+            expected,
+            tree.morris_preorder_iter_mut()
+                .map(|value| *value)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(original, tree);
+
+        for value in tree.morris_preorder_iter_mut() {
+            *value *= 2;
+        }
+        assert_eq!(
+            expected.iter().map(|value| value * 2).collect::<Vec<_>>(),
+            tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn morris_iterators_work_on_deep_trees() {
+    let mut tree = create_deep_binary_tree(10_000);
+    let inorder = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+    let preorder = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+
+    assert_eq!(
+        inorder,
+        tree.morris_inorder_iter_mut()
+            .map(|value| *value)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        preorder,
+        tree.morris_preorder_iter_mut()
+            .map(|value| *value)
+            .collect::<Vec<_>>()
+    );
+
+    tree.morris_inorder_iter_mut().nth(5_000);
+    assert_eq!(
+        inorder,
+        tree.dfs_inorder_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        preorder,
+        tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn morris_iterators_restore_the_tree_when_dropped_early() {
+    let mut tree = create_binary_tree_for_testing();
+    let original = tree.clone();
+    let len = tree.dfs_preorder_iter().count();
+
+    for stop_after in 0..=len {
+        let mut iter = tree.morris_inorder_iter_mut();
+        for _ in 0..stop_after {
+            iter.next().unwrap();
+        }
+        drop(iter);
+        assert_eq!(original, tree);
+
+        let mut iter = tree.morris_preorder_iter_mut();
+        let values = iter
+            .by_ref()
+            .take(stop_after)
+            .map(|value| *value)
+            .collect::<Vec<_>>();
+        drop(iter);
+        assert_eq!(
+            original
+                .dfs_preorder_iter()
+                .take(stop_after)
+                .copied()
+                .collect::<Vec<_>>(),
+            values
+        );
+        assert_eq!(original, tree);
+    }
+}
+
+#[test]
+fn morris_iterators_keep_references_valid_after_restoring() {
+    let mut tree = create_binary_tree_for_testing();
+    let mut iter = tree.morris_inorder_iter_mut();
+    let first = iter.next().unwrap();
+    let second = iter.next().unwrap();
+    drop(iter);
+
+    *first += 100;
+    *second += 100;
+    assert_eq!(
+        vec![103, 101, 4, 0],
+        tree.dfs_inorder_iter().copied().take(4).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn leaked_morris_iterators_leave_a_valid_tree() {
+    let mut tree = create_binary_tree_for_testing();
+    let mut iter = tree.morris_inorder_iter_mut();
+    iter.nth(5);
+    std::mem::forget(iter);
+
+    // the subtrees are leaked along with the iterator, but the tree itself is
+    // still safe to use and drop.
+    assert_eq!(
+        vec![0],
+        tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    );
+}
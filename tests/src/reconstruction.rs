@@ -0,0 +1,172 @@
+use crate::create_binary_tree_for_testing;
+use tree_iterators_rs::prelude::*;
+
+/// Builds a variety of tree shapes with unique values, including the shared
+/// testing tree and trees that lean entirely left or right.
+fn trees_for_testing() -> Vec<BinaryTree<usize>> {
+    let mut trees = vec![
+        create_binary_tree_for_testing(),
+        BinaryTree {
+            value: 0,
+            left: None,
+            right: None,
+        },
+    ];
+
+    let mut state = 41_usize;
+    let mut random = BinaryTree {
+        value: 50,
+        left: None,
+        right: None,
+    };
+    for _ in 0..200 {
+        state = (state * 31 + 7) % 101;
+        random.insert(state);
+    }
+    trees.push(random);
+
+    let mut left_leaning = BinaryTree::from_sorted_iter(0..1).unwrap();
+    let mut right_leaning = BinaryTree::from_sorted_iter(0..1).unwrap();
+    for value in 1..500 {
+        left_leaning = BinaryTree {
+            value,
+            left: Some(Box::new(left_leaning)),
+            right: None,
+        };
+        right_leaning = BinaryTree {
+            value,
+            left: None,
+            right: Some(Box::new(right_leaning)),
+        };
+    }
+    trees.push(left_leaning);
+    trees.push(right_leaning);
+    trees
+}
+
+#[test]
+fn from_preorder_inorder_round_trips() {
+    for tree in trees_for_testing() {
+        let preorder = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+        let inorder = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+        assert_eq!(
+            Ok(&tree),
+            BinaryTree::from_preorder_inorder(preorder, &inorder).as_ref()
+        );
+    }
+}
+
+#[test]
+fn from_postorder_inorder_round_trips() {
+    for tree in trees_for_testing() {
+        let postorder = tree.dfs_postorder_iter().copied().collect::<Vec<_>>();
+        let inorder = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+        assert_eq!(
+            Ok(&tree),
+            BinaryTree::from_postorder_inorder(postorder, &inorder).as_ref()
+        );
+    }
+}
+
+#[test]
+fn level_order_with_nulls_round_trips() {
+    for tree in trees_for_testing() {
+        let level_order = tree.to_level_order_with_nulls();
+        assert_eq!(Some(&Some(&tree.value)), level_order.first());
+        assert_ne!(Some(&None), level_order.last());
+        assert_eq!(
+            tree.bfs_iter().collect::<Vec<_>>(),
+            level_order.iter().flatten().copied().collect::<Vec<_>>()
+        );
+
+        let round_trip = BinaryTree::from_level_order_with_nulls(level_order).unwrap();
+        assert_eq!(tree, round_trip.cloned());
+    }
+}
+
+#[test]
+fn from_level_order_with_nulls_matches_leetcode_format() {
+    // [5, 4, 8, 11, null, 13, 4, 7, 2, null, null, null, 1]
+    let tree = BinaryTree::from_level_order_with_nulls([
+        Some(5),
+        Some(4),
+        Some(8),
+        Some(11),
+        None,
+        Some(13),
+        Some(4),
+        Some(7),
+        Some(2),
+        None,
+        None,
+        None,
+        Some(1),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        vec![5, 4, 11, 7, 2, 8, 13, 4, 1],
+        tree.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![7, 11, 2, 4, 5, 13, 8, 4, 1],
+        tree.dfs_inorder_iter().copied().collect::<Vec<_>>()
+    );
+
+    // explicit trailing nulls are accepted and dropped on the way back out.
+    let tree =
+        BinaryTree::from_level_order_with_nulls([Some(1), None, Some(2), None, None]).unwrap();
+    assert_eq!(
+        vec![Some(&1), None, Some(&2)],
+        tree.to_level_order_with_nulls()
+    );
+}
+
+#[test]
+fn inconsistent_inputs_are_rejected() {
+    assert_eq!(
+        Err(ReconstructionError::MissingRoot),
+        BinaryTree::<usize>::from_preorder_inorder([], &[])
+    );
+    assert_eq!(
+        Err(ReconstructionError::LengthMismatch),
+        BinaryTree::from_preorder_inorder([1, 2], &[1])
+    );
+    assert_eq!(
+        Err(ReconstructionError::DuplicateValue),
+        BinaryTree::from_preorder_inorder([1, 1], &[1, 1])
+    );
+    assert_eq!(
+        Err(ReconstructionError::InconsistentTraversals),
+        BinaryTree::from_preorder_inorder([1, 2], &[1, 3])
+    );
+    assert_eq!(
+        Err(ReconstructionError::InconsistentTraversals),
+        BinaryTree::from_preorder_inorder([1, 1], &[1, 2])
+    );
+    assert_eq!(
+        Err(ReconstructionError::InconsistentTraversals),
+        BinaryTree::from_preorder_inorder([1, 2, 3], &[3, 1, 2])
+    );
+    assert_eq!(
+        Err(ReconstructionError::InconsistentTraversals),
+        BinaryTree::from_postorder_inorder([2, 3, 1], &[3, 1, 2])
+    );
+
+    assert_eq!(
+        Err(ReconstructionError::MissingRoot),
+        BinaryTree::<usize>::from_level_order_with_nulls([])
+    );
+    assert_eq!(
+        Err(ReconstructionError::MissingRoot),
+        BinaryTree::from_level_order_with_nulls([None, Some(1)])
+    );
+    assert_eq!(
+        Err(ReconstructionError::UnattachedNode),
+        BinaryTree::from_level_order_with_nulls([Some(1), None, None, Some(2)])
+    );
+    assert_eq!(
+        Err(ReconstructionError::UnattachedNode),
+        BinaryTree::from_level_order_with_nulls([Some(1), Some(2), None, None, None, None])
+    );
+}
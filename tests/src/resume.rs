@@ -0,0 +1,223 @@
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// Collects the path of every node in the order the given context iterator
+/// visits them.
+macro_rules! all_paths {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push(context.path().to_vec());
+        }
+        result
+    }};
+}
+
+/// Asserts that resuming at every node's path yields exactly the suffix of
+/// the full traversal starting at that node.
+macro_rules! assert_resumes_everywhere {
+    ($tree: expr, $iter: ident, $iter_mut: ident, $into_iter: ident) => {{
+        let mut tree = $tree;
+        let expected = tree.$iter().copied().collect::<Vec<_>>();
+        let paths = all_paths!(tree.$iter().attach_context());
+        assert_eq!(expected.len(), paths.len());
+
+        for (i, path) in paths.iter().enumerate() {
+            let borrowed = tree
+                .$iter()
+                .resume_from(path)
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>();
+            assert_eq!(expected[i..], borrowed[..]);
+
+            let mut_borrowed = tree
+                .$iter_mut()
+                .resume_from(path)
+                .unwrap()
+                .map(|value| *value)
+                .collect::<Vec<_>>();
+            assert_eq!(expected[i..], mut_borrowed[..]);
+
+            let owned = tree
+                .clone()
+                .$into_iter()
+                .resume_from(path)
+                .unwrap()
+                .collect::<Vec<_>>();
+            assert_eq!(expected[i..], owned[..]);
+        }
+    }};
+}
+
+#[test]
+fn dfs_preorder_resume_from_yields_remaining_nodes() {
+    assert_resumes_everywhere!(
+        create_tree_for_testing(),
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder
+    );
+}
+
+#[test]
+fn binary_dfs_preorder_resume_from_yields_remaining_nodes() {
+    assert_resumes_everywhere!(
+        create_binary_tree_for_testing(),
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder
+    );
+}
+
+#[test]
+fn bfs_resume_from_yields_remaining_nodes() {
+    assert_resumes_everywhere!(create_tree_for_testing(), bfs_iter, bfs_iter_mut, bfs);
+}
+
+#[test]
+fn binary_bfs_resume_from_yields_remaining_nodes() {
+    assert_resumes_everywhere!(
+        create_binary_tree_for_testing(),
+        bfs_iter,
+        bfs_iter_mut,
+        bfs
+    );
+}
+
+#[test]
+fn resume_from_returns_none_for_missing_paths() {
+    let tree = create_tree_for_testing();
+    assert!(tree.dfs_preorder_iter().resume_from(&[3]).is_none());
+    assert!(tree.dfs_preorder_iter().resume_from(&[0, 0, 0]).is_none());
+    assert!(tree.bfs_iter().resume_from(&[3]).is_none());
+    assert!(tree.bfs_iter().resume_from(&[0, 0, 0]).is_none());
+
+    let binary_tree = create_binary_tree_for_testing();
+    assert!(binary_tree.dfs_preorder_iter().resume_from(&[2]).is_none());
+    assert!(binary_tree.bfs_iter().resume_from(&[2]).is_none());
+
+    // Node 6 only has a left child in the binary test tree.
+    assert!(binary_tree
+        .dfs_preorder_iter()
+        .resume_from(&[1, 1, 1])
+        .is_none());
+    assert!(binary_tree.bfs_iter().resume_from(&[1, 1, 1]).is_none());
+}
+
+#[test]
+fn resume_from_checkpoint_round_trips() {
+    let tree = create_tree_for_testing();
+    let expected = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    assert_eq!(None, iter.checkpoint());
+    for i in 0..expected.len() {
+        iter.advance();
+        let checkpoint = iter.checkpoint().unwrap();
+        let resumed = tree
+            .dfs_preorder_iter()
+            .resume_from(&checkpoint)
+            .unwrap()
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(expected[i..], resumed[..]);
+    }
+    iter.advance();
+    assert_eq!(None, iter.checkpoint());
+
+    let binary_tree = create_binary_tree_for_testing();
+    let expected = binary_tree.bfs_iter().copied().collect::<Vec<_>>();
+
+    let mut iter = binary_tree.bfs_iter().attach_context();
+    for i in 0..expected.len() {
+        iter.advance();
+        let checkpoint = iter.checkpoint().unwrap();
+        let resumed = binary_tree
+            .bfs_iter()
+            .resume_from(&checkpoint)
+            .unwrap()
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(expected[i..], resumed[..]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn attach_context_after_resume_from_panics() {
+    let tree = create_tree_for_testing();
+    let _ = tree
+        .dfs_preorder_iter()
+        .resume_from(&[1, 0])
+        .unwrap()
+        .attach_context();
+}
+
+#[test]
+fn cloned_iterators_continue_independently() {
+    let tree = create_tree_for_testing();
+    let expected = tree.dfs_postorder_iter().copied().collect::<Vec<_>>();
+    let mut iter = tree.dfs_postorder_iter();
+    iter.nth(4);
+    let fork = iter.clone();
+    assert_eq!(expected[5..], iter.copied().collect::<Vec<_>>()[..]);
+    assert_eq!(expected[5..], fork.copied().collect::<Vec<_>>()[..]);
+
+    let binary_tree = create_binary_tree_for_testing();
+    let expected = binary_tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+    let mut iter = binary_tree.dfs_inorder_iter();
+    iter.nth(3);
+    let fork = iter.clone();
+    assert_eq!(expected[4..], iter.copied().collect::<Vec<_>>()[..]);
+    assert_eq!(expected[4..], fork.copied().collect::<Vec<_>>()[..]);
+
+    let trees = vec![create_tree_for_testing(), create_tree_for_testing()];
+    let expected = trees.bfs_each_iter().copied().collect::<Vec<_>>();
+    let mut iter = trees.bfs_each_iter();
+    iter.nth(12);
+    let fork = iter.clone();
+    assert_eq!(expected[13..], iter.copied().collect::<Vec<_>>()[..]);
+    assert_eq!(expected[13..], fork.copied().collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn cloned_context_iterators_continue_independently() {
+    let tree = create_tree_for_testing();
+    let expected = all_paths!(tree.bfs_iter().attach_context());
+    let mut iter = tree.bfs_iter().attach_context();
+    iter.nth(5);
+    let fork = iter.clone();
+    assert_eq!(expected[6..], all_paths!(iter)[..]);
+    assert_eq!(expected[6..], all_paths!(fork)[..]);
+
+    let binary_tree = create_binary_tree_for_testing();
+    let expected = all_paths!(binary_tree.dfs_postorder_iter().attach_context());
+    let mut iter = binary_tree.dfs_postorder_iter().attach_context();
+    iter.nth(5);
+    let fork = iter.clone();
+    assert_eq!(expected[6..], all_paths!(iter)[..]);
+    assert_eq!(expected[6..], all_paths!(fork)[..]);
+
+    let mut expected = Vec::new();
+    let mut iter = tree.dfs_preorder_iter().attach_ancestors();
+    while let Some(ancestors) = iter.next() {
+        expected.push(ancestors.iter().map(|value| **value).collect::<Vec<_>>());
+    }
+
+    let mut iter = tree.dfs_preorder_iter().attach_ancestors();
+    iter.nth(7);
+    let mut fork = iter.clone();
+    for expected in &expected[8..] {
+        let original = iter.next().unwrap().iter().map(|value| **value);
+        assert_eq!(expected, &original.collect::<Vec<_>>());
+    }
+    for expected in &expected[8..] {
+        let forked = fork.next().unwrap().iter().map(|value| **value);
+        assert_eq!(expected, &forked.collect::<Vec<_>>());
+    }
+    assert!(fork.next().is_none());
+}
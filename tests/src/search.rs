@@ -0,0 +1,119 @@
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// Collects the value and path of every node in the order the given context
+/// iterator visits them.
+macro_rules! visited {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push((
+                **context.ancestors().last().unwrap(),
+                context.path().to_vec(),
+            ));
+        }
+        result
+    }};
+}
+
+macro_rules! assert_searches {
+    ($tree: expr) => {{
+        let mut tree = $tree;
+        let orders = [
+            (
+                SearchOrder::DepthFirst,
+                visited!(tree.dfs_preorder_iter().attach_context()),
+            ),
+            (
+                SearchOrder::BreadthFirst,
+                visited!(tree.bfs_iter().attach_context()),
+            ),
+        ];
+
+        for (order, visited) in orders {
+            for target in 0..12 {
+                let matches = |value: usize| value >= target && value % 3 == target % 3;
+                let expected = visited.iter().find(|(value, _)| matches(*value)).cloned();
+
+                let found = tree.find_ref(order, |value| matches(**value));
+                assert_eq!(expected, found.map(|(value, path)| (*value, path)));
+
+                let found = tree.find_mut(order, |value| matches(**value));
+                assert_eq!(expected, found.map(|(value, path)| (*value, path)));
+
+                let expected_paths = visited
+                    .iter()
+                    .filter(|(value, _)| matches(*value))
+                    .map(|(_, path)| path.clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    expected_paths,
+                    tree.find_all_paths(order, |value| matches(**value))
+                );
+
+                let expected_ancestors = expected.map(|(_, path)| {
+                    (0..=path.len())
+                        .map(|depth| tree.at_path_ref(&path[..depth]).unwrap().value)
+                        .collect::<Vec<_>>()
+                });
+                let found = tree
+                    .find_with_ancestors(order, |ancestors| matches(**ancestors.last().unwrap()));
+                assert_eq!(
+                    expected_ancestors,
+                    found.map(|ancestors| ancestors.into_iter().copied().collect())
+                );
+
+                assert_eq!(target <= 10, tree.contains_value(&target));
+            }
+        }
+    }};
+}
+
+#[test]
+fn tree_searches_match_traversal_order() {
+    assert_searches!(create_tree_for_testing());
+}
+
+#[test]
+fn binary_tree_searches_match_traversal_order() {
+    assert_searches!(create_binary_tree_for_testing());
+}
+
+#[test]
+fn searches_stop_at_the_first_match() {
+    let tree = create_tree_for_testing();
+    let mut visited = Vec::new();
+    let found = tree.find_ref(SearchOrder::DepthFirst, |value| {
+        visited.push(**value);
+        **value == 4
+    });
+    assert_eq!(Some((&4, vec![0, 1])), found);
+    assert_eq!(vec![0, 1, 3, 4], visited);
+
+    let binary_tree = create_binary_tree_for_testing();
+    let mut visited = Vec::new();
+    let found = binary_tree.find_ref(SearchOrder::BreadthFirst, |value| {
+        visited.push(**value);
+        **value == 4
+    });
+    assert_eq!(Some((&4, vec![0, 1])), found);
+    assert_eq!(vec![0, 1, 2, 3, 4], visited);
+}
+
+#[test]
+fn find_mut_updates_the_match() {
+    let mut tree = create_binary_tree_for_testing();
+    let (value, path) = tree
+        .find_mut(SearchOrder::BreadthFirst, |value| **value > 6)
+        .unwrap();
+    *value = 70;
+    assert_eq!(vec![1, 1, 0], path);
+    assert_eq!(
+        Some((&70, vec![1, 1, 0])),
+        tree.find_ref(SearchOrder::DepthFirst, |value| **value == 70)
+    );
+    assert!(!tree.contains_value(&7));
+}
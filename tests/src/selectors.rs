@@ -0,0 +1,204 @@
+use tree_iterators_rs::prelude::{BinaryTree, Selector, Tree};
+
+use crate::{
+    create_binary_tree_for_testing, create_tree_for_testing, get_value_to_path_map,
+    get_value_to_path_map_binary,
+};
+
+fn expected_matches(values: &[usize], binary: bool) -> Vec<(Vec<usize>, usize)> {
+    let value_to_path = if binary {
+        get_value_to_path_map_binary()
+    } else {
+        get_value_to_path_map()
+    };
+
+    values
+        .iter()
+        .map(|value| (value_to_path[value].clone(), *value))
+        .collect()
+}
+
+fn assert_selects<'a>(
+    tree: &'a Tree<usize>,
+    binary_tree: &'a BinaryTree<usize>,
+    selector: &Selector<&'a usize>,
+    expected: &[usize],
+) {
+    let matches = selector
+        .select(tree)
+        .map(|(path, value)| (path, *value))
+        .collect::<Vec<_>>();
+    assert_eq!(expected_matches(expected, false), matches);
+
+    let matches = selector
+        .select_binary(binary_tree)
+        .map(|(path, value)| (path, *value))
+        .collect::<Vec<_>>();
+    assert_eq!(expected_matches(expected, true), matches);
+}
+
+#[test]
+fn empty_selector_matches_nothing() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    assert_selects(&tree, &binary_tree, &Selector::new(), &[]);
+}
+
+#[test]
+fn first_child_step_only_matches_root() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    assert_selects(&tree, &binary_tree, &Selector::new().child(|_| true), &[0]);
+    assert_selects(
+        &tree,
+        &binary_tree,
+        &Selector::new().child(|value: &&usize| **value == 1),
+        &[],
+    );
+}
+
+#[test]
+fn descendant_filters_values() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    let selector = Selector::new().descendant(|value: &&usize| (**value).is_multiple_of(2));
+    assert_selects(&tree, &binary_tree, &selector, &[0, 4, 2, 6, 8, 10]);
+
+    let selector = Selector::new()
+        .descendant(|value: &&usize| (**value).is_multiple_of(2))
+        .filter(|value| **value > 4);
+    assert_selects(&tree, &binary_tree, &selector, &[6, 8, 10]);
+}
+
+#[test]
+fn child_combinator() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    let selector = Selector::new()
+        .descendant(|value: &&usize| **value == 2)
+        .child(|_| true);
+    assert_selects(&tree, &binary_tree, &selector, &[5, 6]);
+
+    let selector = Selector::new()
+        .descendant(|value: &&usize| **value == 0)
+        .child(|value| **value == 3);
+    assert_selects(&tree, &binary_tree, &selector, &[]);
+
+    let selector = Selector::new()
+        .child(|_: &&usize| true)
+        .child(|_| true)
+        .child(|_| true);
+    assert_selects(&tree, &binary_tree, &selector, &[3, 4, 5, 6]);
+}
+
+#[test]
+fn descendant_combinator() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    let selector = Selector::new()
+        .descendant(|value: &&usize| **value == 2)
+        .descendant(|value| **value > 6);
+    assert_selects(&tree, &binary_tree, &selector, &[7, 8, 9, 10]);
+
+    let selector = Selector::new()
+        .descendant(|value: &&usize| **value == 1)
+        .descendant(|value| **value > 6);
+    assert_selects(&tree, &binary_tree, &selector, &[]);
+
+    // requires backtracking past the nearest matching ancestor.
+    let selector = Selector::new()
+        .descendant(|value: &&usize| **value == 6)
+        .child(|value| **value == 7)
+        .descendant(|value| **value >= 9);
+    assert_selects(&tree, &binary_tree, &selector, &[9, 10]);
+}
+
+#[test]
+fn nth_child() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    let selector = Selector::new().descendant(|_| true).nth_child(0);
+    assert_eq!(
+        expected_matches(&[1, 3, 5, 7, 8, 9, 10], false),
+        selector
+            .select(&tree)
+            .map(|(path, value)| (path, *value))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        expected_matches(&[1, 3, 5, 7, 9], true),
+        selector
+            .select_binary(&binary_tree)
+            .map(|(path, value)| (path, *value))
+            .collect::<Vec<_>>()
+    );
+
+    let selector = Selector::new().descendant(|_| true).nth_child(1);
+    assert_eq!(
+        expected_matches(&[4, 2, 6], false),
+        selector
+            .select(&tree)
+            .map(|(path, value)| (path, *value))
+            .collect::<Vec<_>>()
+    );
+
+    // in a binary tree, 8 and 10 are right children.
+    assert_eq!(
+        expected_matches(&[4, 2, 6, 8, 10], true),
+        selector
+            .select_binary(&binary_tree)
+            .map(|(path, value)| (path, *value))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parent_matches() {
+    let tree = create_tree_for_testing();
+    let binary_tree = create_binary_tree_for_testing();
+    let selector = Selector::new()
+        .descendant(|_| true)
+        .parent_matches(|value: &&usize| **value == 1);
+    assert_selects(&tree, &binary_tree, &selector, &[3, 4]);
+
+    let selector = Selector::new()
+        .child(|_| true)
+        .parent_matches(|_: &&usize| true);
+    assert_selects(&tree, &binary_tree, &selector, &[]);
+}
+
+#[test]
+fn matches_context() {
+    let selector = Selector::new()
+        .descendant(|value: &usize| *value == 2)
+        .child(|_| true)
+        .nth_child(1);
+    assert!(selector.matches(&[0, 2, 6], &[1, 1]));
+    assert!(!selector.matches(&[0, 2, 5], &[1, 0]));
+    assert!(!selector.matches(&[], &[]));
+}
+
+#[test]
+#[should_panic]
+fn nth_child_without_step_panics() {
+    let _ = Selector::<usize>::new().nth_child(0);
+}
+
+#[test]
+fn descendant_steps_do_not_backtrack() {
+    let mut selector = Selector::new().descendant(|value: &usize| *value == 2);
+    for _ in 0..20 {
+        selector = selector.descendant(|value| *value == 0);
+    }
+    let selector = selector.descendant(|value| *value == 1);
+
+    let mut ancestors = vec![0; 200];
+    ancestors.push(1);
+    let path = vec![0; ancestors.len() - 1];
+    assert!(!selector.matches(&ancestors, &path));
+
+    ancestors[179] = 2;
+    assert!(selector.matches(&ancestors, &path));
+    assert!(selector.matches(&ancestors[179..], &path[179..]));
+    assert!(!selector.matches(&ancestors[180..], &path[180..]));
+}
@@ -0,0 +1,170 @@
+use std::rc::Rc;
+
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::{BorrowedTreeNode, SharedTree, Tree};
+
+use crate::create_tree_for_testing;
+
+fn create_tree_with_duplicates() -> Tree<usize> {
+    let mut tree = create_tree_for_testing();
+    let left = tree.children[0].clone();
+    tree.children.push(left.clone());
+    tree.children[1].children.push(left);
+    tree
+}
+
+#[test]
+fn compress_shares_identical_subtrees() {
+    let compressed = create_tree_with_duplicates().compress();
+
+    assert!(Rc::ptr_eq(&compressed.children[0], &compressed.children[2]));
+    assert!(Rc::ptr_eq(
+        &compressed.children[0],
+        &compressed.children[1].children[2]
+    ));
+    assert!(!Rc::ptr_eq(
+        &compressed.children[0],
+        &compressed.children[1]
+    ));
+    // referenced once by each of its 3 parents.
+    assert_eq!(3, Rc::strong_count(&compressed.children[0]));
+}
+
+#[test]
+fn compress_shares_identical_leaves() {
+    let tree = Tree {
+        value: 0,
+        children: vec![
+            Tree {
+                value: 1,
+                children: vec![Tree {
+                    value: 2,
+                    children: Vec::new(),
+                }],
+            },
+            Tree {
+                value: 2,
+                children: Vec::new(),
+            },
+            Tree {
+                value: 1,
+                children: Vec::new(),
+            },
+        ],
+    };
+
+    let compressed = tree.clone().compress();
+    assert!(Rc::ptr_eq(
+        &compressed.children[0].children[0],
+        &compressed.children[1]
+    ));
+    // same value, different children
+    assert!(!Rc::ptr_eq(
+        &compressed.children[0],
+        &compressed.children[2]
+    ));
+    assert_eq!(tree, compressed.expand());
+}
+
+#[test]
+fn compressed_traversals_match_tree() {
+    let tree = create_tree_with_duplicates();
+    let compressed = tree.clone().compress();
+
+    assert_eq!(
+        tree.dfs_preorder_iter().collect::<Vec<_>>(),
+        compressed.dfs_preorder_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        tree.dfs_postorder_iter().collect::<Vec<_>>(),
+        compressed.dfs_postorder_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        tree.bfs_iter().collect::<Vec<_>>(),
+        compressed.bfs_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        tree.bfs_iter().leaves().collect::<Vec<_>>(),
+        compressed.bfs_iter().leaves().collect::<Vec<_>>()
+    );
+
+    let mut expected = Vec::new();
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        expected.push((context.path().to_vec(), context.ancestors().to_vec()));
+    }
+
+    let mut actual = Vec::new();
+    let mut iter = compressed.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        actual.push((context.path().to_vec(), context.ancestors().to_vec()));
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn compressed_fold_and_map_match_tree() {
+    let tree = create_tree_with_duplicates();
+    let compressed = tree.clone().compress();
+
+    let sum = |children: Vec<usize>, value: &usize| children.into_iter().sum::<usize>() + *value;
+    assert_eq!(tree.fold_ref(sum), compressed.fold_ref(sum));
+    assert_eq!(
+        tree.map_ref(|value| value * 2),
+        compressed.map_ref(|value| value * 2)
+    );
+    assert_eq!(
+        tree.prune_ref(|value| **value == 1),
+        compressed.prune_ref(|value| **value == 1)
+    );
+}
+
+#[test]
+fn expand_round_trips() {
+    let tree = create_tree_with_duplicates();
+    assert_eq!(tree, tree.clone().compress().expand());
+
+    let single = Tree {
+        value: "root",
+        children: Vec::new(),
+    };
+    assert_eq!(
+        SharedTree {
+            value: "root",
+            children: Vec::new(),
+        },
+        single.clone().compress()
+    );
+    assert_eq!(single, single.clone().compress().expand());
+}
+
+#[test]
+fn compress_deep_tree() {
+    let mut tree = Tree {
+        value: 0,
+        children: Vec::new(),
+    };
+    // a long chain where every level also has a duplicated leaf.
+    for i in 1..1_000 {
+        tree = Tree {
+            value: i % 2,
+            children: vec![
+                Tree {
+                    value: 7,
+                    children: Vec::new(),
+                },
+                tree,
+            ],
+        };
+    }
+
+    let compressed = tree.clone().compress();
+    assert_eq!(tree.size(), compressed.size());
+    assert_eq!(tree.height(), compressed.height());
+    assert!(Rc::ptr_eq(
+        &compressed.children[0],
+        &compressed.children[1].children[0]
+    ));
+    assert_eq!(tree, compressed.expand());
+}
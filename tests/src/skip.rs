@@ -0,0 +1,263 @@
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+use super::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// The (skip_children, skip_subtree) triggers each test case runs with.
+const CASES: &[(&[usize], &[usize])] = &[
+    (&[], &[]),
+    (&[0], &[]),
+    (&[2], &[]),
+    (&[1, 6], &[]),
+    (&[], &[0]),
+    (&[], &[1]),
+    (&[], &[3]),
+    (&[], &[5, 8]),
+    (&[1], &[5]),
+    (&[7], &[2]),
+];
+
+/// Builds the expected output by walking the full traversal and filtering
+/// out every node that one of the previously kept trigger nodes skips.
+fn expected(
+    visited: &[(usize, Vec<usize>)],
+    skip_children: &[usize],
+    skip_subtree: &[usize],
+) -> Vec<usize> {
+    let mut kept: Vec<&(usize, Vec<usize>)> = Vec::new();
+    for node in visited {
+        let (_, path) = node;
+        let skipped = kept.iter().any(|(value, trigger)| {
+            let is_descendant = path.len() > trigger.len() && path.starts_with(trigger);
+            let parent = &trigger[..trigger.len().saturating_sub(1)];
+            let is_later_sibling_subtree = !trigger.is_empty()
+                && path.len() >= trigger.len()
+                && path.starts_with(parent)
+                && path[parent.len()] > trigger[parent.len()];
+
+            (skip_children.contains(value) || skip_subtree.contains(value)) && is_descendant
+                || skip_subtree.contains(value) && is_later_sibling_subtree
+        });
+
+        if !skipped {
+            kept.push(node);
+        }
+    }
+
+    kept.into_iter().map(|(value, _)| *value).collect()
+}
+
+macro_rules! visited {
+    ($iter: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(context) = iter.next() {
+            result.push((
+                **context.ancestors().last().unwrap(),
+                context.path().to_vec(),
+            ));
+        }
+        result
+    }};
+}
+
+macro_rules! run {
+    ($iter: expr, $skip_children: expr, $skip_subtree: expr, $value: expr) => {{
+        let mut iter = $iter;
+        let mut result = Vec::new();
+        while let Some(item) = iter.next() {
+            let value: usize = $value(item);
+            result.push(value);
+            if $skip_children.contains(&value) {
+                iter.skip_children();
+            }
+            if $skip_subtree.contains(&value) {
+                iter.skip_subtree();
+            }
+        }
+        result
+    }};
+}
+
+macro_rules! assert_skips {
+    ($tree: expr, $iter: ident, $iter_mut: ident, $into_iter: ident) => {{
+        let mut tree = $tree;
+        let visited = visited!(tree.$iter().attach_context());
+        for (skip_children, skip_subtree) in CASES {
+            let expected = expected(&visited, skip_children, skip_subtree);
+
+            let result = run!(
+                tree.$iter(),
+                skip_children,
+                skip_subtree,
+                |value: &usize| { *value }
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.$iter_mut(),
+                skip_children,
+                skip_subtree,
+                |value: &mut usize| *value
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.clone().$into_iter(),
+                skip_children,
+                skip_subtree,
+                |value: usize| value
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.$iter().attach_ancestors(),
+                skip_children,
+                skip_subtree,
+                |ancestors: &[&usize]| **ancestors.last().unwrap()
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.clone().$into_iter().attach_ancestors(),
+                skip_children,
+                skip_subtree,
+                |ancestors: &[usize]| *ancestors.last().unwrap()
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.$iter().attach_context(),
+                skip_children,
+                skip_subtree,
+                |context: &TreeContext<&usize, _>| **context.ancestors().last().unwrap()
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.$iter_mut().attach_context(),
+                skip_children,
+                skip_subtree,
+                |context: &TreeContext<&mut usize, _>| **context.ancestors().last().unwrap()
+            );
+            assert_eq!(expected, result);
+
+            let result = run!(
+                tree.clone().$into_iter().attach_context(),
+                skip_children,
+                skip_subtree,
+                |context: &TreeContext<usize, _>| *context.ancestors().last().unwrap()
+            );
+            assert_eq!(expected, result);
+        }
+    }};
+}
+
+#[test]
+fn dfs_preorder_skips() {
+    assert_skips!(
+        create_tree_for_testing(),
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder
+    );
+}
+
+#[test]
+fn binary_dfs_preorder_skips() {
+    assert_skips!(
+        create_binary_tree_for_testing(),
+        dfs_preorder_iter,
+        dfs_preorder_iter_mut,
+        dfs_preorder
+    );
+}
+
+#[test]
+fn bfs_skips() {
+    assert_skips!(create_tree_for_testing(), bfs_iter, bfs_iter_mut, bfs);
+}
+
+#[test]
+fn binary_bfs_skips() {
+    assert_skips!(
+        create_binary_tree_for_testing(),
+        bfs_iter,
+        bfs_iter_mut,
+        bfs
+    );
+}
+
+#[test]
+fn skipping_before_the_first_node_does_nothing() {
+    let tree = create_tree_for_testing();
+    let expected = tree.bfs_iter().copied().collect::<Vec<_>>();
+
+    let mut iter = tree.bfs_iter();
+    iter.skip_children();
+    iter.skip_subtree();
+    assert_eq!(expected, iter.copied().collect::<Vec<_>>());
+
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    iter.skip_children();
+    iter.skip_subtree();
+    let mut count = 0;
+    while iter.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(expected.len(), count);
+}
+
+#[test]
+fn skipping_twice_only_skips_once() {
+    let tree = create_tree_for_testing();
+    let mut iter = tree.dfs_preorder_iter();
+    let mut result = Vec::new();
+    while let Some(value) = iter.next() {
+        result.push(*value);
+        if *value == 1 {
+            iter.skip_children();
+            iter.skip_children();
+        }
+    }
+
+    assert_eq!(vec![0, 1, 2, 5, 6, 7, 8, 9, 10], result);
+}
+
+#[test]
+fn context_paths_survive_skipping() {
+    let binary_tree = create_binary_tree_for_testing();
+    let tree = create_tree_for_testing();
+
+    let full = visited!(tree.bfs_iter().attach_context());
+    let mut iter = tree.bfs_iter().attach_context();
+    let mut result = Vec::new();
+    while let Some(context) = iter.next() {
+        let value = **context.ancestors().last().unwrap();
+        result.push((value, context.path().to_vec()));
+        if value == 1 {
+            iter.skip_subtree();
+        }
+    }
+    let expected = full
+        .into_iter()
+        .filter(|(value, _)| ![2, 3, 4, 5, 6, 7, 8, 9, 10].contains(value))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, result);
+
+    let full = visited!(binary_tree.dfs_preorder_iter().attach_context());
+    let mut iter = binary_tree.dfs_preorder_iter().attach_context();
+    let mut result = Vec::new();
+    while let Some(context) = iter.next() {
+        let value = **context.ancestors().last().unwrap();
+        result.push((value, context.path().to_vec()));
+        if value == 1 || value == 7 {
+            iter.skip_children();
+        }
+    }
+    let expected = full
+        .into_iter()
+        .filter(|(value, _)| ![3, 4, 8, 9, 10].contains(value))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, result);
+}
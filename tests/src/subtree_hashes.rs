@@ -0,0 +1,182 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasherDefault, Hasher},
+};
+
+use tree_iterators_rs::prelude::{
+    BinaryTree, BorrowedBinaryTreeNode, BorrowedTreeNode, OwnedBinaryTreeNode, OwnedTreeNode, Tree,
+};
+
+use crate::{create_binary_tree_for_testing, create_tree_for_testing};
+
+/// A hasher that sends everything to the same bucket so that
+/// collision handling can be tested.
+#[derive(Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+fn create_tree_with_duplicates() -> Tree<usize> {
+    let mut tree = create_tree_for_testing();
+    let left = tree.children[0].clone();
+    tree.children.push(left.clone());
+    tree.children[1].children.push(left);
+    tree
+}
+
+#[test]
+fn subtree_hashes_match_structure() {
+    let tree = create_tree_with_duplicates();
+    let build_hasher = RandomState::new();
+    let hashes = tree.subtree_hashes_ref(&build_hasher);
+
+    assert_eq!(tree.size(), hashes.size());
+    assert_eq!(tree.height(), hashes.height());
+
+    assert_eq!(hashes.children[0].value, hashes.children[2].value);
+    assert_eq!(
+        hashes.children[0].value,
+        hashes.children[1].children[2].value
+    );
+    assert_ne!(hashes.children[0].value, hashes.children[1].value);
+
+    // same value, different children
+    let single = Tree {
+        value: 1,
+        children: Vec::new(),
+    };
+    assert_ne!(
+        hashes.children[0].value,
+        single.subtree_hashes_ref(&build_hasher).value
+    );
+}
+
+#[test]
+fn find_duplicate_subtrees() {
+    let tree = create_tree_with_duplicates();
+    let expected = vec![
+        vec![vec![0], vec![1, 2], vec![2]],
+        vec![vec![0, 0], vec![1, 2, 0], vec![2, 0]],
+        vec![vec![0, 1], vec![1, 2, 1], vec![2, 1]],
+    ];
+
+    assert_eq!(expected, tree.find_duplicate_subtrees(&RandomState::new()));
+    assert_eq!(
+        expected,
+        tree.find_duplicate_subtrees(&BuildHasherDefault::<CollidingHasher>::default())
+    );
+
+    for group in expected {
+        let first = tree.at_path_ref(&group[0]).unwrap();
+        for path in &group[1..] {
+            assert_eq!(first, tree.at_path_ref(path).unwrap());
+        }
+    }
+}
+
+#[test]
+fn find_duplicate_subtrees_without_duplicates() {
+    let tree = create_tree_for_testing();
+    assert!(tree
+        .find_duplicate_subtrees(&BuildHasherDefault::<CollidingHasher>::default())
+        .is_empty());
+
+    let binary_tree = create_binary_tree_for_testing();
+    assert!(binary_tree
+        .find_duplicate_subtrees(&BuildHasherDefault::<CollidingHasher>::default())
+        .is_empty());
+}
+
+#[test]
+fn binary_subtree_hashes_respect_empty_slots() {
+    let left_only = BinaryTree {
+        value: 0,
+        left: Some(Box::new(BinaryTree {
+            value: 1,
+            left: None,
+            right: None,
+        })),
+        right: None,
+    };
+    let right_only = BinaryTree {
+        value: 0,
+        left: None,
+        right: Some(Box::new(BinaryTree {
+            value: 1,
+            left: None,
+            right: None,
+        })),
+    };
+
+    let build_hasher = RandomState::new();
+    assert_ne!(
+        left_only.subtree_hashes_ref(&build_hasher).value,
+        right_only.subtree_hashes_ref(&build_hasher).value
+    );
+
+    let tree = BinaryTree {
+        value: 2,
+        left: Some(Box::new(left_only)),
+        right: Some(Box::new(right_only)),
+    };
+    assert_eq!(
+        vec![vec![vec![0, 0], vec![1, 1]]],
+        tree.find_duplicate_subtrees(&BuildHasherDefault::<CollidingHasher>::default())
+    );
+}
+
+#[test]
+fn binary_find_duplicate_subtrees() {
+    let mut tree = create_binary_tree_for_testing();
+    let left = tree.left.clone();
+    tree.right.as_mut().unwrap().right.as_mut().unwrap().right = left;
+
+    let expected = vec![
+        vec![vec![0], vec![1, 1, 1]],
+        vec![vec![0, 0], vec![1, 1, 1, 0]],
+        vec![vec![0, 1], vec![1, 1, 1, 1]],
+    ];
+    assert_eq!(expected, tree.find_duplicate_subtrees(&RandomState::new()));
+    assert_eq!(
+        expected,
+        tree.find_duplicate_subtrees(&BuildHasherDefault::<CollidingHasher>::default())
+    );
+
+    let hashes = tree.subtree_hashes_ref(&RandomState::new());
+    for group in expected {
+        let first = hashes.at_path_ref(&group[0]).unwrap().value;
+        for path in &group[1..] {
+            assert_eq!(first, hashes.clone().at_path(path).unwrap().value);
+        }
+    }
+}
+
+#[test]
+fn duplicate_leaf_values_are_grouped() {
+    let tree = Tree {
+        value: 0,
+        children: vec![
+            Tree {
+                value: 1,
+                children: Vec::new(),
+            },
+            Tree {
+                value: 1,
+                children: Vec::new(),
+            },
+        ],
+    };
+
+    let groups = tree.find_duplicate_subtrees(&RandomState::new());
+    assert_eq!(vec![vec![vec![0], vec![1]]], groups);
+    assert_eq!(
+        Some(1),
+        tree.clone().at_path(&groups[0][1]).map(|node| node.value)
+    );
+}
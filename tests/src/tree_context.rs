@@ -0,0 +1,192 @@
+use super::{
+    bfs::get_expected_order_bfs, create_binary_tree_for_testing, create_tree_for_testing,
+    dfs_inorder::get_expected_order_dfs_inorder, dfs_postorder::get_expected_order_dfs_postorder,
+    dfs_preorder::get_expected_order_dfs_preorder,
+};
+use core::borrow::Borrow;
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+/// Gets the (sibling index, sibling count) of each value. The test trees
+/// line up so the same positions apply to both the Tree and the BinaryTree.
+fn get_expected_sibling_position(value: usize) -> (usize, usize) {
+    match value {
+        0 => (0, 1),
+        1 | 3 | 5 => (0, 2),
+        2 | 4 | 6 => (1, 2),
+        7..=10 => (0, 1),
+        _ => panic!("unexpected value"),
+    }
+}
+
+macro_rules! assert_context_positions {
+    ($iter: expr, $expected_order: expr) => {{
+        let expected_order = $expected_order;
+        let mut iter = $iter;
+        let mut i = 0;
+        while let Some(context) = iter.next() {
+            let value: usize = *context.ancestors()[context.depth()].borrow();
+            assert_eq!(expected_order[i], value);
+            assert_eq!(i, context.index());
+
+            let (sibling_index, sibling_count) = get_expected_sibling_position(value);
+            assert_eq!(sibling_index, context.sibling_index());
+            assert_eq!(sibling_count, context.sibling_count());
+            assert_eq!(
+                sibling_index + 1 == sibling_count,
+                context.is_last_sibling()
+            );
+
+            for depth in 0..=context.depth() {
+                let (sibling_index, sibling_count) =
+                    get_expected_sibling_position(*context.ancestors()[depth].borrow());
+                assert_eq!(
+                    sibling_index + 1 < sibling_count,
+                    context.ancestor_has_next_sibling(depth)
+                );
+            }
+            i += 1;
+        }
+        assert_eq!(expected_order.len(), i);
+    }};
+}
+
+#[test]
+fn tree_context_sibling_positions_and_index() {
+    let mut tree = create_tree_for_testing();
+    let preorder = get_expected_order_dfs_preorder();
+    let postorder = get_expected_order_dfs_postorder();
+    let bfs = get_expected_order_bfs();
+
+    assert_context_positions!(tree.dfs_preorder_iter().attach_context(), preorder);
+    assert_context_positions!(tree.dfs_postorder_iter().attach_context(), postorder);
+    assert_context_positions!(tree.bfs_iter().attach_context(), bfs);
+
+    assert_context_positions!(tree.dfs_preorder_iter_mut().attach_context(), preorder);
+    assert_context_positions!(tree.dfs_postorder_iter_mut().attach_context(), postorder);
+    assert_context_positions!(tree.bfs_iter_mut().attach_context(), bfs);
+
+    assert_context_positions!(tree.clone().dfs_preorder().attach_context(), preorder);
+    assert_context_positions!(tree.clone().dfs_postorder().attach_context(), postorder);
+    assert_context_positions!(tree.bfs().attach_context(), bfs);
+}
+
+/// A node whose children iterator can't report its exact length.
+struct UnsizedChildren {
+    value: usize,
+    children: Vec<UnsizedChildren>,
+}
+
+impl<'a> BorrowedTreeNode<'a> for UnsizedChildren {
+    type BorrowedValue = &'a usize;
+    type BorrowedChildren =
+        core::iter::Filter<core::slice::Iter<'a, UnsizedChildren>, fn(&&'a Self) -> bool>;
+
+    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, Self::BorrowedChildren) {
+        (&self.value, self.children.iter().filter(|_| true))
+    }
+}
+
+#[test]
+fn tree_context_sibling_positions_with_unsized_children() {
+    let tree =
+        create_tree_for_testing().fold(|children, value| UnsizedChildren { value, children });
+
+    assert_context_positions!(
+        tree.dfs_preorder_iter().attach_context(),
+        get_expected_order_dfs_preorder()
+    );
+    assert_context_positions!(
+        tree.dfs_postorder_iter().attach_context(),
+        get_expected_order_dfs_postorder()
+    );
+    assert_context_positions!(tree.bfs_iter().attach_context(), get_expected_order_bfs());
+}
+
+#[test]
+fn binary_tree_context_sibling_positions_and_index() {
+    let mut tree = create_binary_tree_for_testing();
+    let preorder = get_expected_order_dfs_preorder();
+    let inorder = get_expected_order_dfs_inorder();
+    let postorder = get_expected_order_dfs_postorder();
+    let bfs = get_expected_order_bfs();
+
+    assert_context_positions!(tree.dfs_preorder_iter().attach_context(), preorder);
+    assert_context_positions!(tree.dfs_inorder_iter().attach_context(), inorder);
+    assert_context_positions!(tree.dfs_postorder_iter().attach_context(), postorder);
+    assert_context_positions!(tree.bfs_iter().attach_context(), bfs);
+
+    assert_context_positions!(tree.dfs_preorder_iter_mut().attach_context(), preorder);
+    assert_context_positions!(tree.dfs_inorder_iter_mut().attach_context(), inorder);
+    assert_context_positions!(tree.dfs_postorder_iter_mut().attach_context(), postorder);
+    assert_context_positions!(tree.bfs_iter_mut().attach_context(), bfs);
+
+    assert_context_positions!(tree.clone().dfs_preorder().attach_context(), preorder);
+    assert_context_positions!(tree.clone().dfs_inorder().attach_context(), inorder);
+    assert_context_positions!(tree.clone().dfs_postorder().attach_context(), postorder);
+    assert_context_positions!(tree.bfs().attach_context(), bfs);
+}
+
+#[test]
+fn binary_tree_sibling_positions_ignore_empty_slots() {
+    let tree = BinaryTree {
+        value: 0,
+        left: None,
+        right: Some(Box::new(BinaryTree {
+            value: 1,
+            left: Some(Box::new(BinaryTree {
+                value: 2,
+                left: None,
+                right: None,
+            })),
+            right: Some(Box::new(BinaryTree {
+                value: 3,
+                left: None,
+                right: None,
+            })),
+        })),
+    };
+
+    let mut positions = Vec::new();
+    let mut iter = tree.dfs_preorder_iter().attach_context();
+    while let Some(context) = iter.next() {
+        positions.push((
+            *context.ancestors()[context.depth()],
+            context.sibling_index(),
+            context.sibling_count(),
+        ));
+    }
+
+    assert_eq!(vec![(0, 0, 1), (1, 0, 1), (2, 0, 2), (3, 1, 2)], positions);
+}
+
+#[test]
+fn tree_context_index_continues_across_collections() {
+    let trees = [create_tree_for_testing(), create_tree_for_testing()];
+    let preorder = get_expected_order_dfs_preorder();
+
+    let mut indexes = Vec::new();
+    let mut iter = trees.iter().dfs_preorder_each_iter().attach_context();
+    while let Some(context) = iter.next() {
+        assert_eq!(
+            preorder[context.index() % preorder.len()],
+            *context.ancestors()[context.depth()]
+        );
+        indexes.push(context.index());
+    }
+    assert_eq!((0..preorder.len() * 2).collect::<Vec<_>>(), indexes);
+
+    let binary_trees = vec![
+        create_binary_tree_for_testing(),
+        create_binary_tree_for_testing(),
+    ];
+    let mut indexes = Vec::new();
+    let mut iter = binary_trees.bfs_each().attach_context();
+    while let Some(context) = iter.next() {
+        if context.depth() == 0 {
+            assert!(context.is_last_sibling());
+        }
+        indexes.push(context.index());
+    }
+    assert_eq!((0..preorder.len() * 2).collect::<Vec<_>>(), indexes);
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use streaming_iterator::StreamingIterator;
+use tree_iterators_rs::prelude::*;
+
+/// A deterministic set of short words over a small alphabet so that many of
+/// them share prefixes.
+fn pseudo_random_words() -> Vec<String> {
+    let mut state = 7_u64;
+    (0..300)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let len = (state >> 60) as usize % 5;
+            (0..len)
+                .map(|i| (b'a' + ((state >> (8 * i + 8)) % 3) as u8) as char)
+                .collect()
+        })
+        .collect()
+}
+
+fn assert_matches(expected: &BTreeMap<String, usize>, trie: &Trie<char, usize>) {
+    assert_eq!(expected.len(), trie.len());
+    assert_eq!(expected.is_empty(), trie.is_empty());
+    assert_eq!(
+        expected
+            .iter()
+            .map(|(key, value)| (key.clone(), value))
+            .collect::<Vec<_>>(),
+        trie.iter()
+            .map(|(key, value)| (key.into_iter().collect::<String>(), value))
+            .collect::<Vec<_>>()
+    );
+
+    // removing keys must not leave behind branches that lead nowhere.
+    for (part, value) in trie.root().dfs_preorder_iter().leaves() {
+        assert!(part.is_none() || value.is_some());
+    }
+}
+
+#[test]
+fn insert_get_and_remove_match_btree_map() {
+    let words = pseudo_random_words();
+    let mut expected = BTreeMap::new();
+    let mut trie = Trie::new();
+    for (i, word) in words.iter().enumerate() {
+        assert_eq!(
+            expected.insert(word.clone(), i),
+            trie.insert(word.chars(), i)
+        );
+    }
+    assert_matches(&expected, &trie);
+
+    for word in words
+        .iter()
+        .chain(["abcabc".to_string(), "d".to_string()].iter())
+    {
+        assert_eq!(expected.get(word), trie.get(word.chars()));
+        assert_eq!(expected.contains_key(word), trie.contains_key(word.chars()));
+    }
+
+    for word in words.iter().step_by(2) {
+        assert_eq!(expected.remove(word), trie.remove(word.chars()));
+        assert_matches(&expected, &trie);
+    }
+
+    for word in words.iter() {
+        assert_eq!(expected.remove(word), trie.remove(word.chars()));
+    }
+    assert!(trie.is_empty());
+    assert!(trie.root().children.is_empty());
+}
+
+#[test]
+fn prefix_queries() {
+    let trie = ["", "a", "ab", "abc", "abd", "b", "bcd"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| (word.chars(), i))
+        .collect::<Trie<_, _>>();
+
+    assert_eq!(Some((0, &0)), trie.longest_prefix_match("".chars()));
+    assert_eq!(Some((2, &2)), trie.longest_prefix_match("abx".chars()));
+    assert_eq!(Some((3, &3)), trie.longest_prefix_match("abcd".chars()));
+    assert_eq!(Some((1, &5)), trie.longest_prefix_match("bc".chars()));
+    assert_eq!(Some((0, &0)), trie.longest_prefix_match("x".chars()));
+
+    let collect = |prefix: &str| {
+        trie.iter_prefix(prefix.chars())
+            .map(|(key, value)| (key.into_iter().collect::<String>(), *value))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        vec![
+            ("ab".to_string(), 2),
+            ("abc".to_string(), 3),
+            ("abd".to_string(), 4)
+        ],
+        collect("ab")
+    );
+    assert_eq!(vec![("bcd".to_string(), 6)], collect("bc"));
+    assert_eq!(Vec::<(String, usize)>::new(), collect("abcd"));
+    assert_eq!(7, collect("").len());
+    assert_eq!(
+        vec!["", "a", "ab", "abc", "abd", "b", "bcd"],
+        trie.keys()
+            .map(|key| key.into_iter().collect::<String>())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn trie_nodes_work_with_tree_apis() {
+    let trie = ["car", "cart", "cat", "dog"]
+        .into_iter()
+        .map(|word| (word.chars(), word.len()))
+        .collect::<Trie<_, _>>();
+
+    let mut keys = Vec::new();
+    let mut iter = trie.root().bfs_iter().attach_ancestors();
+    while let Some(ancestors) = iter.next() {
+        if ancestors.last().unwrap().1.is_some() {
+            keys.push(
+                ancestors
+                    .iter()
+                    .filter_map(|(part, _)| *part)
+                    .collect::<String>(),
+            );
+        }
+    }
+    assert_eq!(vec!["car", "cat", "dog", "cart"], keys);
+
+    let pruned = trie
+        .root()
+        .prune_ref(|(part, _)| *part == Some('r'))
+        .unwrap();
+    assert_eq!(
+        vec![Some(&3), Some(&3)],
+        pruned
+            .dfs_preorder_iter()
+            .map(|(_, value)| value.as_ref())
+            .filter(|value| value.is_some())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(3, trie.root().dfs_preorder_iter().leaves().count());
+}
@@ -0,0 +1,413 @@
+use alloc::boxed::Box;
+use core::{borrow::Borrow, cmp::Ordering, mem};
+
+use crate::prelude::{BorrowedBinaryTreeNode, MutBorrowedBinaryTreeNode};
+
+/// An ordered map backed by a self-balancing AVL tree.
+///
+/// The nodes of the tree ([`AvlNode`]) implement [`BorrowedBinaryTreeNode`] and
+/// [`MutBorrowedBinaryTreeNode`], so all of the borrowed binary tree APIs can be
+/// used on the map through [`root`](AvlTree::root) and
+/// [`root_mut`](AvlTree::root_mut). Each node's value is a (key, value) pair,
+/// and a [`dfs_inorder_iter`](BorrowedBinaryTreeNode::dfs_inorder_iter) visits
+/// the entries in key order. Keys are never handed out mutably since changing
+/// them could break the ordering of the tree.
+///
+/// ### Example Usage
+/// ```rust
+/// use tree_iterators_rs::prelude::{AvlTree, BorrowedBinaryTreeNode};
+///
+/// let mut map = AvlTree::new();
+/// for (i, word) in ["delta", "alpha", "charlie", "bravo"].into_iter().enumerate() {
+///     map.insert(word, i);
+/// }
+///
+/// assert_eq!(Some(&2), map.get("charlie"));
+/// assert_eq!(
+///     vec!["alpha", "bravo", "charlie", "delta"],
+///     map.root()
+///         .unwrap()
+///         .dfs_inorder_iter()
+///         .map(|(key, _)| *key)
+///         .collect::<Vec<_>>()
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct AvlTree<K, V> {
+    root: Option<Box<AvlNode<K, V>>>,
+    len: usize,
+}
+
+/// A node in an [`AvlTree`].
+#[derive(Clone, Debug)]
+pub struct AvlNode<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Option<Box<AvlNode<K, V>>>,
+    right: Option<Box<AvlNode<K, V>>>,
+}
+
+impl<K, V> AvlTree<K, V> {
+    /// Creates an empty [`AvlTree`].
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the root node of the tree, or [`None`] if the map is empty.
+    pub fn root(&self) -> Option<&AvlNode<K, V>> {
+        self.root.as_deref()
+    }
+
+    /// Gets the root node of the tree mutably, or [`None`] if the map is empty.
+    /// Only the values of the map can be modified through the returned node.
+    pub fn root_mut(&mut self) -> Option<&mut AvlNode<K, V>> {
+        self.root.as_deref_mut()
+    }
+}
+
+impl<K, V> AvlTree<K, V>
+where
+    K: Ord,
+{
+    /// Inserts a key-value pair into the map, rebalancing the tree as needed.
+    /// If the map already had an entry for the key, its value is replaced and
+    /// the old value is returned. The key itself is not updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (root, old) = insert(self.root.take(), key, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Removes the entry for `key` from the map, rebalancing the tree as needed,
+    /// and returns its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the entry for `key` from the map, rebalancing the tree as needed,
+    /// and returns the stored key and value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (root, removed) = remove(self.root.take(), key);
+        self.root = root;
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Gets a reference to the value stored for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Gets a mutable reference to the value stored for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self.root.as_deref_mut();
+        while let Some(node) = current {
+            current = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left.as_deref_mut(),
+                Ordering::Greater => node.right.as_deref_mut(),
+                Ordering::Equal => return Some(&mut node.value),
+            };
+        }
+        None
+    }
+
+    /// Returns true if the map contains an entry for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<K, V> Default for AvlTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for AvlTree<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+impl<K, V> Extend<(K, V)> for AvlTree<K, V>
+where
+    K: Ord,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> AvlNode<K, V> {
+    /// Gets this node's key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Gets this node's value.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Gets this node's value mutably.
+    pub fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+
+    fn new(key: K, value: V) -> Box<Self> {
+        Box::new(Self {
+            key,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+impl<'a, K, V> MutBorrowedBinaryTreeNode<'a> for AvlNode<K, V>
+where
+    Self: 'a,
+{
+    type MutBorrowedValue = (&'a K, &'a mut V);
+
+    fn get_value_and_children_binary_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, [Option<&'a mut Self>; 2]) {
+        (
+            (&self.key, &mut self.value),
+            [self.left.as_deref_mut(), self.right.as_deref_mut()],
+        )
+    }
+}
+
+impl<'a, K, V> BorrowedBinaryTreeNode<'a> for AvlNode<K, V>
+where
+    Self: 'a,
+{
+    type BorrowedValue = (&'a K, &'a V);
+
+    fn get_value_and_children_binary_iter(
+        &'a self,
+    ) -> (Self::BorrowedValue, [Option<&'a Self>; 2]) {
+        (
+            (&self.key, &self.value),
+            [self.left.as_deref(), self.right.as_deref()],
+        )
+    }
+}
+
+fn height<K, V>(node: &Option<Box<AvlNode<K, V>>>) -> usize {
+    node.as_ref().map_or(0, |node| node.height)
+}
+
+fn rotate_right<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    let mut left = node
+        .left
+        .take()
+        .expect("a right rotation to have a left child");
+    node.left = left.right.take();
+    node.update_height();
+    left.right = Some(node);
+    left.update_height();
+    left
+}
+
+fn rotate_left<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    let mut right = node
+        .right
+        .take()
+        .expect("a left rotation to have a right child");
+    node.right = right.left.take();
+    node.update_height();
+    right.left = Some(node);
+    right.update_height();
+    right
+}
+
+/// Restores the AVL invariant at `node`, assuming both of its subtrees are
+/// balanced and their heights differ by at most 2.
+fn rebalance<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    node.update_height();
+    let balance_factor = node.balance_factor();
+    if balance_factor > 1 {
+        let left = node
+            .left
+            .take()
+            .expect("a left-heavy node to have a left child");
+        node.left = Some(if left.balance_factor() < 0 {
+            rotate_left(left)
+        } else {
+            left
+        });
+        return rotate_right(node);
+    }
+
+    if balance_factor < -1 {
+        let right = node
+            .right
+            .take()
+            .expect("a right-heavy node to have a right child");
+        node.right = Some(if right.balance_factor() > 0 {
+            rotate_right(right)
+        } else {
+            right
+        });
+        return rotate_left(node);
+    }
+
+    node
+}
+
+fn insert<K, V>(
+    node: Option<Box<AvlNode<K, V>>>,
+    key: K,
+    value: V,
+) -> (Box<AvlNode<K, V>>, Option<V>)
+where
+    K: Ord,
+{
+    let mut node = match node {
+        None => return (AvlNode::new(key, value), None),
+        Some(node) => node,
+    };
+
+    match key.cmp(&node.key) {
+        Ordering::Equal => {
+            let old = mem::replace(&mut node.value, value);
+            (node, Some(old))
+        }
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            (rebalance(node), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            (rebalance(node), old)
+        }
+    }
+}
+
+fn remove<K, V, Q>(
+    node: Option<Box<AvlNode<K, V>>>,
+    key: &Q,
+) -> (Option<Box<AvlNode<K, V>>>, Option<(K, V)>)
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut node = match node {
+        None => return (None, None),
+        Some(node) => node,
+    };
+
+    match key.cmp(node.key.borrow()) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), key);
+            node.left = left;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), key);
+            node.right = right;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Equal => {
+            let AvlNode {
+                key,
+                value,
+                left,
+                right,
+                ..
+            } = *node;
+
+            let replacement = match (left, right) {
+                (None, right) => right,
+                (left, None) => left,
+                (left, Some(right)) => {
+                    let (right, mut successor) = remove_min(right);
+                    successor.left = left;
+                    successor.right = right;
+                    Some(rebalance(successor))
+                }
+            };
+            (replacement, Some((key, value)))
+        }
+    }
+}
+
+/// Detaches the node with the smallest key from the subtree rooted at `node`,
+/// returning the rebalanced remainder of the subtree and the detached node.
+fn remove_min<K, V>(
+    mut node: Box<AvlNode<K, V>>,
+) -> (Option<Box<AvlNode<K, V>>>, Box<AvlNode<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (left, min) = remove_min(left);
+            node.left = left;
+            (Some(rebalance(node)), min)
+        }
+    }
+}
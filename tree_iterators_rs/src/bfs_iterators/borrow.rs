@@ -8,14 +8,20 @@ use crate::{
             BorrowedBFSLeavesIteratorWithAncestors, BorrowedBinaryBFSLeavesIteratorWithAncestors,
         },
         breadth_first::borrow::{BorrowedBinaryLeavesIterator, BorrowedLeavesIterator},
+        context_breadth_first::borrow::{
+            BorrowedBFSLeavesIteratorWithContext, BorrowedBinaryBFSLeavesIteratorWithContext,
+        },
     },
-    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode, TreeContext},
+    prelude::{opt_to_opt, BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 
 use super::{
-    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl,
+    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl, bfs_binary_resume_from,
     bfs_context_advance_iterator, bfs_context_binary_streaming_iterator_impl,
-    bfs_context_streaming_iterator_impl, bfs_next, TreeNodeVecDeque,
+    bfs_context_skip_impl, bfs_context_streaming_iterator_impl, bfs_next, bfs_resume_from,
+    bfs_size_hint, bfs_skip_impl, TreeNodeVecDeque,
 };
 
 crate::collection_iterators::borrowed_collection_iterator_impl!(
@@ -49,6 +55,19 @@ where
     traversal_queue: VecDeque<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedBFSIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_queue: self.traversal_queue.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBFSIterator<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -69,19 +88,25 @@ where
         }
     }
 
+    bfs_resume_from!(get_value_and_children_iter);
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedBFSIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => BorrowedBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => BorrowedBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> BorrowedBFSIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => BorrowedBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => BorrowedBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -92,6 +117,7 @@ where
 {
     type Item = Node::BorrowedValue;
     bfs_next!(get_value_and_children_iter);
+    bfs_size_hint!();
 }
 
 pub struct BorrowedBFSIteratorWithContext<'a, Node>
@@ -101,11 +127,35 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
-    pub(crate) iterator_queue: VecDeque<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
+    pub(crate) iterator_queue:
+        VecDeque<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
     pub(crate) current_context: TreeContext<Node::BorrowedValue, Node::BorrowedChildren>,
     pub(crate) path_counter: usize,
 }
 
+impl<'a, Node> Clone for BorrowedBFSIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    Node::BorrowedChildren: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            is_root: self.is_root,
+            tree_cache: self.tree_cache.clone_without_value(),
+            traversal_stack: self
+                .traversal_stack
+                .iter()
+                .map(TreeNodeVecDeque::clone_without_value)
+                .collect(),
+            iterator_queue: self.iterator_queue.clone(),
+            current_context: self.current_context.clone(),
+            path_counter: self.path_counter,
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBFSIteratorWithContext<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -119,8 +169,11 @@ where
             path,
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         BorrowedBFSIteratorWithContext {
             is_root: true,
@@ -132,6 +185,32 @@ where
         }
     }
 
+    bfs_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> BorrowedBFSLeavesIteratorWithContext<'a, Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        BorrowedBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -155,6 +234,27 @@ where
     pub(crate) iterator_queue: VecDeque<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedBFSIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            is_root: self.is_root,
+            item_stack: self.item_stack.clone(),
+            tree_cache: self.tree_cache.clone_without_value(),
+            traversal_stack: self
+                .traversal_stack
+                .iter()
+                .map(TreeNodeVecDeque::clone_without_value)
+                .collect(),
+            iterator_queue: self.iterator_queue.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBFSIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -177,6 +277,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> BorrowedBFSLeavesIteratorWithAncestors<'a, Node> {
         BorrowedBFSLeavesIteratorWithAncestors {
@@ -237,6 +339,18 @@ where
     traversal_queue: VecDeque<BinaryChildren<&'a Node>>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryBFSIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_queue: self.traversal_queue.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryBFSIterator<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -257,19 +371,28 @@ where
         }
     }
 
+    bfs_binary_resume_from!(
+        get_value_and_children_iter,
+        get_value_and_children_binary_iter
+    );
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedBinaryBFSIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => BorrowedBinaryBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => BorrowedBinaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> BorrowedBinaryBFSIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => BorrowedBinaryBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => BorrowedBinaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -280,6 +403,7 @@ where
 {
     type Item = Node::BorrowedValue;
     bfs_next!(get_value_and_children_iter);
+    bfs_size_hint!();
 }
 
 pub struct BorrowedBinaryBFSIteratorWithAncestors<'a, Node>
@@ -293,6 +417,26 @@ where
     pub(crate) iterator_queue: VecDeque<BinaryChildren<&'a Node>>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryBFSIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            is_root: self.is_root,
+            item_stack: self.item_stack.clone(),
+            tree_cache: self.tree_cache.clone_without_value(),
+            traversal_stack: self
+                .traversal_stack
+                .iter()
+                .map(TreeNodeVecDeque::clone_without_value)
+                .collect(),
+            iterator_queue: self.iterator_queue.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryBFSIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -315,6 +459,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> BorrowedBinaryBFSLeavesIteratorWithAncestors<'a, Node> {
         BorrowedBinaryBFSLeavesIteratorWithAncestors::new(self)
@@ -339,11 +485,32 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
-    pub(crate) iterator_queue: VecDeque<IntoIter<Option<&'a Node>, 2>>,
+    pub(crate) iterator_queue: VecDeque<(usize, IntoIter<Option<&'a Node>, 2>)>,
     pub(crate) current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; 2]>,
     pub(crate) path_counter: usize,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryBFSIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            is_root: self.is_root,
+            tree_cache: self.tree_cache.clone_without_value(),
+            traversal_stack: self
+                .traversal_stack
+                .iter()
+                .map(TreeNodeVecDeque::clone_without_value)
+                .collect(),
+            iterator_queue: self.iterator_queue.clone(),
+            current_context: self.current_context.clone(),
+            path_counter: self.path_counter,
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryBFSIteratorWithContext<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -357,8 +524,11 @@ where
             path,
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         Self {
             is_root: true,
@@ -370,6 +540,32 @@ where
         }
     }
 
+    bfs_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> BorrowedBinaryBFSLeavesIteratorWithContext<'a, Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        BorrowedBinaryBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -2,6 +2,8 @@ pub mod borrow;
 pub mod mut_borrow;
 pub mod owned;
 
+use crate::tree_context::SiblingPosition;
+
 macro_rules! bfs_next {
     ($get_value_and_children: ident) => {
         fn next(&mut self) -> Option<Self::Item> {
@@ -25,20 +27,43 @@ macro_rules! bfs_next {
     };
 }
 
+macro_rules! bfs_size_hint {
+    () => {
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            if self.root.is_none() && self.traversal_queue.is_empty() {
+                return (0, Some(0));
+            }
+
+            // Every child that hasn't been visited yet has at least one node
+            // in its subtree.
+            let pending_children = self
+                .traversal_queue
+                .iter()
+                .map(|children| children.size_hint().0)
+                .sum::<usize>();
+            (usize::from(self.root.is_some()) + pending_children, None)
+        }
+    };
+}
+
 macro_rules! bfs_context_streaming_iterator_impl {
     ($get_value_and_children: ident) => {
         fn advance(&mut self) {
             if self.is_root {
                 self.is_root = false;
+                self.current_context.index = self.current_context.index.wrapping_add(1);
                 return;
             }
 
             if self.current_context.ancestors.is_empty() {
                 return;
             }
-
-            let children = self.current_context.children.take();
-            self.iterator_queue.push_back(children.unwrap().into_iter());
+            // The children are only missing if they were skipped.
+            let children = match self.current_context.children.take() {
+                Some(children) => Siblings::new(children),
+                None => Siblings::default(),
+            };
+            self.iterator_queue.push_back(children);
 
             loop {
                 if self.current_context.ancestors.len() == self.traversal_stack.len() + 2 {
@@ -48,12 +73,18 @@ macro_rules! bfs_context_streaming_iterator_impl {
                 let iter = &mut self.iterator_queue[0];
 
                 if let Some(next) = iter.next() {
+                    let position = SiblingPosition {
+                        index: self.path_counter,
+                        count: self.path_counter + 1 + iter.len(),
+                    };
                     self.current_context.path.push(self.path_counter);
                     self.path_counter += 1;
 
                     let (value, children) = next.$get_value_and_children();
                     self.current_context.ancestors.push(value);
+                    self.current_context.siblings.push(position);
                     self.current_context.children = Some(children);
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
                     break;
                 }
 
@@ -73,6 +104,7 @@ macro_rules! bfs_context_streaming_iterator_impl {
 
                         self.traversal_stack.pop();
                         self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
                         self.current_context.path.pop();
                     }
                 }
@@ -82,6 +114,7 @@ macro_rules! bfs_context_streaming_iterator_impl {
 
                 if self.iterator_queue.is_empty() {
                     self.current_context.ancestors.clear();
+                    self.current_context.siblings.clear();
                     break;
                 }
             }
@@ -135,6 +168,7 @@ macro_rules! bfs_context_advance_iterator {
                     value.value = core::mem::MaybeUninit::uninit();
 
                     self.current_context.path.push(value.path_segment);
+                    self.current_context.siblings.push(value.sibling_position);
 
                     let has_children = !value.children.is_empty();
                     self.traversal_stack.push(value);
@@ -160,6 +194,7 @@ macro_rules! bfs_context_advance_iterator {
                     target.value =
                         core::mem::MaybeUninit::new(self.current_context.ancestors.pop().unwrap());
                     target.path_segment = self.current_context.path.pop().unwrap();
+                    target.sibling_position = self.current_context.siblings.pop().unwrap();
                 }
 
                 let popped = self.traversal_stack.pop();
@@ -187,6 +222,7 @@ macro_rules! bfs_context_advance_iterator {
             tree_node.children.push_back(Some(TreeNodeVecDeque {
                 value: core::mem::MaybeUninit::new(self.current_context.ancestors.pop().unwrap()),
                 path_segment: self.current_context.path.pop().unwrap(),
+                sibling_position: self.current_context.siblings.pop().unwrap(),
                 children: VecDeque::new(),
             }));
         }
@@ -198,31 +234,40 @@ macro_rules! bfs_context_binary_streaming_iterator_impl {
         fn advance(&mut self) {
             if self.is_root {
                 self.is_root = false;
+                self.current_context.index = self.current_context.index.wrapping_add(1);
                 return;
             }
 
             if self.current_context.ancestors.is_empty() {
                 return;
             }
-
-            let children = self.current_context.children.take();
-            self.iterator_queue.push_back(children.unwrap().into_iter());
+            // The children are only missing if they were skipped.
+            let children = self
+                .current_context
+                .children
+                .take()
+                .unwrap_or_else(|| core::array::from_fn(|_| None));
+            let count = children.iter().flatten().count();
+            self.iterator_queue.push_back((count, children.into_iter()));
 
             'outer: loop {
                 if self.current_context.ancestors.len() == self.traversal_stack.len() + 2 {
                     self.pop_from_item_stack();
                 }
 
-                let iter = &mut self.iterator_queue[0];
+                let (count, iter) = &mut self.iterator_queue[0];
 
                 while let Some(next) = iter.next() {
                     if let Some(next) = next {
+                        let position = SiblingPosition::slot(*count, iter.as_slice());
                         self.current_context.path.push(self.path_counter);
                         self.path_counter += 1;
 
                         let (value, children) = next.$get_value_and_children_binary();
                         self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
                         self.current_context.children = Some(children);
+                        self.current_context.index = self.current_context.index.wrapping_add(1);
                         break 'outer;
                     } else {
                         self.path_counter += 1;
@@ -246,6 +291,7 @@ macro_rules! bfs_context_binary_streaming_iterator_impl {
 
                         self.traversal_stack.pop();
                         self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
                         self.current_context.path.pop();
                     }
                 }
@@ -254,6 +300,7 @@ macro_rules! bfs_context_binary_streaming_iterator_impl {
                 self.iterator_queue.pop_front();
                 if self.iterator_queue.is_empty() {
                     self.current_context.ancestors.clear();
+                    self.current_context.siblings.clear();
                     break;
                 }
             }
@@ -269,6 +316,269 @@ macro_rules! bfs_context_binary_streaming_iterator_impl {
     };
 }
 
+macro_rules! bfs_resume_from {
+    ($get_value_and_children: ident) => {
+        #[doc = include_str!("../../doc_files/resume_from.md")]
+        pub fn resume_from(self, path: &[usize]) -> Option<Self> {
+            let root = match self.root {
+                Some(root) if self.traversal_queue.is_empty() => root,
+                _ => panic!("Attempted to resume a BFS iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            let (&target_index, parent_path) = match path.split_last() {
+                Some(split) => split,
+                None => {
+                    return Some(Self {
+                        root: Some(root),
+                        traversal_queue: VecDeque::new(),
+                    })
+                }
+            };
+
+            // Build up the level containing the target's parent, keeping
+            // track of where the parent falls within that level.
+            let mut level = Vec::new();
+            level.push(root);
+            let mut parent_index = 0;
+            for &index in parent_path {
+                let mut next_level = Vec::new();
+                let mut next_parent_index = None;
+                for (position, node) in level.into_iter().enumerate() {
+                    let (_, children) = node.$get_value_and_children();
+                    for (child_index, child) in children.into_iter().enumerate() {
+                        if position == parent_index && child_index == index {
+                            next_parent_index = Some(next_level.len());
+                        }
+                        next_level.push(child);
+                    }
+                }
+                parent_index = next_parent_index?;
+                level = next_level;
+            }
+
+            // The nodes on the target's level that come before it have
+            // already been yielded, but their children have not.
+            let mut earlier_children = VecDeque::new();
+            let mut level = level.into_iter();
+            for node in level.by_ref().take(parent_index) {
+                let (_, children) = node.$get_value_and_children();
+                for child in children {
+                    let (_, grandchildren) = child.$get_value_and_children();
+                    earlier_children.push_back(grandchildren.into_iter());
+                }
+            }
+
+            let (_, siblings) = level.next()?.$get_value_and_children();
+            let mut siblings = siblings.into_iter();
+            for sibling in siblings.by_ref().take(target_index) {
+                let (_, children) = sibling.$get_value_and_children();
+                earlier_children.push_back(children.into_iter());
+            }
+            let target = siblings.next()?;
+
+            let mut traversal_queue = VecDeque::new();
+            traversal_queue.push_back(siblings);
+            for node in level {
+                let (_, children) = node.$get_value_and_children();
+                traversal_queue.push_back(children.into_iter());
+            }
+            traversal_queue.append(&mut earlier_children);
+
+            Some(Self {
+                root: Some(target),
+                traversal_queue,
+            })
+        }
+    };
+}
+
+macro_rules! bfs_binary_resume_from {
+    ($get_value_and_children: ident, $get_value_and_children_binary: ident) => {
+        #[doc = include_str!("../../doc_files/resume_from.md")]
+        pub fn resume_from(self, path: &[usize]) -> Option<Self> {
+            let root = match self.root {
+                Some(root) if self.traversal_queue.is_empty() => root,
+                _ => panic!("Attempted to resume a BFS iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            let (&target_index, parent_path) = match path.split_last() {
+                Some(split) => split,
+                None => {
+                    return Some(Self {
+                        root: Some(root),
+                        traversal_queue: VecDeque::new(),
+                    })
+                }
+            };
+
+            // Build up the level containing the target's parent, keeping
+            // track of where the parent falls within that level.
+            let mut level = Vec::new();
+            level.push(root);
+            let mut parent_index = 0;
+            for &index in parent_path {
+                let mut next_level = Vec::new();
+                let mut next_parent_index = None;
+                for (position, node) in level.into_iter().enumerate() {
+                    let (_, children) = node.$get_value_and_children_binary();
+                    for (child_index, child) in children.into_iter().enumerate() {
+                        if let Some(child) = child {
+                            if position == parent_index && child_index == index {
+                                next_parent_index = Some(next_level.len());
+                            }
+                            next_level.push(child);
+                        }
+                    }
+                }
+                parent_index = next_parent_index?;
+                level = next_level;
+            }
+
+            // The nodes on the target's level that come before it have
+            // already been yielded, but their children have not.
+            let mut earlier_children = VecDeque::new();
+            let mut level = level.into_iter();
+            for node in level.by_ref().take(parent_index) {
+                let (_, children) = node.$get_value_and_children();
+                for child in children {
+                    let (_, grandchildren) = child.$get_value_and_children();
+                    earlier_children.push_back(grandchildren);
+                }
+            }
+
+            let (_, mut siblings) = level.next()?.$get_value_and_children_binary();
+            let target = siblings.get_mut(target_index)?.take()?;
+            for sibling in siblings.iter_mut().take(target_index).filter_map(Option::take) {
+                let (_, children) = sibling.$get_value_and_children();
+                earlier_children.push_back(children);
+            }
+
+            let mut traversal_queue = VecDeque::new();
+            traversal_queue.push_back(
+                siblings
+                    .into_iter()
+                    .flat_map(opt_to_opt as fn(Option<_>) -> Option<_>),
+            );
+            for node in level {
+                let (_, children) = node.$get_value_and_children();
+                traversal_queue.push_back(children);
+            }
+            traversal_queue.append(&mut earlier_children);
+
+            Some(Self {
+                root: Some(target),
+                traversal_queue,
+            })
+        }
+    };
+}
+
+macro_rules! bfs_skip_impl {
+    () => {
+        #[doc = include_str!("../../doc_files/skip_children.md")]
+        pub fn skip_children(&mut self) {
+            if self.root.is_some() {
+                return;
+            }
+
+            // The children of the most recently yielded node are always at
+            // the back of the queue.
+            if let Some(children) = self.traversal_queue.back_mut() {
+                children.for_each(drop);
+            }
+        }
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            if self.root.is_some() {
+                return;
+            }
+
+            self.skip_children();
+
+            // The most recently yielded node came from the front of the
+            // queue, so its remaining siblings are still in there.
+            if let Some(siblings) = self.traversal_queue.front_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+    (ancestors) => {
+        #[doc = include_str!("../../doc_files/skip_children.md")]
+        pub fn skip_children(&mut self) {
+            if self.is_root {
+                return;
+            }
+
+            // The children of the most recently yielded node are always at
+            // the back of the queue.
+            if let Some(children) = self.iterator_queue.back_mut() {
+                children.for_each(drop);
+            }
+        }
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            if self.is_root {
+                return;
+            }
+
+            self.skip_children();
+
+            // The most recently yielded node came from the front of the
+            // queue, so its remaining siblings are still in there.
+            if let Some(siblings) = self.iterator_queue.front_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+}
+
+macro_rules! bfs_context_skip_impl {
+    () => {
+        bfs_context_skip_impl!(@skip_children);
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            if self.is_root {
+                return;
+            }
+
+            self.skip_children();
+            if let Some(siblings) = self.iterator_queue.front_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+    (binary) => {
+        bfs_context_skip_impl!(@skip_children);
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            if self.is_root {
+                return;
+            }
+
+            self.skip_children();
+            if let Some((_, siblings)) = self.iterator_queue.front_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+    (@skip_children) => {
+        #[doc = include_str!("../../doc_files/skip_children.md")]
+        pub fn skip_children(&mut self) {
+            if self.is_root {
+                return;
+            }
+
+            // Without its children, the current node gets treated as a leaf
+            // the next time the iterator is advanced.
+            self.current_context.children.take();
+        }
+    };
+}
+
 macro_rules! bfs_ancestors_streaming_iterator_impl {
     ($get_value_and_children: ident) => {
         fn advance(&mut self) {
@@ -415,6 +725,7 @@ macro_rules! bfs_ancestors_advance_iterator {
             tree_node.children.push_back(Some(TreeNodeVecDeque {
                 value: core::mem::MaybeUninit::new(self.item_stack.pop().unwrap()),
                 path_segment: 0,
+                sibling_position: SiblingPosition::ROOT,
                 children: VecDeque::new(),
             }));
         }
@@ -423,10 +734,14 @@ macro_rules! bfs_ancestors_advance_iterator {
 
 pub(crate) use bfs_ancestors_advance_iterator;
 pub(crate) use bfs_ancestors_streaming_iterator_impl;
+pub(crate) use bfs_binary_resume_from;
 pub(crate) use bfs_context_advance_iterator;
 pub(crate) use bfs_context_binary_streaming_iterator_impl;
+pub(crate) use bfs_context_skip_impl;
 pub(crate) use bfs_context_streaming_iterator_impl;
 pub(crate) use bfs_next;
+pub(crate) use bfs_resume_from;
+pub(crate) use bfs_skip_impl;
 pub(crate) use get_mut_ancestors;
 pub(crate) use get_mut_context;
 
@@ -434,6 +749,7 @@ pub(crate) use get_mut_context;
 pub(crate) struct TreeNodeVecDeque<T> {
     pub(crate) value: core::mem::MaybeUninit<T>,
     pub(crate) path_segment: usize,
+    pub(crate) sibling_position: SiblingPosition,
     pub(crate) children: alloc::collections::VecDeque<Option<Self>>,
 }
 
@@ -445,6 +761,25 @@ where
         Self {
             value: core::mem::MaybeUninit::new(unsafe { self.value.assume_init_ref().clone() }),
             path_segment: self.path_segment,
+            sibling_position: self.sibling_position,
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T> TreeNodeVecDeque<T>
+where
+    T: Clone,
+{
+    /// Clones a node whose value has been moved out into an iterator's stack
+    /// of ancestors. The tree cache and every node on the traversal stack are
+    /// in this state, while the nodes in their children lists still hold their
+    /// values.
+    pub(crate) fn clone_without_value(&self) -> Self {
+        Self {
+            value: core::mem::MaybeUninit::uninit(),
+            path_segment: self.path_segment,
+            sibling_position: self.sibling_position,
             children: self.children.clone(),
         }
     }
@@ -455,7 +790,9 @@ impl<T> Default for TreeNodeVecDeque<T> {
         Self {
             value: core::mem::MaybeUninit::uninit(),
             path_segment: 0,
+            sibling_position: SiblingPosition::ROOT,
             children: alloc::collections::VecDeque::new(),
         }
     }
 }
+pub(crate) use bfs_size_hint;
@@ -4,10 +4,10 @@ use alloc::{collections::VecDeque, vec::Vec};
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use super::{
-    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl,
+    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl, bfs_binary_resume_from,
     bfs_context_advance_iterator, bfs_context_binary_streaming_iterator_impl,
-    bfs_context_streaming_iterator_impl, bfs_next, get_mut_ancestors, get_mut_context,
-    TreeNodeVecDeque,
+    bfs_context_skip_impl, bfs_context_streaming_iterator_impl, bfs_next, bfs_resume_from,
+    bfs_size_hint, bfs_skip_impl, get_mut_ancestors, get_mut_context, TreeNodeVecDeque,
 };
 use crate::{
     leaves_iterators::{
@@ -16,8 +16,15 @@ use crate::{
             MutBorrowedBinaryBFSLeavesIteratorWithAncestors,
         },
         breadth_first::mut_borrow::{MutBorrowedBinaryLeavesIterator, MutBorrowedLeavesIterator},
+        context_breadth_first::mut_borrow::{
+            MutBorrowedBFSLeavesIteratorWithContext, MutBorrowedBinaryBFSLeavesIteratorWithContext,
+        },
+    },
+    prelude::{
+        opt_to_opt, BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode, TreeContext,
     },
-    prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 
 crate::collection_iterators::mut_borrowed_collection_iterator_impl!(
@@ -71,19 +78,25 @@ where
         }
     }
 
+    bfs_resume_from!(get_value_and_children_iter_mut);
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedBFSIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => MutBorrowedBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> MutBorrowedBFSIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => MutBorrowedBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -94,6 +107,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     bfs_next!(get_value_and_children_iter_mut);
+    bfs_size_hint!();
 }
 
 pub struct MutBorrowedBFSIteratorWithContext<'a, Node>
@@ -103,7 +117,8 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
-    pub(crate) iterator_queue: VecDeque<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>,
+    pub(crate) iterator_queue:
+        VecDeque<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
     pub(crate) current_context: TreeContext<Node::MutBorrowedValue, Node::MutBorrowedChildren>,
     pub(crate) path_counter: usize,
 }
@@ -120,8 +135,11 @@ where
             path,
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         MutBorrowedBFSIteratorWithContext {
             is_root: true,
@@ -133,6 +151,32 @@ where
         }
     }
 
+    bfs_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> MutBorrowedBFSLeavesIteratorWithContext<'a, Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        MutBorrowedBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -185,6 +229,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> MutBorrowedBFSLeavesIteratorWithAncestors<'a, Node> {
         MutBorrowedBFSLeavesIteratorWithAncestors {
@@ -272,19 +318,28 @@ where
         }
     }
 
+    bfs_binary_resume_from!(
+        get_value_and_children_iter_mut,
+        get_value_and_children_binary_iter_mut
+    );
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedBinaryBFSIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => MutBorrowedBinaryBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedBinaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> MutBorrowedBinaryBFSIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => MutBorrowedBinaryBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedBinaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -295,6 +350,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     bfs_next!(get_value_and_children_iter_mut);
+    bfs_size_hint!();
 }
 
 pub struct MutBorrowedBinaryBFSIteratorWithAncestors<'a, Node>
@@ -330,6 +386,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> MutBorrowedBinaryBFSLeavesIteratorWithAncestors<'a, Node> {
         MutBorrowedBinaryBFSLeavesIteratorWithAncestors::new(self)
@@ -361,7 +419,7 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
-    pub(crate) iterator_queue: VecDeque<IntoIter<Option<&'a mut Node>, 2>>,
+    pub(crate) iterator_queue: VecDeque<(usize, IntoIter<Option<&'a mut Node>, 2>)>,
     pub(crate) current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; 2]>,
     pub(crate) path_counter: usize,
 }
@@ -379,8 +437,11 @@ where
             path,
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         Self {
             is_root: true,
@@ -392,6 +453,36 @@ where
         }
     }
 
+    bfs_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> MutBorrowedBinaryBFSLeavesIteratorWithContext<'a, Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        MutBorrowedBinaryBFSLeavesIteratorWithContext::new(
+            value,
+            children,
+            self.current_context.path,
+        )
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -4,10 +4,10 @@ use alloc::{collections::VecDeque, vec::Vec};
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use super::{
-    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl,
+    bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl, bfs_binary_resume_from,
     bfs_context_advance_iterator, bfs_context_binary_streaming_iterator_impl,
-    bfs_context_streaming_iterator_impl, bfs_next, get_mut_ancestors, get_mut_context,
-    TreeNodeVecDeque,
+    bfs_context_skip_impl, bfs_context_streaming_iterator_impl, bfs_next, bfs_resume_from,
+    bfs_size_hint, bfs_skip_impl, get_mut_ancestors, get_mut_context, TreeNodeVecDeque,
 };
 use crate::{
     leaves_iterators::{
@@ -15,8 +15,13 @@ use crate::{
             OwnedBFSLeavesIteratorWithAncestors, OwnedBinaryBFSLeavesIteratorWithAncestors,
         },
         breadth_first::owned::{OwnedBinaryLeavesIterator, OwnedLeavesIterator},
+        context_breadth_first::owned::{
+            OwnedBFSLeavesIteratorWithContext, OwnedBinaryBFSLeavesIteratorWithContext,
+        },
     },
-    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode, TreeContext},
+    prelude::{opt_to_opt, BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 
 crate::collection_iterators::owned_collection_iterator_impl!(
@@ -72,19 +77,25 @@ where
         }
     }
 
+    bfs_resume_from!(get_value_and_children);
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedBFSIteratorWithContext<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => OwnedBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => OwnedBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> OwnedBFSIteratorWithAncestors<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => OwnedBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => OwnedBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -95,6 +106,7 @@ where
 {
     type Item = Node::OwnedValue;
     bfs_next!(get_value_and_children);
+    bfs_size_hint!();
 }
 
 pub struct OwnedBFSIteratorWithContext<Node>
@@ -104,7 +116,7 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
-    pub(crate) iterator_queue: VecDeque<<Node::OwnedChildren as IntoIterator>::IntoIter>,
+    pub(crate) iterator_queue: VecDeque<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
     pub(crate) current_context: TreeContext<Node::OwnedValue, Node::OwnedChildren>,
     pub(crate) path_counter: usize,
 }
@@ -121,9 +133,12 @@ where
         let mut current_context = TreeContext {
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
             path,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         OwnedBFSIteratorWithContext {
             is_root: true,
@@ -135,6 +150,32 @@ where
         }
     }
 
+    bfs_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> OwnedBFSLeavesIteratorWithContext<Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        OwnedBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -187,6 +228,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> OwnedBFSLeavesIteratorWithAncestors<Node> {
         OwnedBFSLeavesIteratorWithAncestors {
@@ -272,19 +315,25 @@ where
         }
     }
 
+    bfs_binary_resume_from!(get_value_and_children, get_value_and_children_binary);
+
+    exact_size_impl!(root, traversal_queue);
+
+    bfs_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedBinaryBFSIteratorWithContext<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => OwnedBinaryBFSIteratorWithContext::new(root, Vec::new())
+            Some(root) if self.traversal_queue.is_empty() => OwnedBinaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> OwnedBinaryBFSIteratorWithAncestors<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => OwnedBinaryBFSIteratorWithAncestors::new(root)
+            Some(root) if self.traversal_queue.is_empty() => OwnedBinaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -295,6 +344,7 @@ where
 {
     type Item = Node::OwnedValue;
     bfs_next!(get_value_and_children);
+    bfs_size_hint!();
 }
 
 pub struct OwnedBinaryBFSIteratorWithAncestors<Node>
@@ -330,6 +380,8 @@ where
         }
     }
 
+    bfs_skip_impl!(ancestors);
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(self) -> OwnedBinaryBFSLeavesIteratorWithAncestors<Node> {
         OwnedBinaryBFSLeavesIteratorWithAncestors::new(self)
@@ -361,7 +413,7 @@ where
     pub(crate) is_root: bool,
     pub(crate) tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
     pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
-    pub(crate) iterator_queue: VecDeque<IntoIter<Option<Node>, 2>>,
+    pub(crate) iterator_queue: VecDeque<(usize, IntoIter<Option<Node>, 2>)>,
     pub(crate) current_context: TreeContext<Node::OwnedValue, [Option<Node>; 2]>,
     pub(crate) path_counter: usize,
 }
@@ -378,9 +430,12 @@ where
         let mut current_context = TreeContext {
             ancestors: Vec::new(),
             children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
             path,
         };
         current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
 
         Self {
             is_root: true,
@@ -392,6 +447,32 @@ where
         }
     }
 
+    bfs_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> OwnedBinaryBFSLeavesIteratorWithContext<Node> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        OwnedBinaryBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
     bfs_context_advance_iterator!();
 }
 
@@ -0,0 +1,483 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+
+impl<T> BinaryTree<T>
+where
+    T: Ord,
+{
+    /// Builds a height-balanced binary search tree out of the values in `iter`.
+    /// If `iter` is empty, this returns [`None`].
+    ///
+    /// The values are assumed to be sorted in ascending order and are placed
+    /// in the tree in the order they are yielded. If they are not sorted (or
+    /// contain duplicates), the result will not be a valid binary search tree
+    /// (see [`is_valid_bst`](BinaryTree::is_valid_bst)).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(0..7).unwrap();
+    /// assert_eq!(3, tree.value);
+    /// assert_eq!(2, tree.height());
+    /// assert_eq!(
+    ///     (0..7).collect::<Vec<_>>(),
+    ///     tree.dfs_inorder_iter().copied().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn from_sorted_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let values = iter.into_iter().collect::<Vec<_>>();
+        let len = values.len();
+        build_balanced(&mut values.into_iter(), len).map(|root| *root)
+    }
+
+    /// Inserts `value` into this binary search tree. Returns whether the value
+    /// was newly inserted. If an equal value is already in the tree, the tree is
+    /// left unchanged and false is returned.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let mut tree = BinaryTree {
+    ///     value: 5,
+    ///     left: None,
+    ///     right: None,
+    /// };
+    /// assert!(tree.insert(3));
+    /// assert!(tree.insert(8));
+    /// assert!(!tree.insert(3));
+    ///
+    /// assert_eq!(vec![3, 5, 8], tree.dfs_inorder_iter().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut current = self;
+        loop {
+            let slot = match value.cmp(&current.value) {
+                Ordering::Less => &mut current.left,
+                Ordering::Greater => &mut current.right,
+                Ordering::Equal => return false,
+            };
+
+            if slot.is_none() {
+                *slot = Some(Box::new(BinaryTree {
+                    value,
+                    left: None,
+                    right: None,
+                }));
+                return true;
+            }
+
+            current = slot.as_mut().expect("the slot to be populated");
+        }
+    }
+
+    /// Removes the value equal to `value` from this binary search tree.
+    ///
+    /// Since a [`BinaryTree`] always has a root, this consumes the tree and
+    /// returns the remaining tree alongside the removed value. The remaining
+    /// tree is [`None`] if the only node in the tree was removed. If no value
+    /// in the tree is equal to `value`, the tree is returned unchanged alongside
+    /// [`None`].
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(0..5).unwrap();
+    ///
+    /// let (tree, removed) = tree.remove(&2);
+    /// assert_eq!(Some(2), removed);
+    /// let tree = tree.unwrap();
+    /// assert_eq!(vec![0, 1, 3, 4], tree.dfs_inorder_iter().copied().collect::<Vec<_>>());
+    ///
+    /// let (tree, removed) = tree.remove(&10);
+    /// assert_eq!(None, removed);
+    /// assert_eq!(4, tree.unwrap().size());
+    /// ```
+    pub fn remove<Q>(self, value: &Q) -> (Option<Self>, Option<T>)
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut root = Some(Box::new(self));
+        let removed = remove_from_slot(&mut root, value);
+        (root.map(|root| *root), removed)
+    }
+
+    /// Returns true if this binary search tree contains a value equal to `value`.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(["a", "b", "c"]).unwrap();
+    /// assert!(tree.contains("b"));
+    /// assert!(!tree.contains("d"));
+    /// ```
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(value).is_some()
+    }
+
+    /// Gets a reference to the value in this binary search tree that is equal
+    /// to `value`, if there is one.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(vec![
+    ///     "a".to_string(),
+    ///     "b".to_string(),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(Some(&"b".to_string()), tree.get("b"));
+    /// assert_eq!(None, tree.get("c"));
+    /// ```
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self;
+        loop {
+            let next = match value.cmp(current.value.borrow()) {
+                Ordering::Less => &current.left,
+                Ordering::Greater => &current.right,
+                Ordering::Equal => return Some(&current.value),
+            };
+
+            current = next.as_deref()?;
+        }
+    }
+
+    /// Returns a lazy iterator over the values of this binary search tree that
+    /// fall within `range`, in ascending order. Subtrees that cannot contain any
+    /// value in the range are never visited.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(0..100).unwrap();
+    /// assert_eq!(
+    ///     vec![10, 11, 12],
+    ///     tree.range(10..13).copied().collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(
+    ///     vec![97, 98, 99],
+    ///     tree.range(97..).copied().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> BinarySearchTreeRange<'_, T, Q, R>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        BinarySearchTreeRange::new(self, range)
+    }
+
+    /// Gets the smallest value in this binary search tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(3..10).unwrap();
+    /// assert_eq!(&3, tree.min());
+    /// ```
+    pub fn min(&self) -> &T {
+        let mut current = self;
+        while let Some(left) = current.left.as_deref() {
+            current = left;
+        }
+        &current.value
+    }
+
+    /// Gets the largest value in this binary search tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(3..10).unwrap();
+    /// assert_eq!(&9, tree.max());
+    /// ```
+    pub fn max(&self) -> &T {
+        let mut current = self;
+        while let Some(right) = current.right.as_deref() {
+            current = right;
+        }
+        &current.value
+    }
+
+    /// Given the path of a node (see
+    /// [`TreeContext::path`](crate::prelude::TreeContext::path) for more
+    /// information), gets the path of the node that comes directly after it
+    /// in an in order traversal. Returns [`None`] if the path does not point
+    /// at a node or the node is the last one in the tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(0..7).unwrap();
+    ///
+    /// // 2 is the right child of 1, so its successor is the root.
+    /// assert_eq!(Some(vec![]), tree.successor(&[0, 1]));
+    /// // the root's successor is the leftmost node of its right subtree.
+    /// let successor = tree.successor(&[]).unwrap();
+    /// assert_eq!(Some(&4), tree.at_path_ref(&successor).map(|node| &node.value));
+    /// assert_eq!(None, tree.successor(&[1, 1]));
+    /// ```
+    pub fn successor(&self, path: &[usize]) -> Option<Vec<usize>> {
+        inorder_neighbor(self, path, 1)
+    }
+
+    /// Given the path of a node (see
+    /// [`TreeContext::path`](crate::prelude::TreeContext::path) for more
+    /// information), gets the path of the node that comes directly before it
+    /// in an in order traversal. Returns [`None`] if the path does not point
+    /// at a node or the node is the first one in the tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_sorted_iter(0..7).unwrap();
+    /// assert_eq!(Some(vec![]), tree.predecessor(&[1, 0]));
+    /// assert_eq!(Some(vec![0, 1]), tree.predecessor(&[]));
+    /// assert_eq!(None, tree.predecessor(&[0, 0]));
+    /// ```
+    pub fn predecessor(&self, path: &[usize]) -> Option<Vec<usize>> {
+        inorder_neighbor(self, path, 0)
+    }
+
+    /// Returns true if every node's value is strictly greater than all of the
+    /// values in its left subtree and strictly less than all of the values in
+    /// its right subtree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::from_sorted_iter(0..7).unwrap();
+    /// assert!(tree.is_valid_bst());
+    ///
+    /// tree.value = 100;
+    /// assert!(!tree.is_valid_bst());
+    /// ```
+    pub fn is_valid_bst(&self) -> bool {
+        let mut previous: Option<&T> = None;
+        for value in self.dfs_inorder_iter() {
+            if previous.is_some_and(|previous| previous >= value) {
+                return false;
+            }
+            previous = Some(value);
+        }
+        true
+    }
+}
+
+fn build_balanced<T>(
+    values: &mut impl Iterator<Item = T>,
+    len: usize,
+) -> Option<Box<BinaryTree<T>>> {
+    if len == 0 {
+        return None;
+    }
+
+    let left_len = len / 2;
+    let left = build_balanced(values, left_len);
+    let value = values
+        .next()
+        .expect("there to be exactly len values remaining");
+    let right = build_balanced(values, len - left_len - 1);
+    Some(Box::new(BinaryTree { value, left, right }))
+}
+
+fn remove_from_slot<T, Q>(root: &mut Option<Box<BinaryTree<T>>>, value: &Q) -> Option<T>
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut slot = root;
+    loop {
+        let ordering = value.cmp(slot.as_ref()?.value.borrow());
+        if ordering == Ordering::Equal {
+            break;
+        }
+
+        let node = slot.as_mut().expect("the slot to be populated");
+        slot = if ordering == Ordering::Less {
+            &mut node.left
+        } else {
+            &mut node.right
+        };
+    }
+
+    let mut node = slot.take().expect("the slot to be populated");
+    *slot = match (node.left.take(), node.right.take()) {
+        (None, right) => right,
+        (left, None) => left,
+        (left, mut right) => {
+            let successor = pop_min(&mut right).expect("the right subtree to be populated");
+            Some(Box::new(BinaryTree {
+                value: successor,
+                left,
+                right,
+            }))
+        }
+    };
+    Some(node.value)
+}
+
+fn pop_min<T>(root: &mut Option<Box<BinaryTree<T>>>) -> Option<T> {
+    let mut slot = root;
+    while slot.as_ref()?.left.is_some() {
+        slot = &mut slot.as_mut().expect("the slot to be populated").left;
+    }
+
+    let node = slot.take().expect("the slot to be populated");
+    *slot = node.right;
+    Some(node.value)
+}
+
+/// Finds the in order successor (direction = 1) or predecessor (direction = 0)
+/// of the node at `path`.
+fn inorder_neighbor<T>(
+    root: &BinaryTree<T>,
+    path: &[usize],
+    direction: usize,
+) -> Option<Vec<usize>> {
+    let node = root.at_path_ref(path)?;
+    let mut result = path.to_vec();
+
+    let child = if direction == 0 {
+        &node.left
+    } else {
+        &node.right
+    };
+    if let Some(mut current) = child.as_deref() {
+        result.push(direction);
+        loop {
+            let next = if direction == 0 {
+                &current.right
+            } else {
+                &current.left
+            };
+
+            match next.as_deref() {
+                Some(next) => {
+                    result.push(1 - direction);
+                    current = next;
+                }
+                None => return Some(result),
+            }
+        }
+    }
+
+    while let Some(segment) = result.pop() {
+        if segment != direction {
+            return Some(result);
+        }
+    }
+    None
+}
+
+/// A lazy in order iterator over the values of a binary search tree that fall
+/// within a range. See [`BinaryTree::range`] for more details.
+pub struct BinarySearchTreeRange<'a, T, Q, R>
+where
+    Q: ?Sized,
+{
+    range: R,
+    stack: Vec<&'a BinaryTree<T>>,
+    phantom: PhantomData<fn(&Q)>,
+}
+
+impl<'a, T, Q, R> BinarySearchTreeRange<'a, T, Q, R>
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    fn new(root: &'a BinaryTree<T>, range: R) -> Self {
+        let mut result = Self {
+            range,
+            stack: Vec::new(),
+            phantom: PhantomData,
+        };
+        result.push_left_spine(Some(root));
+        result
+    }
+
+    /// Pushes the left spine of `node` onto the stack, skipping any nodes
+    /// (and their left subtrees) that fall below the start of the range.
+    fn push_left_spine(&mut self, mut node: Option<&'a BinaryTree<T>>) {
+        while let Some(current) = node {
+            let below_start = match self.range.start_bound() {
+                Bound::Included(start) => current.value.borrow() < start,
+                Bound::Excluded(start) => current.value.borrow() <= start,
+                Bound::Unbounded => false,
+            };
+
+            if below_start {
+                node = current.right.as_deref();
+            } else {
+                self.stack.push(current);
+                node = current.left.as_deref();
+            }
+        }
+    }
+}
+
+impl<'a, T, Q, R> Iterator for BinarySearchTreeRange<'a, T, Q, R>
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let after_end = match self.range.end_bound() {
+            Bound::Included(end) => node.value.borrow() > end,
+            Bound::Excluded(end) => node.value.borrow() >= end,
+            Bound::Unbounded => false,
+        };
+
+        if after_end {
+            self.stack.clear();
+            return None;
+        }
+
+        self.push_left_spine(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+
+impl<'a, T, Q, R> FusedIterator for BinarySearchTreeRange<'a, T, Q, R>
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+}
@@ -103,6 +103,17 @@ macro_rules! owned_collection_context_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -115,8 +126,12 @@ macro_rules! owned_collection_context_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
@@ -195,6 +210,17 @@ macro_rules! owned_collection_context_no_children_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -207,8 +233,12 @@ macro_rules! owned_collection_context_no_children_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
@@ -285,6 +315,13 @@ macro_rules! owned_collection_binary_context_iterator_impl {
 
                     let next_tree_iterator = self.collection.next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+                        .tree_traversal_iterator
+                        .as_ref()
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
                     let mut path_with_index =
                         if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                             Vec::with_capacity(tree_iterator.current_context.path.capacity())
@@ -298,8 +335,10 @@ macro_rules! owned_collection_binary_context_iterator_impl {
                     self.tree_traversal_iterator =
                         next_tree_iterator.map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
                     }
                 }
             }
@@ -373,6 +412,13 @@ macro_rules! owned_collection_binary_context_no_children_iterator_impl {
 
                     let next_tree_iterator = self.collection.next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+                        .tree_traversal_iterator
+                        .as_ref()
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
                     let mut path_with_index =
                         if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                             Vec::with_capacity(tree_iterator.current_context.path.capacity())
@@ -386,8 +432,10 @@ macro_rules! owned_collection_binary_context_no_children_iterator_impl {
                     self.tree_traversal_iterator =
                         next_tree_iterator.map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
                     }
                 }
             }
@@ -518,6 +566,17 @@ macro_rules! mut_borrowed_collection_context_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -530,8 +589,12 @@ macro_rules! mut_borrowed_collection_context_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
@@ -569,6 +632,22 @@ macro_rules! borrowed_binary_collection_context_iterator_impl {
             tree_traversal_iterator: Option<$inner_iterator<'a, Node>>,
         }
 
+        impl<'a, IntoIter, Node> Clone for $struct_name<'a, IntoIter, Node>
+        where
+            IntoIter: IntoIterator<Item = &'a Node>,
+            IntoIter::IntoIter: Clone,
+            Node: BorrowedBinaryTreeNode<'a>,
+            $inner_iterator<'a, Node>: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    collection: self.collection.clone(),
+                    index: self.index,
+                    tree_traversal_iterator: self.tree_traversal_iterator.clone(),
+                }
+            }
+        }
+
         impl<'a, IntoIter, Node> $struct_name<'a, IntoIter, Node>
         where
             IntoIter: IntoIterator<Item = &'a Node>,
@@ -610,6 +689,17 @@ macro_rules! borrowed_binary_collection_context_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -622,8 +712,12 @@ macro_rules! borrowed_binary_collection_context_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
@@ -648,6 +742,21 @@ macro_rules! borrowed_collection_iterator_impl {
             tree_traversal_iterator: Option<$inner_iterator<'a, Node>>,
         }
 
+        impl<'a, IntoIter, Node> Clone for $struct_name<'a, IntoIter, Node>
+        where
+            IntoIter: IntoIterator<Item = &'a Node>,
+            IntoIter::IntoIter: Clone,
+            Node: $tree_trait<'a>,
+            $inner_iterator<'a, Node>: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    collection: self.collection.clone(),
+                    tree_traversal_iterator: self.tree_traversal_iterator.clone(),
+                }
+            }
+        }
+
         impl<'a, IntoIter, Node> $struct_name<'a, IntoIter, Node>
         where
             IntoIter: IntoIterator<Item = &'a Node>,
@@ -701,6 +810,22 @@ macro_rules! borrowed_collection_context_iterator_impl {
             tree_traversal_iterator: Option<$inner_iterator<'a, Node>>,
         }
 
+        impl<'a, IntoIter, Node> Clone for $struct_name<'a, IntoIter, Node>
+        where
+            IntoIter: IntoIterator<Item = &'a Node>,
+            IntoIter::IntoIter: Clone,
+            Node: BorrowedTreeNode<'a>,
+            $inner_iterator<'a, Node>: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    collection: self.collection.clone(),
+                    index: self.index,
+                    tree_traversal_iterator: self.tree_traversal_iterator.clone(),
+                }
+            }
+        }
+
         impl<'a, IntoIter, Node> $struct_name<'a, IntoIter, Node>
         where
             IntoIter: IntoIterator<Item = &'a Node>,
@@ -742,6 +867,17 @@ macro_rules! borrowed_collection_context_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -754,8 +890,12 @@ macro_rules! borrowed_collection_context_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
@@ -822,6 +962,17 @@ macro_rules! mut_borrowed_binary_collection_context_iterator_impl {
                         .collection
                         .next();
 
+                    // keep counting the traversal index across the trees in the collection.
+
+                    let traversal_index = self
+
+                        .tree_traversal_iterator
+
+                        .as_ref()
+
+                        .map_or(usize::MAX, |iter| iter.current_context.index);
+
+
                     let mut path_with_index = if let Some(tree_iterator) = self.tree_traversal_iterator.as_ref() {
                         Vec::with_capacity(tree_iterator.current_context.path.capacity())
                     } else {
@@ -834,8 +985,12 @@ macro_rules! mut_borrowed_binary_collection_context_iterator_impl {
                     self.tree_traversal_iterator = next_tree_iterator
                         .map(|item| $inner_iterator::new(item, path_with_index));
 
-                    if self.tree_traversal_iterator.is_none() {
-                        return;
+                    match self.tree_traversal_iterator.as_mut() {
+
+                        Some(iter) => iter.current_context.index = traversal_index,
+
+                        None => return,
+
                     }
                 }
             }
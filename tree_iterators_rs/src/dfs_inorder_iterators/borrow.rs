@@ -6,12 +6,18 @@ use streaming_iterator::StreamingIterator;
 use crate::{
     leaves_iterators::{
         ancestors_depth_first::borrow::BorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
+        context_depth_first::borrow::BorrowedBinaryDFSLeavesIteratorWithContext,
         depth_first::borrow::BorrowedBinaryLeavesIterator,
     },
     prelude::{BorrowedBinaryTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::SiblingPosition,
 };
 
-use super::{dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, TraversalStatus};
+use super::{
+    dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, dfs_inorder_size_hint,
+    TraversalStatus,
+};
 
 crate::collection_iterators::borrowed_collection_iterator_impl!(
     BorrowedDFSInorderCollectionIterator,
@@ -47,6 +53,20 @@ where
     moved: bool,
 }
 
+impl<'a, Node> Clone for BorrowedDFSInorderIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            right_stack: self.right_stack.clone(),
+            item_stack: self.item_stack.clone(),
+            moved: self.moved,
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSInorderIterator<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -89,6 +109,8 @@ where
         }
     }
 
+    exact_size_impl!(inorder);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(mut self) -> BorrowedDFSInorderIteratorWithContext<'a, Node> {
         let root = self.right_stack.pop();
@@ -115,6 +137,7 @@ where
     type Item = Node::BorrowedValue;
 
     dfs_inorder_next!(get_value_and_children_binary_iter);
+    dfs_inorder_size_hint!();
 }
 
 pub struct BorrowedDFSInorderIteratorWithAncestors<'a, Node>
@@ -126,6 +149,20 @@ where
     status_stack: Vec<TraversalStatus>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSInorderIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            right_stack: self.right_stack.clone(),
+            item_stack: self.item_stack.clone(),
+            status_stack: self.status_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSInorderIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -181,12 +218,27 @@ pub struct BorrowedDFSInorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
 {
-    right_stack: Vec<Option<&'a Node>>,
+    right_stack: Vec<(Option<&'a Node>, SiblingPosition)>,
     current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; 2]>,
     into_iterator_stack: Vec<[Option<&'a Node>; 2]>,
     status_stack: Vec<TraversalStatus>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSInorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            right_stack: self.right_stack.clone(),
+            current_context: self.current_context.clone(),
+            into_iterator_stack: self.into_iterator_stack.clone(),
+            status_stack: self.status_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSInorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -196,12 +248,14 @@ where
         path: Vec<usize>,
     ) -> BorrowedDFSInorderIteratorWithContext<'a, Node> {
         let mut right_stack = Vec::new();
-        right_stack.push(Some(root));
+        right_stack.push((Some(root), SiblingPosition::ROOT));
 
         let context = TreeContext {
             path,
             ancestors: Vec::new(),
             children: None,
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
 
         Self {
@@ -211,6 +265,16 @@ where
             status_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.right_stack.pop() {
+            Some((Some(root), _)) if self.current_context.index == usize::MAX => {
+                BorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path)
+            }
+            _ => panic!("Attempted to get the leaves of a DFS in order iterator with context in the middle of a tree traversal. This is forbidden."),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for BorrowedDFSInorderIteratorWithContext<'a, Node>
@@ -221,11 +285,13 @@ where
 
     fn advance(&mut self) {
         let mut current = None;
+        let mut position = SiblingPosition::ROOT;
         while current.is_none() {
             if let Some(last_status) = self.status_stack.last_mut() {
                 match last_status {
                     TraversalStatus::WentRight => {
                         self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
                         self.current_context.path.pop();
                         self.status_stack.pop();
                         continue;
@@ -234,17 +300,20 @@ where
                         *last_status = TraversalStatus::ReturnedSelf;
                         self.current_context.children =
                             Some(self.into_iterator_stack.pop().unwrap());
+                        self.current_context.index = self.current_context.index.wrapping_add(1);
                         return;
                     }
                     TraversalStatus::ReturnedSelf => *last_status = TraversalStatus::WentRight,
                 }
             }
 
-            if let Some(top_of_right_stack) = self.right_stack.pop() {
+            if let Some((top_of_right_stack, right_position)) = self.right_stack.pop() {
                 current = top_of_right_stack;
+                position = right_position;
                 continue;
             } else {
                 self.current_context.ancestors.clear();
+                self.current_context.siblings.clear();
                 return;
             }
         }
@@ -252,12 +321,16 @@ where
         while let Some(current_val) = current {
             let (value, children) = current_val.get_value_and_children_binary_iter();
 
-            self.right_stack
-                .push(unsafe { core::ptr::read(&children[1] as *const Option<&'a Node>) });
+            let count = children.iter().flatten().count();
+            self.right_stack.push((
+                unsafe { core::ptr::read(&children[1] as *const Option<&'a Node>) },
+                SiblingPosition::binary(1, count),
+            ));
             let left = unsafe { core::ptr::read(&children[0] as *const Option<&'a Node>) };
             self.into_iterator_stack.push(children);
 
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(position);
             match self.status_stack.last() {
                 None => {}
                 Some(TraversalStatus::WentLeft | TraversalStatus::ReturnedSelf) => {
@@ -266,6 +339,7 @@ where
                 Some(TraversalStatus::WentRight) => self.current_context.path.push(1),
             }
             self.status_stack.push(TraversalStatus::WentLeft);
+            position = SiblingPosition::binary(0, count);
             current = left;
         }
 
@@ -277,6 +351,8 @@ where
                 .pop()
                 .expect("There to be a children IntoIterator"),
         );
+
+        self.current_context.index = self.current_context.index.wrapping_add(1);
     }
 
     fn get(&self) -> Option<&Self::Item> {
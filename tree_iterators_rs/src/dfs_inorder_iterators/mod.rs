@@ -34,6 +34,26 @@ macro_rules! dfs_inorder_next {
     };
 }
 
+macro_rules! dfs_inorder_size_hint {
+    () => {
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // Every value on the item stack still has to be yielded, and every
+            // right subtree that hasn't been visited yet has at least one node.
+            let pending_subtrees = self
+                .right_stack
+                .iter()
+                .filter(|subtree| subtree.is_some())
+                .count();
+            let lower = self.item_stack.len() + pending_subtrees;
+            if lower == 0 {
+                (0, Some(0))
+            } else {
+                (lower, None)
+            }
+        }
+    };
+}
+
 macro_rules! dfs_inorder_ancestors_streaming_iterator_impl {
     ($get_value_and_left_right: ident) => {
         fn advance(&mut self) {
@@ -114,6 +134,7 @@ macro_rules! get_mut_context {
 /// should be treated as a state machine that can only flow
 /// in one direction
 /// WentLeft -> ReturnedSelf -> WentRight.
+#[derive(Clone, Copy)]
 pub(crate) enum TraversalStatus {
     WentLeft,
     ReturnedSelf,
@@ -122,5 +143,6 @@ pub(crate) enum TraversalStatus {
 
 pub(crate) use dfs_inorder_ancestors_streaming_iterator_impl;
 pub(crate) use dfs_inorder_next;
+pub(crate) use dfs_inorder_size_hint;
 pub(crate) use get_mut_ancestors;
 pub(crate) use get_mut_context;
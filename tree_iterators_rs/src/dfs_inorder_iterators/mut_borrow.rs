@@ -6,14 +6,17 @@ use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 use crate::{
     leaves_iterators::{
         ancestors_depth_first::mut_borrow::MutBorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
+        context_depth_first::mut_borrow::MutBorrowedBinaryDFSLeavesIteratorWithContext,
         depth_first::mut_borrow::MutBorrowedBinaryLeavesIterator,
     },
     prelude::{MutBorrowedBinaryTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::SiblingPosition,
 };
 
 use super::{
-    dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, get_mut_ancestors,
-    get_mut_context, TraversalStatus,
+    dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, dfs_inorder_size_hint,
+    get_mut_ancestors, get_mut_context, TraversalStatus,
 };
 
 crate::collection_iterators::mut_borrowed_collection_iterator_impl!(
@@ -92,6 +95,8 @@ where
         }
     }
 
+    exact_size_impl!(inorder);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(mut self) -> MutBorrowedDFSInorderIteratorWithContext<'a, Node> {
         let root = self.right_stack.pop();
@@ -118,6 +123,7 @@ where
     type Item = Node::MutBorrowedValue;
 
     dfs_inorder_next!(get_value_and_children_binary_iter_mut);
+    dfs_inorder_size_hint!();
 }
 
 pub struct MutBorrowedDFSInorderIteratorWithAncestors<'a, Node>
@@ -193,7 +199,7 @@ pub struct MutBorrowedDFSInorderIteratorWithContext<'a, Node>
 where
     Node: MutBorrowedBinaryTreeNode<'a>,
 {
-    right_stack: Vec<Option<*mut Node>>,
+    right_stack: Vec<(Option<*mut Node>, SiblingPosition)>,
     current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; 2]>,
     into_iterator_stack: Vec<[Option<*mut Node>; 2]>,
     status_stack: Vec<TraversalStatus>,
@@ -208,12 +214,14 @@ where
         path: Vec<usize>,
     ) -> MutBorrowedDFSInorderIteratorWithContext<'a, Node> {
         let mut right_stack = Vec::new();
-        right_stack.push(Some(root as *mut Node));
+        right_stack.push((Some(root as *mut Node), SiblingPosition::ROOT));
 
         let context = TreeContext {
             path,
             ancestors: Vec::new(),
             children: None,
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
 
         Self {
@@ -223,6 +231,19 @@ where
             status_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.right_stack.pop() {
+            Some((Some(root), _)) if self.current_context.index == usize::MAX => {
+                // SAFETY: the root pointer came from the &'a mut Node this iterator
+                // was created with, and nothing else has been handed out yet.
+                let root = unsafe { &mut *root };
+                MutBorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path)
+            }
+            _ => panic!("Attempted to get the leaves of a DFS in order iterator with context in the middle of a tree traversal. This is forbidden."),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for MutBorrowedDFSInorderIteratorWithContext<'a, Node>
@@ -233,11 +254,13 @@ where
 
     fn advance(&mut self) {
         let mut current = None;
+        let mut position = SiblingPosition::ROOT;
         while current.is_none() {
             if let Some(last_status) = self.status_stack.last_mut() {
                 match last_status {
                     TraversalStatus::WentRight => {
                         self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
                         self.current_context.path.pop();
                         self.status_stack.pop();
                         continue;
@@ -250,6 +273,7 @@ where
                                 .unwrap()
                                 .map(|opt| opt.map(|item| unsafe { &mut *item })),
                         );
+                        self.current_context.index = self.current_context.index.wrapping_add(1);
                         return;
                     }
                     TraversalStatus::ReturnedSelf => {
@@ -258,11 +282,13 @@ where
                 }
             }
 
-            if let Some(top_of_right_stack) = self.right_stack.pop() {
+            if let Some((top_of_right_stack, right_position)) = self.right_stack.pop() {
                 current = top_of_right_stack;
+                position = right_position;
                 continue;
             } else {
                 self.current_context.ancestors.clear();
+                self.current_context.siblings.clear();
                 return;
             }
         }
@@ -270,12 +296,14 @@ where
         while let Some(current_val) = current {
             let (value, children) = unsafe { &mut *current_val }.get_value_and_children_binary_iter_mut();
 
+            let count = children.iter().flatten().count();
             let [left, right] = children.map(|child_opt| child_opt.map(|child| child as *mut Node));
             self.right_stack
-                .push(right.clone());
+                .push((right.clone(), SiblingPosition::binary(1, count)));
             self.into_iterator_stack.push([left, right]);
 
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(position);
             match self.status_stack.last() {
                 None => {}
                 Some(TraversalStatus::WentLeft | TraversalStatus::ReturnedSelf) => {
@@ -284,6 +312,7 @@ where
                 Some(TraversalStatus::WentRight) => self.current_context.path.push(1),
             }
             self.status_stack.push(TraversalStatus::WentLeft);
+            position = SiblingPosition::binary(0, count);
             current = left;
         }
 
@@ -296,6 +325,8 @@ where
                 .expect("There to be a children IntoIterator")
                 .map(|opt| opt.map(|node_ref| unsafe { &mut *node_ref })),
         );
+
+        self.current_context.index = self.current_context.index.wrapping_add(1);
     }
 
     fn get(&self) -> Option<&Self::Item> {
@@ -3,16 +3,19 @@ use core::option::IntoIter;
 use crate::{
     leaves_iterators::{
         ancestors_depth_first::owned::OwnedBinaryDFSLeavesPostorderIteratorWithAncestors,
+        context_depth_first::owned::OwnedBinaryDFSLeavesIteratorWithContext,
         depth_first::owned::OwnedBinaryLeavesIterator,
     },
     prelude::{OwnedBinaryTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::SiblingPosition,
 };
 use alloc::vec::Vec;
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use super::{
-    dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, get_mut_ancestors,
-    get_mut_context, TraversalStatus,
+    dfs_inorder_ancestors_streaming_iterator_impl, dfs_inorder_next, dfs_inorder_size_hint,
+    get_mut_ancestors, get_mut_context, TraversalStatus,
 };
 
 crate::collection_iterators::owned_collection_iterator_impl!(
@@ -89,6 +92,8 @@ where
         }
     }
 
+    exact_size_impl!(inorder);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(mut self) -> OwnedDFSInorderIteratorWithContext<Node> {
         let root = self.right_stack.pop();
@@ -115,6 +120,7 @@ where
     type Item = Node::OwnedValue;
 
     dfs_inorder_next!(get_value_and_children_binary);
+    dfs_inorder_size_hint!();
 }
 
 pub struct OwnedDFSInorderIteratorWithAncestors<Node>
@@ -189,7 +195,7 @@ pub struct OwnedDFSInorderIteratorWithContext<Node>
 where
     Node: OwnedBinaryTreeNode,
 {
-    right_stack: Vec<Option<Node>>,
+    right_stack: Vec<(Option<Node>, SiblingPosition)>,
     current_context: TreeContext<Node::OwnedValue, ()>,
     status_stack: Vec<TraversalStatus>,
 }
@@ -200,12 +206,14 @@ where
 {
     pub(crate) fn new(root: Node, path: Vec<usize>) -> OwnedDFSInorderIteratorWithContext<Node> {
         let mut right_stack = Vec::new();
-        right_stack.push(Some(root));
+        right_stack.push((Some(root), SiblingPosition::ROOT));
 
         let context = TreeContext {
             path,
             ancestors: Vec::new(),
             children: None,
+            siblings: Vec::new(),
+            index: usize::MAX,
         };
 
         Self {
@@ -214,6 +222,16 @@ where
             status_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> OwnedBinaryDFSLeavesIteratorWithContext<Node> {
+        match self.right_stack.pop() {
+            Some((Some(root), _)) if self.current_context.index == usize::MAX => {
+                OwnedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path)
+            }
+            _ => panic!("Attempted to get the leaves of a DFS in order iterator with context in the middle of a tree traversal. This is forbidden."),
+        }
+    }
 }
 
 impl<Node> StreamingIterator for OwnedDFSInorderIteratorWithContext<Node>
@@ -224,17 +242,20 @@ where
 
     fn advance(&mut self) {
         let mut current = None;
+        let mut position = SiblingPosition::ROOT;
         while current.is_none() {
             if let Some(last_status) = self.status_stack.last_mut() {
                 match last_status {
                     TraversalStatus::WentRight => {
                         self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
                         self.current_context.path.pop();
                         self.status_stack.pop();
                         continue;
                     }
                     TraversalStatus::WentLeft => {
                         *last_status = TraversalStatus::ReturnedSelf;
+                        self.current_context.index = self.current_context.index.wrapping_add(1);
                         return;
                     }
                     TraversalStatus::ReturnedSelf => {
@@ -243,19 +264,24 @@ where
                 }
             }
 
-            if let Some(top_of_right_stack) = self.right_stack.pop() {
+            if let Some((top_of_right_stack, right_position)) = self.right_stack.pop() {
                 current = top_of_right_stack;
+                position = right_position;
                 continue;
             } else {
                 self.current_context.ancestors.clear();
+                self.current_context.siblings.clear();
                 return;
             }
         }
 
         while let Some(current_val) = current {
-            let (value, [left, right]) = current_val.get_value_and_children_binary();
+            let (value, children) = current_val.get_value_and_children_binary();
+            let count = children.iter().flatten().count();
+            let [left, right] = children;
 
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(position);
             match self.status_stack.last() {
                 None => {}
                 Some(TraversalStatus::WentLeft | TraversalStatus::ReturnedSelf) => {
@@ -264,14 +290,18 @@ where
                 Some(TraversalStatus::WentRight) => self.current_context.path.push(1),
             }
 
-            self.right_stack.push(right);
+            self.right_stack
+                .push((right, SiblingPosition::binary(1, count)));
 
             self.status_stack.push(TraversalStatus::WentLeft);
+            position = SiblingPosition::binary(0, count);
             current = left;
         }
 
         let status_stack_len = self.status_stack.len();
         self.status_stack[status_stack_len - 1] = TraversalStatus::ReturnedSelf;
+
+        self.current_context.index = self.current_context.index.wrapping_add(1);
     }
 
     fn get(&self) -> Option<&Self::Item> {
@@ -6,14 +6,21 @@ use crate::{
             BorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
             BorrowedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::borrow::{
+            BorrowedBinaryDFSLeavesIteratorWithContext, BorrowedDFSLeavesIteratorWithContext,
+        },
         depth_first::borrow::{BorrowedBinaryLeavesIterator, BorrowedLeavesIterator},
     },
     prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 use alloc::vec::Vec;
 use streaming_iterator::StreamingIterator;
 
-use super::{dfs_postorder_next, postorder_ancestors_streaming_iterator_impl};
+use super::{
+    dfs_postorder_next, dfs_postorder_size_hint, postorder_ancestors_streaming_iterator_impl,
+};
 
 crate::collection_iterators::borrowed_collection_iterator_impl!(
     BorrowedDFSPostorderCollectionIterator,
@@ -49,6 +56,21 @@ where
     traversal_stack: Vec<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPostorderIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            item_stack: self.item_stack.clone(),
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPostorderIterator<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -73,6 +95,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedDFSPostorderIteratorWithContext<'a, Node> {
         match self.root {
@@ -100,6 +124,7 @@ where
 {
     type Item = Node::BorrowedValue;
     dfs_postorder_next!(get_value_and_children_iter);
+    dfs_postorder_size_hint!();
 }
 
 pub struct BorrowedDFSPostorderIteratorWithContext<'a, Node>
@@ -107,11 +132,28 @@ where
     Node: BorrowedTreeNode<'a>,
 {
     root: Option<&'a Node>,
-    traversal_stack: Vec<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
     into_iterator_stack: Vec<Node::BorrowedChildren>,
     current_context: TreeContext<Node::BorrowedValue, Node::BorrowedChildren>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPostorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    Node::BorrowedChildren: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            into_iterator_stack: self.into_iterator_stack.clone(),
+            current_context: self.current_context.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPostorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -125,9 +167,19 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for BorrowedDFSPostorderIteratorWithContext<'a, Node>
@@ -140,14 +192,16 @@ where
             let (value, children) = next.get_value_and_children_iter();
             // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
             // If that assumption is not true, this code will cause Undefined Behavior.
-            self.traversal_stack.push(
-                unsafe { core::ptr::read(&children as *const Node::BorrowedChildren) }.into_iter(),
-            );
+            self.traversal_stack.push(Siblings::new(unsafe {
+                core::ptr::read(&children as *const Node::BorrowedChildren)
+            }));
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
             self.into_iterator_stack.push(children);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
@@ -163,15 +217,20 @@ where
                         .expect("There to be a path unless we are on the root element");
                     *last = last.wrapping_add(1);
 
+                    let position = SiblingPosition {
+                        index: *last,
+                        count: *last + 1 + top.len(),
+                    };
+
                     let (value, children) = node.get_value_and_children_iter();
 
                     // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
                     // If that assumption is not true, this code will cause Undefined Behavior.
-                    self.traversal_stack.push(
-                        unsafe { core::ptr::read(&children as *const Node::BorrowedChildren) }
-                            .into_iter(),
-                    );
+                    self.traversal_stack.push(Siblings::new(unsafe {
+                        core::ptr::read(&children as *const Node::BorrowedChildren)
+                    }));
                     self.current_context.ancestors.push(value);
+                    self.current_context.siblings.push(position);
                     self.current_context.path.push(usize::MAX);
                     self.into_iterator_stack.push(children);
                     continue;
@@ -185,6 +244,7 @@ where
             );
             self.traversal_stack.pop();
             self.current_context.path.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             break;
         }
     }
@@ -207,6 +267,21 @@ where
     traversal_stack: Vec<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPostorderIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            item_stack: self.item_stack.clone(),
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPostorderIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -278,6 +353,20 @@ where
     traversal_stack: Vec<<BinaryChildren<&'a Node> as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPostorderIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            item_stack: self.item_stack.clone(),
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPostorderIterator<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -300,6 +389,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedBinaryDFSPostorderIteratorWithContext<'a, Node> {
         match self.root {
@@ -327,6 +418,7 @@ where
 {
     type Item = Node::BorrowedValue;
     dfs_postorder_next!(get_value_and_children_iter);
+    dfs_postorder_size_hint!();
 }
 
 pub struct BorrowedBinaryDFSPostorderIteratorWithAncestors<'a, Node>
@@ -338,6 +430,20 @@ where
     traversal_stack: Vec<BinaryChildren<&'a Node>>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPostorderIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            item_stack: self.item_stack.clone(),
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPostorderIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -377,11 +483,26 @@ where
     Node: BorrowedBinaryTreeNode<'a>,
 {
     root: Option<&'a Node>,
-    traversal_stack: Vec<IntoIter<Option<&'a Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, 2>)>,
     current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; 2]>,
     into_iterator_stack: Vec<[Option<&'a Node>; 2]>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPostorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            current_context: self.current_context.clone(),
+            into_iterator_stack: self.into_iterator_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPostorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -396,11 +517,21 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
             traversal_stack: Vec::new(),
             into_iterator_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for BorrowedBinaryDFSPostorderIteratorWithContext<'a, Node>
@@ -413,21 +544,25 @@ where
             let (value, children) = next.get_value_and_children_binary_iter();
             // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
             // If that assumption is not true, this code will cause Undefined Behavior.
-            self.traversal_stack.push(
+            let count = children.iter().flatten().count();
+            self.traversal_stack.push((
+                count,
                 unsafe { core::ptr::read(&children as *const [Option<&'a Node>; 2]) }.into_iter(),
-            );
+            ));
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
             self.into_iterator_stack.push(children);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
         }
 
         'outer: loop {
-            if let Some(top) = self.traversal_stack.last_mut() {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
                 for node in top.by_ref() {
                     let last = self
                         .current_context
@@ -437,15 +572,19 @@ where
                     *last = last.wrapping_add(1);
 
                     if let Some(node) = node {
+                        let position = SiblingPosition::binary(*last, *count);
                         let (value, children) = node.get_value_and_children_binary_iter();
 
                         // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
                         // If that assumption is not true, this code will cause Undefined Behavior.
-                        self.traversal_stack.push(
+                        let count = children.iter().flatten().count();
+                        self.traversal_stack.push((
+                            count,
                             unsafe { core::ptr::read(&children as *const [Option<&'a Node>; 2]) }
                                 .into_iter(),
-                        );
+                        ));
                         self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
                         self.current_context.path.push(usize::MAX);
                         self.into_iterator_stack.push(children);
                         continue 'outer;
@@ -460,6 +599,7 @@ where
             );
             self.current_context.path.pop();
             self.traversal_stack.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             return;
         }
     }
@@ -32,6 +32,29 @@ macro_rules! dfs_postorder_next {
     };
 }
 
+macro_rules! dfs_postorder_size_hint {
+    () => {
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            if self.root.is_none() && self.item_stack.is_empty() {
+                return (0, Some(0));
+            }
+
+            // Every value on the item stack still has to be yielded, and every
+            // child that hasn't been visited yet has at least one node in its
+            // subtree.
+            let pending_children = self
+                .traversal_stack
+                .iter()
+                .map(|children| children.size_hint().0)
+                .sum::<usize>();
+            (
+                usize::from(self.root.is_some()) + self.item_stack.len() + pending_children,
+                None,
+            )
+        }
+    };
+}
+
 macro_rules! get_mut_context {
     () => {
         fn get_mut(&mut self) -> Option<&mut Self::Item> {
@@ -92,6 +115,7 @@ macro_rules! get_mut_ancestors {
 }
 
 pub(crate) use dfs_postorder_next;
+pub(crate) use dfs_postorder_size_hint;
 pub(crate) use get_mut_ancestors;
 pub(crate) use get_mut_context;
 pub(crate) use postorder_ancestors_streaming_iterator_impl;
@@ -6,15 +6,20 @@ use crate::{
             MutBorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
             MutBorrowedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::mut_borrow::{
+            MutBorrowedBinaryDFSLeavesIteratorWithContext, MutBorrowedDFSLeavesIteratorWithContext,
+        },
         depth_first::mut_borrow::{MutBorrowedBinaryLeavesIterator, MutBorrowedLeavesIterator},
     },
     prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 use alloc::vec::Vec;
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use super::{
-    dfs_postorder_next, get_mut_ancestors, get_mut_context,
+    dfs_postorder_next, dfs_postorder_size_hint, get_mut_ancestors, get_mut_context,
     postorder_ancestors_streaming_iterator_impl,
 };
 
@@ -77,6 +82,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedDFSPostorderIteratorWithContext<'a, Node> {
         match self.root {
@@ -104,6 +111,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     dfs_postorder_next!(get_value_and_children_iter_mut);
+    dfs_postorder_size_hint!();
 }
 
 pub struct MutBorrowedDFSPostorderIteratorWithContext<'a, Node>
@@ -111,7 +119,7 @@ where
     Node: MutBorrowedTreeNode<'a>,
 {
     root: Option<&'a mut Node>,
-    traversal_stack: Vec<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
     into_iterator_stack: Vec<Node::MutBorrowedChildren>,
     current_context: TreeContext<Node::MutBorrowedValue, Node::MutBorrowedChildren>,
 }
@@ -132,9 +140,19 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for MutBorrowedDFSPostorderIteratorWithContext<'a, Node>
@@ -147,15 +165,16 @@ where
             let (value, children) = next.get_value_and_children_iter_mut();
             // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
             // If that assumption is not true, this code will cause Undefined Behavior.
-            self.traversal_stack.push(
-                unsafe { core::ptr::read(&children as *const Node::MutBorrowedChildren) }
-                    .into_iter(),
-            );
+            self.traversal_stack.push(Siblings::new(unsafe {
+                core::ptr::read(&children as *const Node::MutBorrowedChildren)
+            }));
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
             self.into_iterator_stack.push(children);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
@@ -171,15 +190,20 @@ where
                         .expect("There to be a path unless we are on the root element");
                     *last = last.wrapping_add(1);
 
+                    let position = SiblingPosition {
+                        index: *last,
+                        count: *last + 1 + top.len(),
+                    };
+
                     let (value, children) = node.get_value_and_children_iter_mut();
 
                     // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
                     // If that assumption is not true, this code will cause Undefined Behavior.
-                    self.traversal_stack.push(
-                        unsafe { core::ptr::read(&children as *const Node::MutBorrowedChildren) }
-                            .into_iter(),
-                    );
+                    self.traversal_stack.push(Siblings::new(unsafe {
+                        core::ptr::read(&children as *const Node::MutBorrowedChildren)
+                    }));
                     self.current_context.ancestors.push(value);
+                    self.current_context.siblings.push(position);
                     self.current_context.path.push(usize::MAX);
                     self.into_iterator_stack.push(children);
                     continue;
@@ -193,6 +217,7 @@ where
             );
             self.traversal_stack.pop();
             self.current_context.path.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             return;
         }
     }
@@ -322,6 +347,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedBinaryDFSPostorderIteratorWithContext<'a, Node> {
         match self.root {
@@ -349,6 +376,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     dfs_postorder_next!(get_value_and_children_iter_mut);
+    dfs_postorder_size_hint!();
 }
 
 pub struct MutBorrowedBinaryDFSPostorderIteratorWithAncestors<'a, Node>
@@ -408,7 +436,7 @@ where
     Node: MutBorrowedBinaryTreeNode<'a>,
 {
     root: Option<&'a mut Node>,
-    traversal_stack: Vec<IntoIter<Option<*mut Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<*mut Node>, 2>)>,
     current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; 2]>,
     into_iterator_stack: Vec<[Option<*mut Node>; 2]>,
 }
@@ -427,11 +455,21 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
             traversal_stack: Vec::new(),
             into_iterator_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for MutBorrowedBinaryDFSPostorderIteratorWithContext<'a, Node>
@@ -450,21 +488,24 @@ where
 
             // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
             // If that assumption is not true, this code will cause Undefined Behavior.
+            let count = children.iter().flatten().count();
             self.traversal_stack
-                .push([left.clone(), right.clone()].into_iter());
+                .push((count, [left.clone(), right.clone()].into_iter()));
             self.into_iterator_stack.push([left, right]);
 
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
         }
 
         'outer: loop {
-            if let Some(top) = self.traversal_stack.last_mut() {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
                 for node in top.by_ref() {
                     let last = self
                         .current_context
@@ -474,19 +515,22 @@ where
                     *last = last.wrapping_add(1);
 
                     if let Some(node) = node {
+                        let position = SiblingPosition::binary(*last, *count);
                         let (value, mut children) =
                             unsafe { &mut *node }.get_value_and_children_binary_iter_mut();
 
                         let left = children[0].as_mut().map(|val| *val as *mut Node);
                         let right = children[1].as_mut().map(|val| *val as *mut Node);
 
+                        let count = children.iter().flatten().count();
                         self.traversal_stack
-                            .push([left.clone(), right.clone()].into_iter());
+                            .push((count, [left.clone(), right.clone()].into_iter()));
                         self.into_iterator_stack.push([left, right]);
 
                         // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
                         // If that assumption is not true, this code will cause Undefined Behavior.
                         self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
                         self.current_context.path.push(usize::MAX);
                         continue 'outer;
                     }
@@ -501,6 +545,7 @@ where
             );
             self.current_context.path.pop();
             self.traversal_stack.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             return;
         }
     }
@@ -6,15 +6,20 @@ use crate::{
             OwnedBinaryDFSLeavesPostorderIteratorWithAncestors,
             OwnedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::owned::{
+            OwnedBinaryDFSLeavesIteratorWithContext, OwnedDFSLeavesIteratorWithContext,
+        },
         depth_first::owned::{OwnedBinaryLeavesIterator, OwnedLeavesIterator},
     },
     prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode, TreeContext},
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 use alloc::vec::Vec;
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use super::{
-    dfs_postorder_next, get_mut_ancestors, get_mut_context,
+    dfs_postorder_next, dfs_postorder_size_hint, get_mut_ancestors, get_mut_context,
     postorder_ancestors_streaming_iterator_impl,
 };
 
@@ -72,6 +77,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedDFSPostorderIteratorWithContext<Node> {
         match self.root {
@@ -99,6 +106,7 @@ where
 {
     type Item = Node::OwnedValue;
     dfs_postorder_next!(get_value_and_children);
+    dfs_postorder_size_hint!();
 }
 
 pub struct OwnedDFSPostorderIteratorWithContext<Node>
@@ -106,7 +114,7 @@ where
     Node: OwnedTreeNode,
 {
     root: Option<Node>,
-    traversal_stack: Vec<<Node::OwnedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
     current_context: TreeContext<Node::OwnedValue, ()>,
 }
 
@@ -122,9 +130,19 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedDFSLeavesIteratorWithContext<Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<Node> StreamingIterator for OwnedDFSPostorderIteratorWithContext<Node>
@@ -135,11 +153,13 @@ where
     fn advance(&mut self) {
         if let Some(next) = self.root.take() {
             let (value, children) = next.get_value_and_children();
-            self.traversal_stack.push(children.into_iter());
+            self.traversal_stack.push(Siblings::new(children));
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
@@ -155,10 +175,16 @@ where
                         .expect("There to be a path unless we are on the root element");
                     *last = last.wrapping_add(1);
 
+                    let position = SiblingPosition {
+                        index: *last,
+                        count: *last + 1 + top.len(),
+                    };
+
                     let (value, children) = node.get_value_and_children();
 
-                    self.traversal_stack.push(children.into_iter());
+                    self.traversal_stack.push(Siblings::new(children));
                     self.current_context.ancestors.push(value);
+                    self.current_context.siblings.push(position);
                     self.current_context.path.push(usize::MAX);
                     continue;
                 }
@@ -166,6 +192,7 @@ where
 
             self.traversal_stack.pop();
             self.current_context.path.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             return;
         }
     }
@@ -292,6 +319,8 @@ where
         }
     }
 
+    exact_size_impl!(root, traversal_stack);
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedBinaryDFSPostorderIteratorWithContext<Node> {
         match self.root {
@@ -319,6 +348,7 @@ where
 {
     type Item = Node::OwnedValue;
     dfs_postorder_next!(get_value_and_children);
+    dfs_postorder_size_hint!();
 }
 
 pub struct OwnedBinaryDFSPostorderIteratorWithAncestors<Node>
@@ -375,7 +405,7 @@ where
     Node: OwnedBinaryTreeNode,
 {
     root: Option<Node>,
-    traversal_stack: Vec<IntoIter<Option<Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, 2>)>,
     current_context: TreeContext<Node::OwnedValue, ()>,
 }
 
@@ -390,10 +420,20 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
             traversal_stack: Vec::new(),
         }
     }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedBinaryDFSLeavesIteratorWithContext<Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<Node> StreamingIterator for OwnedBinaryDFSPostorderIteratorWithContext<Node>
@@ -404,18 +444,21 @@ where
     fn advance(&mut self) {
         if let Some(next) = self.root.take() {
             let (value, children) = next.get_value_and_children_binary();
-            self.traversal_stack.push(children.into_iter());
+            let count = children.iter().flatten().count();
+            self.traversal_stack.push((count, children.into_iter()));
             self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
             self.current_context.path.push(usize::MAX);
         } else {
             self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
             if self.current_context.ancestors.is_empty() {
                 return;
             }
         }
 
         'outer: loop {
-            if let Some(top) = self.traversal_stack.last_mut() {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
                 for node in top.by_ref() {
                     let last = self
                         .current_context
@@ -425,12 +468,15 @@ where
                     *last = last.wrapping_add(1);
 
                     if let Some(node) = node {
+                        let position = SiblingPosition::binary(*last, *count);
                         // Path is not populated on the first pass over just the root node.
 
                         let (value, children) = node.get_value_and_children_binary();
 
-                        self.traversal_stack.push(children.into_iter());
+                        let count = children.iter().flatten().count();
+                        self.traversal_stack.push((count, children.into_iter()));
                         self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
                         self.current_context.path.push(usize::MAX);
                         continue 'outer;
                     }
@@ -439,6 +485,7 @@ where
 
             self.traversal_stack.pop();
             self.current_context.path.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
             return;
         }
     }
@@ -9,21 +9,28 @@ use crate::{
             BorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
             BorrowedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::borrow::{
+            BorrowedBinaryDFSLeavesIteratorWithContext, BorrowedDFSLeavesIteratorWithContext,
+        },
         depth_first::borrow::{BorrowedBinaryLeavesIterator, BorrowedLeavesIterator},
     },
     prelude::{
-        BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator, BorrowedBinaryTreeNode,
-        BorrowedTreeNode, TreeCollectionIterator, TreeCollectionIteratorBase, TreeContext,
-        TreeIterator, TreeIteratorBase,
+        opt_to_opt, BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator,
+        BorrowedBinaryTreeNode, BorrowedTreeNode, TreeCollectionIterator,
+        TreeCollectionIteratorBase, TreeContext, TreeIterator, TreeIteratorBase,
     },
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 use alloc::vec::Vec;
 use streaming_iterator::StreamingIterator;
 
 use super::{
-    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_next,
-    dfs_preorder_next_with_path_tracking, preorder_ancestors_streaming_iterator_impl,
-    preorder_binary_context_streaming_iterator_impl, preorder_context_streaming_iterator_impl,
+    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_binary_resume_from,
+    dfs_preorder_next, dfs_preorder_next_with_path_tracking, dfs_preorder_resume_from,
+    dfs_preorder_size_hint, preorder_ancestors_streaming_iterator_impl,
+    preorder_binary_context_streaming_iterator_impl, preorder_context_skip_impl,
+    preorder_context_streaming_iterator_impl, preorder_skip_impl,
 };
 
 crate::collection_iterators::borrowed_collection_iterator_impl!(
@@ -157,6 +164,19 @@ where
     traversal_stack: Vec<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPreorderIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPreorderIterator<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -180,23 +200,29 @@ where
         }
     }
 
+    dfs_preorder_resume_from!(get_value_and_children_iter);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedDFSPreorderIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 BorrowedDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> BorrowedDFSPreorderIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 BorrowedDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -207,6 +233,7 @@ where
 {
     type Item = Node::BorrowedValue;
     dfs_preorder_next!(get_value_and_children_iter);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct BorrowedDFSPreorderIteratorWithPathTracking<'a, Node>
@@ -267,10 +294,26 @@ where
     Node: BorrowedTreeNode<'a>,
 {
     root: Option<&'a Node>,
-    traversal_stack: Vec<<Node::BorrowedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
     current_context: TreeContext<Node::BorrowedValue, Node::BorrowedChildren>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    Node::BorrowedChildren: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            current_context: self.current_context.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPreorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -283,9 +326,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for BorrowedDFSPreorderIteratorWithContext<'a, Node>
@@ -305,6 +365,21 @@ where
     item_stack: Vec<Node::BorrowedValue>,
 }
 
+impl<'a, Node> Clone for BorrowedDFSPreorderIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            item_stack: self.item_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedDFSPreorderIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedTreeNode<'a>,
@@ -317,6 +392,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -437,6 +514,18 @@ where
     traversal_stack: Vec<BinaryChildren<&'a Node>>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPreorderIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPreorderIterator<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -458,23 +547,29 @@ where
         }
     }
 
+    dfs_preorder_binary_resume_from!(get_value_and_children_binary_iter);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> BorrowedBinaryDFSPreorderIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 BorrowedBinaryDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> BorrowedBinaryDFSPreorderIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 BorrowedBinaryDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -485,6 +580,7 @@ where
 {
     type Item = Node::BorrowedValue;
     dfs_preorder_next!(get_value_and_children_iter);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct BorrowedBinaryDFSPreorderIteratorWithPathTracking<'a, Node>
@@ -549,6 +645,20 @@ where
     item_stack: Vec<Node::BorrowedValue>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPreorderIteratorWithAncestors<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            item_stack: self.item_stack.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPreorderIteratorWithAncestors<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -561,6 +671,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -588,10 +700,24 @@ where
     Node: BorrowedBinaryTreeNode<'a>,
 {
     root: Option<&'a Node>,
-    traversal_stack: Vec<IntoIter<Option<&'a Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, 2>)>,
     current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; 2]>,
 }
 
+impl<'a, Node> Clone for BorrowedBinaryDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            current_context: self.current_context.clone(),
+        }
+    }
+}
+
 impl<'a, Node> BorrowedBinaryDFSPreorderIteratorWithContext<'a, Node>
 where
     Node: BorrowedBinaryTreeNode<'a>,
@@ -604,9 +730,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for BorrowedBinaryDFSPreorderIteratorWithContext<'a, Node>
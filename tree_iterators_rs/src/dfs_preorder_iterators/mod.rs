@@ -32,6 +32,25 @@ macro_rules! dfs_preorder_next {
     };
 }
 
+macro_rules! dfs_preorder_size_hint {
+    () => {
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            if self.root.is_none() && self.traversal_stack.is_empty() {
+                return (0, Some(0));
+            }
+
+            // Every child that hasn't been visited yet has at least one node
+            // in its subtree.
+            let pending_children = self
+                .traversal_stack
+                .iter()
+                .map(|children| children.size_hint().0)
+                .sum::<usize>();
+            (usize::from(self.root.is_some()) + pending_children, None)
+        }
+    };
+}
+
 macro_rules! dfs_preorder_next_with_path_tracking {
     ($get_value_and_children: ident) => {
         fn next(&mut self) -> Option<Self::Item> {
@@ -86,7 +105,7 @@ macro_rules! dfs_preorder_binary_next_with_path_tracking {
 
             let next = 'outer: loop {
                 if let Some(top) = self.traversal_stack.last_mut() {
-                    while let Some(value) = top.next() {
+                    for value in top.by_ref() {
                         let last = self.path.last_mut().expect("path to have a value");
                         *last = last.wrapping_add(1);
 
@@ -111,6 +130,125 @@ macro_rules! dfs_preorder_binary_next_with_path_tracking {
     };
 }
 
+macro_rules! dfs_preorder_resume_from {
+    ($get_value_and_children: ident) => {
+        #[doc = include_str!("../../doc_files/resume_from.md")]
+        pub fn resume_from(self, path: &[usize]) -> Option<Self> {
+            let mut current = match self.root {
+                Some(root) if self.traversal_stack.is_empty() => root,
+                _ => panic!("Attempted to resume a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            let mut traversal_stack = Vec::with_capacity(path.len());
+            for &index in path {
+                let (_, children) = current.$get_value_and_children();
+                let mut children = children.into_iter();
+                current = children.nth(index)?;
+                traversal_stack.push(children);
+            }
+
+            Some(Self {
+                root: Some(current),
+                traversal_stack,
+            })
+        }
+    };
+}
+
+macro_rules! dfs_preorder_binary_resume_from {
+    ($get_value_and_children_binary: ident) => {
+        #[doc = include_str!("../../doc_files/resume_from.md")]
+        pub fn resume_from(self, path: &[usize]) -> Option<Self> {
+            let mut current = match self.root {
+                Some(root) if self.traversal_stack.is_empty() => root,
+                _ => panic!("Attempted to resume a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            let mut traversal_stack = Vec::with_capacity(path.len());
+            for &index in path {
+                let (_, mut children) = current.$get_value_and_children_binary();
+                current = children.get_mut(index)?.take()?;
+                for earlier in children.iter_mut().take(index) {
+                    earlier.take();
+                }
+                traversal_stack.push(
+                    children
+                        .into_iter()
+                        .flat_map(opt_to_opt as fn(Option<_>) -> Option<_>),
+                );
+            }
+
+            Some(Self {
+                root: Some(current),
+                traversal_stack,
+            })
+        }
+    };
+}
+
+macro_rules! preorder_skip_impl {
+    () => {
+        #[doc = include_str!("../../doc_files/skip_children.md")]
+        pub fn skip_children(&mut self) {
+            if self.root.is_some() {
+                return;
+            }
+
+            // The children of the most recently yielded node are always on
+            // top of the traversal stack.
+            if let Some(children) = self.traversal_stack.last_mut() {
+                children.for_each(drop);
+            }
+        }
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            if self.root.is_some() {
+                return;
+            }
+
+            // Right below the children of the most recently yielded node are
+            // its remaining siblings.
+            for children in self.traversal_stack.iter_mut().rev().take(2) {
+                children.for_each(drop);
+            }
+        }
+    };
+}
+
+macro_rules! preorder_context_skip_impl {
+    () => {
+        preorder_context_skip_impl!(@skip_children);
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            self.skip_children();
+            if let Some(siblings) = self.traversal_stack.last_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+    (binary) => {
+        preorder_context_skip_impl!(@skip_children);
+
+        #[doc = include_str!("../../doc_files/skip_subtree.md")]
+        pub fn skip_subtree(&mut self) {
+            self.skip_children();
+            if let Some((_, siblings)) = self.traversal_stack.last_mut() {
+                siblings.for_each(drop);
+            }
+        }
+    };
+    (@skip_children) => {
+        #[doc = include_str!("../../doc_files/skip_children.md")]
+        pub fn skip_children(&mut self) {
+            // Without its children, the current node gets treated as a leaf
+            // the next time the iterator is advanced.
+            self.current_context.children.take();
+        }
+    };
+}
+
 macro_rules! preorder_ancestors_streaming_iterator_impl {
     ($get_value_and_children: ident) => {
         fn advance(&mut self) {
@@ -169,7 +307,9 @@ macro_rules! preorder_context_streaming_iterator_impl {
             if let Some(root) = self.root.take() {
                 let (value, children) = root.$get_value_and_children();
                 self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(SiblingPosition::ROOT);
                 self.current_context.children = Some(children);
+                self.current_context.index = self.current_context.index.wrapping_add(1);
                 return;
             }
 
@@ -178,10 +318,11 @@ macro_rules! preorder_context_streaming_iterator_impl {
             }
 
             if let Some(children) = self.current_context.children.take() {
-                self.traversal_stack.push(children.into_iter());
+                self.traversal_stack.push(Siblings::new(children));
                 self.current_context.path.push(usize::MAX);
             } else {
                 self.current_context.ancestors.pop();
+                self.current_context.siblings.pop();
                 if let Some(&usize::MAX) = self.current_context.path.last() {
                     self.current_context.path.pop();
                 }
@@ -196,23 +337,32 @@ macro_rules! preorder_context_streaming_iterator_impl {
                             .last_mut()
                             .expect("There to always be a value in the path list");
                         *last = last.wrapping_add(1);
-                        break Some(value);
+
+                        let position = SiblingPosition {
+                            index: *last,
+                            count: *last + 1 + top.len(),
+                        };
+                        break Some((value, position));
                     }
 
                     self.traversal_stack.pop();
                     self.current_context.ancestors.pop();
+                    self.current_context.siblings.pop();
                     self.current_context.path.pop();
                 } else {
                     break None;
                 }
             };
 
-            if let Some(next) = next {
+            if let Some((next, position)) = next {
                 let (value, children) = next.$get_value_and_children();
                 self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(position);
                 self.current_context.children = Some(children);
+                self.current_context.index = self.current_context.index.wrapping_add(1);
             } else {
                 self.current_context.ancestors.clear();
+                self.current_context.siblings.clear();
             }
         }
 
@@ -232,7 +382,9 @@ macro_rules! preorder_binary_context_streaming_iterator_impl {
             if let Some(root) = self.root.take() {
                 let (value, children) = root.$get_value_and_children();
                 self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(SiblingPosition::ROOT);
                 self.current_context.children = Some(children);
+                self.current_context.index = self.current_context.index.wrapping_add(1);
                 return;
             }
 
@@ -241,17 +393,19 @@ macro_rules! preorder_binary_context_streaming_iterator_impl {
             }
 
             if let Some(children) = self.current_context.children.take() {
-                self.traversal_stack.push(children.into_iter());
+                let count = children.iter().flatten().count();
+                self.traversal_stack.push((count, children.into_iter()));
                 self.current_context.path.push(usize::MAX);
             } else {
                 self.current_context.ancestors.pop();
+                self.current_context.siblings.pop();
                 if let Some(&usize::MAX) = self.current_context.path.last() {
                     self.current_context.path.pop();
                 }
             }
 
             let next = 'outer: loop {
-                if let Some(top) = self.traversal_stack.last_mut() {
+                if let Some((count, top)) = self.traversal_stack.last_mut() {
                     while let Some(value) = top.next() {
                         let last = self
                             .current_context
@@ -261,24 +415,29 @@ macro_rules! preorder_binary_context_streaming_iterator_impl {
                         *last = last.wrapping_add(1);
 
                         if let Some(value) = value {
-                            break 'outer Some(value);
+                            let position = SiblingPosition::slot(*count, top.as_slice());
+                            break 'outer Some((value, position));
                         }
                     }
 
                     self.traversal_stack.pop();
                     self.current_context.ancestors.pop();
+                    self.current_context.siblings.pop();
                     self.current_context.path.pop();
                 } else {
                     break None;
                 }
             };
 
-            if let Some(next) = next {
+            if let Some((next, position)) = next {
                 let (value, children) = next.$get_value_and_children();
                 self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(position);
                 self.current_context.children = Some(children);
+                self.current_context.index = self.current_context.index.wrapping_add(1);
             } else {
                 self.current_context.ancestors.clear();
+                self.current_context.siblings.clear();
             }
         }
 
@@ -305,10 +464,15 @@ macro_rules! get_mut_context {
 }
 
 pub(crate) use dfs_preorder_binary_next_with_path_tracking;
+pub(crate) use dfs_preorder_binary_resume_from;
 pub(crate) use dfs_preorder_next;
 pub(crate) use dfs_preorder_next_with_path_tracking;
+pub(crate) use dfs_preorder_resume_from;
+pub(crate) use dfs_preorder_size_hint;
 pub(crate) use get_mut_ancestors;
 pub(crate) use get_mut_context;
 pub(crate) use preorder_ancestors_streaming_iterator_impl;
 pub(crate) use preorder_binary_context_streaming_iterator_impl;
+pub(crate) use preorder_context_skip_impl;
 pub(crate) use preorder_context_streaming_iterator_impl;
+pub(crate) use preorder_skip_impl;
@@ -12,20 +12,26 @@ use crate::{
             MutBorrowedBinaryDFSLeavesPostorderIteratorWithAncestors,
             MutBorrowedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::mut_borrow::{
+            MutBorrowedBinaryDFSLeavesIteratorWithContext, MutBorrowedDFSLeavesIteratorWithContext,
+        },
         depth_first::mut_borrow::{MutBorrowedBinaryLeavesIterator, MutBorrowedLeavesIterator},
     },
     prelude::{
-        BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator,
+        opt_to_opt, BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator,
         MutBorrowedBinaryTreeNode, MutBorrowedTreeNode, TreeCollectionIterator,
         TreeCollectionIteratorBase, TreeContext, TreeIterator, TreeIteratorBase,
     },
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
 };
 
 use super::{
-    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_next,
-    dfs_preorder_next_with_path_tracking, get_mut_ancestors, get_mut_context,
+    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_binary_resume_from,
+    dfs_preorder_next, dfs_preorder_next_with_path_tracking, dfs_preorder_resume_from,
+    dfs_preorder_size_hint, get_mut_ancestors, get_mut_context,
     preorder_ancestors_streaming_iterator_impl, preorder_binary_context_streaming_iterator_impl,
-    preorder_context_streaming_iterator_impl,
+    preorder_context_skip_impl, preorder_context_streaming_iterator_impl, preorder_skip_impl,
 };
 
 crate::collection_iterators::mut_borrowed_collection_iterator_impl!(
@@ -166,23 +172,29 @@ where
         }
     }
 
+    dfs_preorder_resume_from!(get_value_and_children_iter_mut);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedDFSPreorderIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 MutBorrowedDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> MutBorrowedDFSPreorderIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 MutBorrowedDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -193,6 +205,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     dfs_preorder_next!(get_value_and_children_iter_mut);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct MutBorrowedDFSPreorderIteratorWithPathTracking<'a, Node>
@@ -253,7 +266,7 @@ where
     Node: MutBorrowedTreeNode<'a>,
 {
     root: Option<&'a mut Node>,
-    traversal_stack: Vec<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
     current_context: TreeContext<Node::MutBorrowedValue, Node::MutBorrowedChildren>,
 }
 
@@ -269,9 +282,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for MutBorrowedDFSPreorderIteratorWithContext<'a, Node>
@@ -310,6 +340,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -478,23 +510,29 @@ where
         }
     }
 
+    dfs_preorder_binary_resume_from!(get_value_and_children_binary_iter_mut);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> MutBorrowedBinaryDFSPreorderIteratorWithContext<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 MutBorrowedBinaryDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> MutBorrowedBinaryDFSPreorderIteratorWithAncestors<'a, Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 MutBorrowedBinaryDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -505,6 +543,7 @@ where
 {
     type Item = Node::MutBorrowedValue;
     dfs_preorder_next!(get_value_and_children_iter_mut);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct MutBorrowedBinaryDFSPreorderIteratorWithPathTracking<'a, Node>
@@ -583,6 +622,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -620,7 +661,7 @@ where
     Node: MutBorrowedBinaryTreeNode<'a>,
 {
     root: Option<&'a mut Node>,
-    traversal_stack: Vec<IntoIter<Option<&'a mut Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a mut Node>, 2>)>,
     current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; 2]>,
 }
 
@@ -636,9 +677,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<'a, Node> StreamingIterator for MutBorrowedBinaryDFSPreorderIteratorWithContext<'a, Node>
@@ -12,20 +12,27 @@ use crate::{
             OwnedBinaryDFSLeavesPostorderIteratorWithAncestors,
             OwnedDFSLeavesPostorderIteratorWithAncestors,
         },
+        context_depth_first::owned::{
+            OwnedBinaryDFSLeavesIteratorWithContext, OwnedDFSLeavesIteratorWithContext,
+        },
         depth_first::owned::{OwnedBinaryLeavesIterator, OwnedLeavesIterator},
     },
     prelude::{
-        BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator, OwnedBinaryTreeNode,
-        OwnedTreeNode, TreeCollectionIterator, TreeCollectionIteratorBase, TreeContext,
+        opt_to_opt, BinaryChildren, BinaryTreeCollectionIterator, BinaryTreeIterator,
+        OwnedBinaryTreeNode, OwnedTreeNode, TreeCollectionIterator, TreeCollectionIteratorBase,
+        TreeContext,
     },
+    sized_tree::{exact_size_impl, ExactSizeTreeIterator, SizedTreeNode},
+    tree_context::{SiblingPosition, Siblings},
     tree_iterators::{TreeIterator, TreeIteratorBase},
 };
 
 use super::{
-    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_next,
-    dfs_preorder_next_with_path_tracking, get_mut_ancestors, get_mut_context,
+    dfs_preorder_binary_next_with_path_tracking, dfs_preorder_binary_resume_from,
+    dfs_preorder_next, dfs_preorder_next_with_path_tracking, dfs_preorder_resume_from,
+    dfs_preorder_size_hint, get_mut_ancestors, get_mut_context,
     preorder_ancestors_streaming_iterator_impl, preorder_binary_context_streaming_iterator_impl,
-    preorder_context_streaming_iterator_impl,
+    preorder_context_skip_impl, preorder_context_streaming_iterator_impl, preorder_skip_impl,
 };
 
 crate::collection_iterators::owned_collection_iterator_impl!(
@@ -165,23 +172,29 @@ where
         }
     }
 
+    dfs_preorder_resume_from!(get_value_and_children);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedDFSPreorderIteratorWithContext<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 OwnedDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> OwnedDFSPreorderIteratorWithAncestors<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 OwnedDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -192,6 +205,7 @@ where
 {
     type Item = Node::OwnedValue;
     dfs_preorder_next!(get_value_and_children);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct OwnedDFSPreorderIteratorWithPathTracking<Node>
@@ -252,7 +266,7 @@ where
     Node: OwnedTreeNode,
 {
     root: Option<Node>,
-    traversal_stack: Vec<<Node::OwnedChildren as IntoIterator>::IntoIter>,
+    traversal_stack: Vec<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
     current_context: TreeContext<Node::OwnedValue, Node::OwnedChildren>,
 }
 
@@ -268,9 +282,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!();
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedDFSLeavesIteratorWithContext<Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<Node> StreamingIterator for OwnedDFSPreorderIteratorWithContext<Node>
@@ -309,6 +340,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -471,23 +504,29 @@ where
         }
     }
 
+    dfs_preorder_binary_resume_from!(get_value_and_children_binary);
+
+    exact_size_impl!(root, traversal_stack);
+
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/attach_context.md")]
     pub fn attach_context(self) -> OwnedBinaryDFSPreorderIteratorWithContext<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 OwnedBinaryDFSPreorderIteratorWithContext::new(root, Vec::new())
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 
     #[doc = include_str!("../../doc_files/attach_ancestors.md")]
     pub fn attach_ancestors(self) -> OwnedBinaryDFSPreorderIteratorWithAncestors<Node> {
         match self.root {
-            None => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
-            Some(root) => {
+            Some(root) if self.traversal_stack.is_empty() => {
                 OwnedBinaryDFSPreorderIteratorWithAncestors::new(root)
             }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
         }
     }
 }
@@ -498,6 +537,7 @@ where
 {
     type Item = Node::OwnedValue;
     dfs_preorder_next!(get_value_and_children);
+    dfs_preorder_size_hint!();
 }
 
 pub(crate) struct OwnedBinaryDFSPreorderIteratorWithPathTracking<Node>
@@ -574,6 +614,8 @@ where
         }
     }
 
+    preorder_skip_impl!();
+
     #[doc = include_str!("../../doc_files/ancestors_leaves.md")]
     pub fn leaves(
         self,
@@ -607,7 +649,7 @@ where
     Node: OwnedBinaryTreeNode,
 {
     root: Option<Node>,
-    traversal_stack: Vec<IntoIter<Option<Node>, 2>>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, 2>)>,
     current_context: TreeContext<Node::OwnedValue, [Option<Node>; 2]>,
 }
 
@@ -623,9 +665,26 @@ where
                 path,
                 ancestors: Vec::new(),
                 children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
             },
         }
     }
+
+    preorder_context_skip_impl!(binary);
+
+    #[doc = include_str!("../../doc_files/checkpoint.md")]
+    pub fn checkpoint(&self) -> Option<Vec<usize>> {
+        self.get().map(|context| context.path().to_vec())
+    }
+
+    #[doc = include_str!("../../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedBinaryDFSLeavesIteratorWithContext<Node> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedBinaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
 }
 
 impl<Node> StreamingIterator for OwnedBinaryDFSPreorderIteratorWithContext<Node>
@@ -0,0 +1,393 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The costs of the operations used by
+/// [`tree_edit_distance`](crate::prelude::BorrowedTreeNode::tree_edit_distance)
+/// to turn one tree into another.
+///
+/// - Deleting a node from the left tree moves its children up into its parent
+///   in its place.
+/// - Inserting a node into the right tree is the reverse of deleting it.
+/// - Renaming changes the value of a node in the left tree into the value of the
+///   node in the right tree that it is mapped to.
+///
+/// [`new`](EditCosts::new) (or [`default`](Default::default)) creates unit costs,
+/// where inserting and deleting a node each cost 1 and renaming a node costs 0 if
+/// the values are equal and 1 otherwise. Each cost can then be replaced with
+/// [`insert_cost`](EditCosts::insert_cost), [`delete_cost`](EditCosts::delete_cost)
+/// and [`rename_cost`](EditCosts::rename_cost).
+///
+/// ### Example Usage
+/// ```rust
+/// use tree_iterators_rs::prelude::{BorrowedTreeNode, EditCosts, Tree};
+///
+/// let leaf = |value| Tree { value, children: Vec::new() };
+/// let left = Tree { value: "div", children: vec![leaf("p"), leaf("img")] };
+/// let right = Tree { value: "div", children: vec![leaf("p"), leaf("video")] };
+///
+/// assert_eq!(1, left.tree_edit_distance(&right, &EditCosts::new()).distance);
+///
+/// // Replacing an image with a video costs more than adding a video.
+/// let costs = EditCosts::new()
+///     .rename_cost(|left: &&&str, right: &&&str| if left == right { 0 } else { 3 });
+/// let result = left.tree_edit_distance(&right, &costs);
+/// assert_eq!(2, result.distance);
+/// assert_eq!(vec![vec![1]], result.deleted);
+/// assert_eq!(vec![vec![1]], result.inserted);
+/// ```
+pub struct EditCosts<'f, Left, Right> {
+    insert: Box<dyn Fn(&Right) -> usize + 'f>,
+    delete: Box<dyn Fn(&Left) -> usize + 'f>,
+    rename: Box<dyn Fn(&Left, &Right) -> usize + 'f>,
+}
+
+impl<Left, Right> Default for EditCosts<'_, Left, Right>
+where
+    Left: PartialEq<Right>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'f, Left, Right> EditCosts<'f, Left, Right> {
+    /// Creates unit costs. Inserting or deleting a node costs 1, and renaming a
+    /// node costs 0 if the values are equal and 1 otherwise.
+    pub fn new() -> Self
+    where
+        Left: PartialEq<Right>,
+    {
+        Self::with_rename_cost(|left, right| usize::from(left != right))
+    }
+
+    /// Creates costs where inserting or deleting a node costs 1 and renaming a
+    /// node costs whatever the given closure returns. This is useful for values
+    /// that can't be compared with `==`.
+    pub fn with_rename_cost<F>(f: F) -> Self
+    where
+        F: Fn(&Left, &Right) -> usize + 'f,
+    {
+        Self {
+            insert: Box::new(|_| 1),
+            delete: Box::new(|_| 1),
+            rename: Box::new(f),
+        }
+    }
+
+    /// Replaces the cost of inserting a node from the right tree.
+    #[must_use]
+    pub fn insert_cost<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Right) -> usize + 'f,
+    {
+        self.insert = Box::new(f);
+        self
+    }
+
+    /// Replaces the cost of deleting a node from the left tree.
+    #[must_use]
+    pub fn delete_cost<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Left) -> usize + 'f,
+    {
+        self.delete = Box::new(f);
+        self
+    }
+
+    /// Replaces the cost of renaming a node from the left tree into a node
+    /// from the right tree.
+    #[must_use]
+    pub fn rename_cost<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Left, &Right) -> usize + 'f,
+    {
+        self.rename = Box::new(f);
+        self
+    }
+}
+
+/// The result of
+/// [`tree_edit_distance`](crate::prelude::BorrowedTreeNode::tree_edit_distance).
+/// Nodes are identified by their paths, as described in
+/// [`current_path`](crate::prelude::TreeIteratorBase::current_path). Every node
+/// of the left tree is either in `mapping` or `deleted`, and every node of the
+/// right tree is either in `mapping` or `inserted`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TreeEditDistance {
+    /// The total cost of the cheapest series of operations that turns the left
+    /// tree into the right tree.
+    pub distance: usize,
+    /// The pairs of nodes that are kept (and renamed if need be), as
+    /// `(left path, right path)`, sorted by the left path.
+    pub mapping: Vec<(Vec<usize>, Vec<usize>)>,
+    /// The paths of the nodes of the left tree that are deleted, sorted.
+    pub deleted: Vec<Vec<usize>>,
+    /// The paths of the nodes of the right tree that are inserted, sorted.
+    pub inserted: Vec<Vec<usize>>,
+}
+
+/// A tree laid out in postorder, the way the Zhang–Shasha algorithm expects.
+struct Postorder<Value> {
+    values: Vec<Value>,
+    paths: Vec<Vec<usize>>,
+    /// The index of the leftmost leaf of each node's subtree, which is also the
+    /// index of the first node of the subtree.
+    leftmost: Vec<usize>,
+}
+
+impl<Value> Postorder<Value> {
+    /// Lays out the tree rooted at `root`. `get_value_and_children` yields each
+    /// child along with the index it contributes to the child's path.
+    fn new<Node, Children>(
+        root: Node,
+        mut get_value_and_children: impl FnMut(Node) -> (Value, Children),
+    ) -> Self
+    where
+        Children: Iterator<Item = (usize, Node)>,
+    {
+        let mut result = Self {
+            values: Vec::new(),
+            paths: Vec::new(),
+            leftmost: Vec::new(),
+        };
+
+        let (value, children) = get_value_and_children(root);
+        let mut stack = vec![(value, children, Vec::new(), 0)];
+        while let Some((_, children, path, _)) = stack.last_mut() {
+            let next_child = children.next();
+            match next_child {
+                Some((index, child)) => {
+                    let mut child_path = path.clone();
+                    child_path.push(index);
+                    let (value, children) = get_value_and_children(child);
+                    stack.push((value, children, child_path, result.values.len()));
+                }
+                None => {
+                    let (value, _, path, leftmost) = stack.pop().unwrap();
+                    result.values.push(value);
+                    result.paths.push(path);
+                    result.leftmost.push(leftmost);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Computes the edit distance between two trees along with an optimal mapping
+/// using the Zhang–Shasha algorithm. This takes O(n * m) space and, in the worst
+/// case, O(n² * m²) time, though balanced trees are much faster than that.
+pub(crate) fn tree_edit_distance<
+    LeftNode,
+    LeftValue,
+    LeftChildren,
+    RightNode,
+    RightValue,
+    RightChildren,
+>(
+    left: LeftNode,
+    get_left_value_and_children: impl FnMut(LeftNode) -> (LeftValue, LeftChildren),
+    right: RightNode,
+    get_right_value_and_children: impl FnMut(RightNode) -> (RightValue, RightChildren),
+    costs: &EditCosts<'_, LeftValue, RightValue>,
+) -> TreeEditDistance
+where
+    LeftChildren: Iterator<Item = (usize, LeftNode)>,
+    RightChildren: Iterator<Item = (usize, RightNode)>,
+{
+    let left = Postorder::new(left, get_left_value_and_children);
+    let right = Postorder::new(right, get_right_value_and_children);
+    let columns = right.values.len();
+
+    let mut zhang_shasha = ZhangShasha {
+        delete: left
+            .values
+            .iter()
+            .map(|value| (costs.delete)(value))
+            .collect(),
+        insert: right
+            .values
+            .iter()
+            .map(|value| (costs.insert)(value))
+            .collect(),
+        rename: left
+            .values
+            .iter()
+            .flat_map(|left| right.values.iter().map(|right| (costs.rename)(left, right)))
+            .collect(),
+        tree_distances: vec![0; left.values.len() * columns],
+        left_leftmost: &left.leftmost,
+        right_leftmost: &right.leftmost,
+    };
+
+    for left_root in keyroots(&left.leftmost) {
+        for right_root in keyroots(&right.leftmost) {
+            zhang_shasha.forest_distances(left_root, right_root);
+        }
+    }
+
+    let left_root = left.values.len() - 1;
+    let right_root = columns - 1;
+    let mut mapping = zhang_shasha.mapping(left_root, right_root);
+
+    let mut left_mapped = vec![false; left.values.len()];
+    let mut right_mapped = vec![false; columns];
+    for (left_index, right_index) in &mapping {
+        left_mapped[*left_index] = true;
+        right_mapped[*right_index] = true;
+    }
+
+    let unmapped = |mapped: Vec<bool>, paths: &[Vec<usize>]| {
+        let mut result = mapped
+            .into_iter()
+            .zip(paths)
+            .filter(|(mapped, _)| !mapped)
+            .map(|(_, path)| path.clone())
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result
+    };
+
+    mapping
+        .sort_unstable_by(|(left_a, _), (left_b, _)| left.paths[*left_a].cmp(&left.paths[*left_b]));
+    TreeEditDistance {
+        distance: zhang_shasha.tree_distances[left_root * columns + right_root],
+        mapping: mapping
+            .into_iter()
+            .map(|(left_index, right_index)| {
+                (
+                    left.paths[left_index].clone(),
+                    right.paths[right_index].clone(),
+                )
+            })
+            .collect(),
+        deleted: unmapped(left_mapped, &left.paths),
+        inserted: unmapped(right_mapped, &right.paths),
+    }
+}
+
+/// Finds the nodes that are not on the leftmost path of their parent, along with
+/// the root. Their subtrees are the only ones whose distances need computing
+/// directly, since every other subtree's distance falls out of its keyroot's.
+fn keyroots(leftmost: &[usize]) -> Vec<usize> {
+    let mut highest = vec![None; leftmost.len()];
+    for (index, leftmost) in leftmost.iter().enumerate() {
+        highest[*leftmost] = Some(index);
+    }
+
+    let mut result = highest.into_iter().flatten().collect::<Vec<_>>();
+    result.sort_unstable();
+    result
+}
+
+struct ZhangShasha<'l> {
+    delete: Vec<usize>,
+    insert: Vec<usize>,
+    /// The cost of renaming each left node into each right node, row by row.
+    rename: Vec<usize>,
+    /// The distance between each left subtree and each right subtree, row by row.
+    tree_distances: Vec<usize>,
+    left_leftmost: &'l [usize],
+    right_leftmost: &'l [usize],
+}
+
+impl ZhangShasha<'_> {
+    /// Computes the distances between every prefix (in postorder) of the left
+    /// subtree and every prefix of the right subtree, filling in the distances
+    /// between any pair of subtrees that share the leftmost leaves of `left_root`
+    /// and `right_root` along the way. Entry `[x][y]` of the result is the
+    /// distance between the first `x` nodes of the left subtree and the first `y`
+    /// nodes of the right subtree.
+    fn forest_distances(&mut self, left_root: usize, right_root: usize) -> Vec<Vec<usize>> {
+        let columns = self.insert.len();
+        let left_start = self.left_leftmost[left_root];
+        let right_start = self.right_leftmost[right_root];
+
+        let mut distances = vec![vec![0; right_root - right_start + 2]; left_root - left_start + 2];
+        for x in 1..distances.len() {
+            distances[x][0] = distances[x - 1][0] + self.delete[left_start + x - 1];
+        }
+        for y in 1..distances[0].len() {
+            distances[0][y] = distances[0][y - 1] + self.insert[right_start + y - 1];
+        }
+
+        for x in 1..distances.len() {
+            let left = left_start + x - 1;
+            for y in 1..distances[x].len() {
+                let right = right_start + y - 1;
+                let delete = distances[x - 1][y] + self.delete[left];
+                let insert = distances[x][y - 1] + self.insert[right];
+
+                let distance = if self.left_leftmost[left] == left_start
+                    && self.right_leftmost[right] == right_start
+                {
+                    let rename = distances[x - 1][y - 1] + self.rename[left * columns + right];
+                    let distance = delete.min(insert).min(rename);
+                    self.tree_distances[left * columns + right] = distance;
+                    distance
+                } else {
+                    let subtrees = distances[self.left_leftmost[left] - left_start]
+                        [self.right_leftmost[right] - right_start]
+                        + self.tree_distances[left * columns + right];
+                    delete.min(insert).min(subtrees)
+                };
+                distances[x][y] = distance;
+            }
+        }
+
+        distances
+    }
+
+    /// Walks back through the forest distances to find which nodes were kept by
+    /// the cheapest edit between the subtrees rooted at `left_root` and
+    /// `right_root`. Keeping nodes is preferred over deleting and inserting them
+    /// when both cost the same.
+    fn mapping(&mut self, left_root: usize, right_root: usize) -> Vec<(usize, usize)> {
+        let columns = self.insert.len();
+        let mut mapping = Vec::new();
+        let mut pending = vec![(left_root, right_root)];
+        while let Some((left_root, right_root)) = pending.pop() {
+            let distances = self.forest_distances(left_root, right_root);
+            let left_start = self.left_leftmost[left_root];
+            let right_start = self.right_leftmost[right_root];
+
+            let (mut x, mut y) = (distances.len() - 1, distances[0].len() - 1);
+            while x > 0 || y > 0 {
+                if x > 0 && y > 0 {
+                    let left = left_start + x - 1;
+                    let right = right_start + y - 1;
+                    let left_subtree_start = self.left_leftmost[left] - left_start;
+                    let right_subtree_start = self.right_leftmost[right] - right_start;
+
+                    if left_subtree_start == 0 && right_subtree_start == 0 {
+                        if distances[x][y]
+                            == distances[x - 1][y - 1] + self.rename[left * columns + right]
+                        {
+                            mapping.push((left, right));
+                            x -= 1;
+                            y -= 1;
+                            continue;
+                        }
+                    } else if distances[x][y]
+                        == distances[left_subtree_start][right_subtree_start]
+                            + self.tree_distances[left * columns + right]
+                    {
+                        pending.push((left, right));
+                        x = left_subtree_start;
+                        y = right_subtree_start;
+                        continue;
+                    }
+                }
+
+                if x > 0 && distances[x][y] == distances[x - 1][y] + self.delete[left_start + x - 1]
+                {
+                    x -= 1;
+                } else {
+                    y -= 1;
+                }
+            }
+        }
+
+        mapping
+    }
+}
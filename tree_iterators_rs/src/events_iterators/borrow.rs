@@ -0,0 +1,103 @@
+use core::iter::Enumerate;
+
+use alloc::vec::Vec;
+use streaming_iterator::StreamingIterator;
+
+use crate::prelude::{BorrowedBinaryTreeNode, BorrowedTreeNode, TreeEvent, TreeEventContext};
+
+use super::{events_streaming_iterator_impl, next_binary_child, next_child};
+
+pub struct BorrowedEventsIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<Enumerate<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
+    item_stack: Vec<Node::BorrowedValue>,
+    current: TreeEventContext<Node::BorrowedValue>,
+}
+
+impl<'a, Node> Clone for BorrowedEventsIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+    <Node::BorrowedChildren as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            item_stack: self.item_stack.clone(),
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<'a, Node> BorrowedEventsIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for BorrowedEventsIterator<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    type Item = TreeEventContext<Node::BorrowedValue>;
+    events_streaming_iterator_impl!(get_value_and_children_iter, next_child);
+}
+
+pub struct BorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<Enumerate<core::array::IntoIter<Option<&'a Node>, 2>>>,
+    item_stack: Vec<Node::BorrowedValue>,
+    current: TreeEventContext<Node::BorrowedValue>,
+}
+
+impl<'a, Node> Clone for BorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Node::BorrowedValue: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            traversal_stack: self.traversal_stack.clone(),
+            item_stack: self.item_stack.clone(),
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<'a, Node> BorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for BorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeEventContext<Node::BorrowedValue>;
+    events_streaming_iterator_impl!(get_value_and_children_binary_iter, next_binary_child);
+}
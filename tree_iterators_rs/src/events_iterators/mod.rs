@@ -0,0 +1,83 @@
+pub mod borrow;
+pub mod mut_borrow;
+pub mod owned;
+
+use core::iter::Enumerate;
+
+/// Gets the next child of a node along with its index among its siblings.
+pub(crate) fn next_child<Iter, Node>(children: &mut Enumerate<Iter>) -> Option<(usize, Node)>
+where
+    Iter: Iterator<Item = Node>,
+{
+    children.next()
+}
+
+/// Gets the next non-empty child slot of a binary node along with the index
+/// of that slot, skipping over empty slots.
+pub(crate) fn next_binary_child<Node>(
+    children: &mut Enumerate<core::array::IntoIter<Option<Node>, 2>>,
+) -> Option<(usize, Node)> {
+    children.find_map(|(index, child)| Some((index, child?)))
+}
+
+macro_rules! events_streaming_iterator_impl {
+    ($get_value_and_children: ident, $next_child: ident) => {
+        fn advance(&mut self) {
+            match self.current.event.take() {
+                None => {
+                    if let Some(root) = self.root.take() {
+                        let (value, children) = root.$get_value_and_children();
+                        self.traversal_stack.push(children.into_iter().enumerate());
+                        self.current.event = Some(TreeEvent::Enter(value));
+                    }
+                    return;
+                }
+                Some(TreeEvent::Enter(value)) => self.item_stack.push(value),
+                Some(TreeEvent::Exit(_)) => {
+                    self.current.path.pop();
+                }
+            }
+
+            let next = match self.traversal_stack.last_mut() {
+                Some(top) => $next_child(top),
+                None => return,
+            };
+
+            match next {
+                Some((index, child)) => {
+                    let (value, children) = child.$get_value_and_children();
+                    self.traversal_stack.push(children.into_iter().enumerate());
+                    self.current.path.push(index);
+                    self.current.event = Some(TreeEvent::Enter(value));
+                }
+                None => {
+                    self.traversal_stack.pop();
+                    self.current.event = self.item_stack.pop().map(TreeEvent::Exit);
+                }
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current.event.is_some() {
+                Some(&self.current)
+            } else {
+                None
+            }
+        }
+    };
+}
+
+macro_rules! events_get_mut {
+    () => {
+        fn get_mut(&mut self) -> Option<&mut Self::Item> {
+            if self.current.event.is_some() {
+                Some(&mut self.current)
+            } else {
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use events_get_mut;
+pub(crate) use events_streaming_iterator_impl;
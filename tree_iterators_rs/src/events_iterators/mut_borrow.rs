@@ -0,0 +1,86 @@
+use core::iter::Enumerate;
+
+use alloc::vec::Vec;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use crate::prelude::{MutBorrowedBinaryTreeNode, MutBorrowedTreeNode, TreeEvent, TreeEventContext};
+
+use super::{events_get_mut, events_streaming_iterator_impl, next_binary_child, next_child};
+
+pub struct MutBorrowedEventsIterator<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<Enumerate<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
+    item_stack: Vec<Node::MutBorrowedValue>,
+    current: TreeEventContext<Node::MutBorrowedValue>,
+}
+
+impl<'a, Node> MutBorrowedEventsIterator<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedEventsIterator<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    type Item = TreeEventContext<Node::MutBorrowedValue>;
+    events_streaming_iterator_impl!(get_value_and_children_iter_mut, next_child);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedEventsIterator<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    events_get_mut!();
+}
+
+pub struct MutBorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<Enumerate<core::array::IntoIter<Option<&'a mut Node>, 2>>>,
+    item_stack: Vec<Node::MutBorrowedValue>,
+    current: TreeEventContext<Node::MutBorrowedValue>,
+}
+
+impl<'a, Node> MutBorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeEventContext<Node::MutBorrowedValue>;
+    events_streaming_iterator_impl!(get_value_and_children_binary_iter_mut, next_binary_child);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedBinaryEventsIterator<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    events_get_mut!();
+}
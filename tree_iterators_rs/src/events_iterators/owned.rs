@@ -0,0 +1,86 @@
+use core::iter::Enumerate;
+
+use alloc::vec::Vec;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use crate::prelude::{OwnedBinaryTreeNode, OwnedTreeNode, TreeEvent, TreeEventContext};
+
+use super::{events_get_mut, events_streaming_iterator_impl, next_binary_child, next_child};
+
+pub struct OwnedEventsIterator<Node>
+where
+    Node: OwnedTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<Enumerate<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
+    item_stack: Vec<Node::OwnedValue>,
+    current: TreeEventContext<Node::OwnedValue>,
+}
+
+impl<Node> OwnedEventsIterator<Node>
+where
+    Node: OwnedTreeNode,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<Node> StreamingIterator for OwnedEventsIterator<Node>
+where
+    Node: OwnedTreeNode,
+{
+    type Item = TreeEventContext<Node::OwnedValue>;
+    events_streaming_iterator_impl!(get_value_and_children, next_child);
+}
+
+impl<Node> StreamingIteratorMut for OwnedEventsIterator<Node>
+where
+    Node: OwnedTreeNode,
+{
+    events_get_mut!();
+}
+
+pub struct OwnedBinaryEventsIterator<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<Enumerate<core::array::IntoIter<Option<Node>, 2>>>,
+    item_stack: Vec<Node::OwnedValue>,
+    current: TreeEventContext<Node::OwnedValue>,
+}
+
+impl<Node> OwnedBinaryEventsIterator<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+            current: TreeEventContext::new(),
+        }
+    }
+}
+
+impl<Node> StreamingIterator for OwnedBinaryEventsIterator<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    type Item = TreeEventContext<Node::OwnedValue>;
+    events_streaming_iterator_impl!(get_value_and_children_binary, next_binary_child);
+}
+
+impl<Node> StreamingIteratorMut for OwnedBinaryEventsIterator<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    events_get_mut!();
+}
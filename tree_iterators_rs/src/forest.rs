@@ -0,0 +1,319 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::{
+    iter::{Chain, Flatten, Map},
+    option,
+};
+
+use crate::prelude::{BorrowedTreeNode, MutBorrowedTreeNode, OwnedTreeNode, Tree};
+
+/// Merges a list of sibling trees so that no two of them share a key. Trees
+/// with equal keys are combined into the first of them, in order, with their
+/// values folded together by `merge` and their children concatenated and
+/// merged the same way.
+pub(crate) fn merge_by_key<Value, Key, KeyFn, MergeFn>(
+    trees: Vec<Tree<Value>>,
+    key: &mut KeyFn,
+    merge: &mut MergeFn,
+) -> Vec<Tree<Value>>
+where
+    Key: Ord,
+    KeyFn: FnMut(&Value) -> Key,
+    MergeFn: FnMut(Value, Value) -> Value,
+{
+    let mut groups: Vec<Vec<Tree<Value>>> = Vec::new();
+    let mut group_indexes: BTreeMap<Key, usize> = BTreeMap::new();
+    for tree in trees {
+        let tree_key = key(&tree.value);
+        if let Some(&index) = group_indexes.get(&tree_key) {
+            groups[index].push(tree);
+        } else {
+            group_indexes.insert(tree_key, groups.len());
+            groups.push(vec![tree]);
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let mut group = group.into_iter();
+            let first = group.next().unwrap();
+            let mut value = first.value;
+            let mut children = first.children;
+            for tree in group {
+                value = merge(value, tree.value);
+                children.extend(tree.children);
+            }
+
+            Tree {
+                value,
+                children: merge_by_key(children, key, merge),
+            }
+        })
+        .collect()
+}
+
+/// Nests each tree under the tree whose key matches its parent key. See
+/// [`group_into_tree`](crate::prelude::OwnedIntoIteratorOfTrees::group_into_tree).
+pub(crate) fn group_into_tree<Value, Key, KeyFn, ParentFn>(
+    trees: Vec<Tree<Value>>,
+    mut key: KeyFn,
+    mut parent_of: ParentFn,
+) -> Vec<Tree<Value>>
+where
+    Key: Ord,
+    KeyFn: FnMut(&Value) -> Key,
+    ParentFn: FnMut(&Value) -> Option<Key>,
+{
+    let mut indexes = BTreeMap::new();
+    for (index, tree) in trees.iter().enumerate() {
+        indexes.entry(key(&tree.value)).or_insert(index);
+    }
+
+    let mut parents = trees
+        .iter()
+        .enumerate()
+        .map(|(index, tree)| {
+            parent_of(&tree.value)
+                .and_then(|parent_key| indexes.get(&parent_key).copied())
+                .filter(|parent| *parent != index)
+        })
+        .collect::<Vec<_>>();
+
+    let mut children = vec![Vec::new(); trees.len()];
+    for (index, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            children[*parent].push(index);
+        }
+    }
+
+    // Walk down from every root to find the order to assemble the trees in.
+    // Anything left unvisited is part of a cycle, which is broken by turning
+    // its first member into a root.
+    let mut preorder = Vec::with_capacity(trees.len());
+    let mut visited = vec![false; trees.len()];
+    let mut roots = Vec::new();
+    for pass in 0..2 {
+        for start in 0..trees.len() {
+            if visited[start] || (pass == 0 && parents[start].is_some()) {
+                continue;
+            }
+
+            parents[start] = None;
+            roots.push(start);
+            let mut stack = vec![start];
+            while let Some(index) = stack.pop() {
+                if visited[index] {
+                    continue;
+                }
+                visited[index] = true;
+                preorder.push(index);
+                stack.extend(children[index].iter().rev());
+            }
+        }
+    }
+
+    let mut slots = trees.into_iter().map(Some).collect::<Vec<_>>();
+    for index in preorder.into_iter().rev() {
+        let mut tree = slots[index].take().unwrap();
+        for child in &children[index] {
+            if parents[*child] == Some(index) {
+                tree.children.push(slots[*child].take().unwrap());
+            }
+        }
+        slots[index] = Some(tree);
+    }
+
+    roots
+        .into_iter()
+        .map(|root| slots[root].take().unwrap())
+        .collect()
+}
+
+/// Lists every node in the forest in depth first preorder, along with the
+/// index of its parent within the returned list.
+pub(crate) fn flatten<Node>(
+    trees: impl IntoIterator<Item = Node>,
+) -> Vec<(Option<usize>, Node::OwnedValue)>
+where
+    Node: OwnedTreeNode,
+{
+    let mut stack = trees
+        .into_iter()
+        .map(|tree| (None, tree))
+        .collect::<Vec<_>>();
+    stack.reverse();
+
+    let mut result = Vec::new();
+    let mut next = stack.pop();
+    while let Some((parent, node)) = next {
+        let index = result.len();
+        let (value, children) = node.get_value_and_children();
+        result.push((parent, value));
+
+        let children_start = stack.len();
+        stack.extend(children.into_iter().map(|child| (Some(index), child)));
+        stack[children_start..].reverse();
+        next = stack.pop();
+    }
+
+    result
+}
+
+/// Either the virtual root of a forest, holding its value and the trees of the
+/// forest, or a node of one of those trees.
+enum SingleTreeNode<Value, Roots, Node> {
+    Root(Value, Roots),
+    Node(Node),
+}
+
+/// The children of a node of a forest gathered under a virtual root: the trees
+/// of the forest for the root, or the node's own children for any other node.
+type SingleTreeChildren<Roots, Children, Node> = Map<
+    Chain<Flatten<option::IntoIter<Roots>>, Flatten<option::IntoIter<Children>>>,
+    fn(<Roots as Iterator>::Item) -> Node,
+>;
+
+/// Chains the trees of the forest or the children of a node, whichever is
+/// present, and wraps each of them as a node of the single tree.
+fn single_tree_children<Roots, Children, Node>(
+    roots: Option<Roots>,
+    children: Option<Children>,
+    wrap: fn(Roots::Item) -> Node,
+) -> SingleTreeChildren<Roots, Children, Node>
+where
+    Roots: Iterator,
+    Children: Iterator<Item = Roots::Item>,
+{
+    roots
+        .into_iter()
+        .flatten()
+        .chain(children.into_iter().flatten())
+        .map(wrap)
+}
+
+/// A forest of owned trees gathered under a virtual root node. See
+/// [`as_single_tree`](crate::prelude::OwnedIntoIteratorOfTrees::as_single_tree)
+/// for more details.
+pub struct OwnedSingleTree<Roots, Node>(SingleTreeNode<Node::OwnedValue, Roots, Node>)
+where
+    Node: OwnedTreeNode;
+
+impl<Roots, Node> OwnedSingleTree<Roots, Node>
+where
+    Node: OwnedTreeNode,
+{
+    pub(crate) fn new(value: Node::OwnedValue, roots: Roots) -> Self {
+        Self(SingleTreeNode::Root(value, roots))
+    }
+}
+
+impl<Roots, Node> OwnedTreeNode for OwnedSingleTree<Roots, Node>
+where
+    Roots: Iterator<Item = Node>,
+    Node: OwnedTreeNode,
+{
+    type OwnedValue = Node::OwnedValue;
+    type OwnedChildren = SingleTreeChildren<
+        Roots,
+        <Node::OwnedChildren as IntoIterator>::IntoIter,
+        OwnedSingleTree<Roots, Node>,
+    >;
+
+    fn get_value_and_children(self) -> (Self::OwnedValue, Self::OwnedChildren) {
+        let (value, roots, children) = match self.0 {
+            SingleTreeNode::Root(value, roots) => (value, Some(roots), None),
+            SingleTreeNode::Node(node) => {
+                let (value, children) = node.get_value_and_children();
+                (value, None, Some(children.into_iter()))
+            }
+        };
+        let wrap = |node| Self(SingleTreeNode::Node(node));
+        (value, single_tree_children(roots, children, wrap))
+    }
+}
+
+/// A forest of mutably borrowed trees gathered under a virtual root node. See
+/// [`as_single_tree_mut`](crate::prelude::MutBorrowedIntoIteratorOfTrees::as_single_tree_mut)
+/// for more details.
+pub struct MutBorrowedSingleTree<'a, Roots, Node>(
+    SingleTreeNode<Node::MutBorrowedValue, Roots, &'a mut Node>,
+)
+where
+    Node: MutBorrowedTreeNode<'a>;
+
+impl<'a, Roots, Node> MutBorrowedSingleTree<'a, Roots, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(value: Node::MutBorrowedValue, roots: Roots) -> Self {
+        Self(SingleTreeNode::Root(value, roots))
+    }
+}
+
+impl<'a, Roots, Node> OwnedTreeNode for MutBorrowedSingleTree<'a, Roots, Node>
+where
+    Roots: Iterator<Item = &'a mut Node>,
+    Node: MutBorrowedTreeNode<'a>,
+{
+    type OwnedValue = Node::MutBorrowedValue;
+    type OwnedChildren = SingleTreeChildren<
+        Roots,
+        <Node::MutBorrowedChildren as IntoIterator>::IntoIter,
+        MutBorrowedSingleTree<'a, Roots, Node>,
+    >;
+
+    fn get_value_and_children(self) -> (Self::OwnedValue, Self::OwnedChildren) {
+        let (value, roots, children) = match self.0 {
+            SingleTreeNode::Root(value, roots) => (value, Some(roots), None),
+            SingleTreeNode::Node(node) => {
+                let (value, children) = node.get_value_and_children_iter_mut();
+                (value, None, Some(children.into_iter()))
+            }
+        };
+        let wrap = |node| Self(SingleTreeNode::Node(node));
+        (value, single_tree_children(roots, children, wrap))
+    }
+}
+
+/// A forest of borrowed trees gathered under a virtual root node. See
+/// [`as_single_tree_ref`](crate::prelude::BorrowedIntoIteratorOfTrees::as_single_tree_ref)
+/// for more details.
+pub struct BorrowedSingleTree<'a, Roots, Node>(
+    SingleTreeNode<Node::BorrowedValue, Roots, &'a Node>,
+)
+where
+    Node: BorrowedTreeNode<'a>;
+
+impl<'a, Roots, Node> BorrowedSingleTree<'a, Roots, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(value: Node::BorrowedValue, roots: Roots) -> Self {
+        Self(SingleTreeNode::Root(value, roots))
+    }
+}
+
+impl<'a, Roots, Node> OwnedTreeNode for BorrowedSingleTree<'a, Roots, Node>
+where
+    Roots: Iterator<Item = &'a Node>,
+    Node: BorrowedTreeNode<'a>,
+{
+    type OwnedValue = Node::BorrowedValue;
+    type OwnedChildren = SingleTreeChildren<
+        Roots,
+        <Node::BorrowedChildren as IntoIterator>::IntoIter,
+        BorrowedSingleTree<'a, Roots, Node>,
+    >;
+
+    fn get_value_and_children(self) -> (Self::OwnedValue, Self::OwnedChildren) {
+        let (value, roots, children) = match self.0 {
+            SingleTreeNode::Root(value, roots) => (value, Some(roots), None),
+            SingleTreeNode::Node(node) => {
+                let (value, children) = node.get_value_and_children_iter();
+                (value, None, Some(children.into_iter()))
+            }
+        };
+        let wrap = |node| Self(SingleTreeNode::Node(node));
+        (value, single_tree_children(roots, children, wrap))
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash, Hasher};
+
+use crate::prelude::TreeIteratorBase;
+
+/// Computes the Merkle-style hash of a node from its value and the
+/// hashes of each of its children.
+pub(crate) fn hash_node<S, Value>(
+    build_hasher: &S,
+    value: &Value,
+    children: impl ExactSizeIterator<Item = Option<u64>>,
+) -> u64
+where
+    S: BuildHasher,
+    Value: Hash,
+{
+    let mut hasher = build_hasher.build_hasher();
+    value.hash(&mut hasher);
+    hasher.write_usize(children.len());
+    for child in children {
+        child.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Checks whether two tree iterators yield structurally equal trees. Two
+/// trees are considered structurally equal if they yield the same values at
+/// the same paths in the same order.
+pub(crate) fn pipelines_eq<Value, Children>(
+    left: impl TreeIteratorBase<Value, Children>,
+    right: impl TreeIteratorBase<Value, Children>,
+) -> bool
+where
+    Value: PartialEq,
+{
+    crate::isomorphism::pipelines_eq_by(left, right, |left, right| left == right)
+}
+
+/// Groups the paths of all subtrees that share a hash into groups of
+/// structurally equal subtrees, discarding any subtree that is unique.
+/// The resulting groups and the paths within them are ordered by
+/// the order they would appear in a depth first preorder traversal.
+pub(crate) fn group_duplicates<F>(
+    mut hashes_and_paths: Vec<(u64, Vec<usize>)>,
+    mut subtrees_eq: F,
+) -> Vec<Vec<Vec<usize>>>
+where
+    F: FnMut(&[usize], &[usize]) -> bool,
+{
+    // sorting paths lexicographically puts them in preorder.
+    hashes_and_paths.sort_unstable();
+
+    let mut result = Vec::new();
+    let mut hashes_and_paths = hashes_and_paths.into_iter().peekable();
+    while let Some((hash, first_path)) = hashes_and_paths.next() {
+        // hashes can collide, so double check that each subtree
+        // in this bucket is actually equal to the others.
+        let mut groups: Vec<Vec<Vec<usize>>> = Vec::new();
+        let bucket = core::iter::once(first_path).chain(core::iter::from_fn(|| {
+            hashes_and_paths
+                .next_if(|(next_hash, _)| *next_hash == hash)
+                .map(|(_, path)| path)
+        }));
+
+        for path in bucket {
+            match groups
+                .iter_mut()
+                .find(|group| subtrees_eq(&group[0], &path))
+            {
+                Some(group) => group.push(path),
+                None => {
+                    let mut group = Vec::new();
+                    group.push(path);
+                    groups.push(group);
+                }
+            }
+        }
+
+        result.extend(groups.into_iter().filter(|group| group.len() > 1));
+    }
+
+    result.sort_unstable_by(|a, b| a[0].cmp(&b[0]));
+    result
+}
@@ -0,0 +1,463 @@
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::prelude::{BorrowedBinaryTreeNode, BorrowedTreeNode, Tree, TreeIteratorBase};
+
+/// Checks whether two tree iterators yield structurally equal trees using the
+/// given comparison function. Two trees are considered structurally equal if
+/// they yield equal values at the same paths in the same order.
+pub(crate) fn pipelines_eq_by<LeftValue, LeftChildren, RightValue, RightChildren, F>(
+    mut left: impl TreeIteratorBase<LeftValue, LeftChildren>,
+    mut right: impl TreeIteratorBase<RightValue, RightChildren>,
+    mut eq: F,
+) -> bool
+where
+    F: FnMut(&LeftValue, &RightValue) -> bool,
+{
+    loop {
+        let next = (left.next(), right.next());
+        match next {
+            (None, None) => return true,
+            (Some(left_value), Some(right_value)) => {
+                if left.current_path() != right.current_path() || !eq(&left_value, &right_value) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// A tree that has been flattened into postorder so that it can be worked on
+/// without recursion. The root is always the last node.
+pub(crate) struct PostorderTree<Value> {
+    values: Vec<Value>,
+    /// The indexes of the children of each node.
+    children: Vec<Vec<usize>>,
+}
+
+impl<Value> PostorderTree<Value> {
+    pub(crate) fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a node after all of its children have been added and returns its
+    /// index. This is meant to be called from a fold.
+    pub(crate) fn push(&mut self, children: Vec<usize>, value: Value) -> usize {
+        self.values.push(value);
+        self.children.push(children);
+        self.values.len() - 1
+    }
+}
+
+/// Checks whether two trees are equal when the order of each node's children is ignored.
+pub(crate) fn unordered_eq<'a, 'b, Left, Right, F>(
+    left: &'a Left,
+    right: &'b Right,
+    eq: &mut F,
+) -> bool
+where
+    Left: BorrowedTreeNode<'a>,
+    Right: BorrowedTreeNode<'b>,
+    F: FnMut(&Left::BorrowedValue, &Right::BorrowedValue) -> bool,
+{
+    let mut left_tree = PostorderTree::new();
+    left.fold_ref(|children, value| left_tree.push(children, value));
+    let mut right_tree = PostorderTree::new();
+    right.fold_ref(|children, value| right_tree.push(children, value));
+    postorder_unordered_eq(left_tree, right_tree, eq)
+}
+
+/// Checks whether two binary trees are equal when each node's left and right
+/// children are allowed to be swapped.
+pub(crate) fn binary_unordered_eq<'a, 'b, Left, Right, F>(
+    left: &'a Left,
+    right: &'b Right,
+    eq: &mut F,
+) -> bool
+where
+    Left: BorrowedBinaryTreeNode<'a>,
+    Right: BorrowedBinaryTreeNode<'b>,
+    F: FnMut(&Left::BorrowedValue, &Right::BorrowedValue) -> bool,
+{
+    // swapping the children of a node with a single child moves it to the other
+    // slot, so only the children that are present need to be matched up.
+    let mut left_tree = PostorderTree::new();
+    left.fold_ref(|children, value| {
+        left_tree.push(children.into_iter().flatten().collect(), value)
+    });
+    let mut right_tree = PostorderTree::new();
+    right.fold_ref(|children, value| {
+        right_tree.push(children.into_iter().flatten().collect(), value)
+    });
+    postorder_unordered_eq(left_tree, right_tree, eq)
+}
+
+/// Identical to [`unordered_eq`] except that values are compared with their
+/// ordering, so they can be grouped into classes by sorting them.
+pub(crate) fn sorted_unordered_eq<'a, 'b, Left, Right>(left: &'a Left, right: &'b Right) -> bool
+where
+    Left: BorrowedTreeNode<'a>,
+    Right: BorrowedTreeNode<'b>,
+    Left::BorrowedValue: Ord + PartialOrd<Right::BorrowedValue>,
+{
+    let mut left_tree = PostorderTree::new();
+    left.fold_ref(|children, value| left_tree.push(children, value));
+    let mut right_tree = PostorderTree::new();
+    right.fold_ref(|children, value| right_tree.push(children, value));
+    if left_tree.values.len() != right_tree.values.len() {
+        return false;
+    }
+
+    let mut classes = value_classes(&left_tree.values, &mut Ord::cmp);
+    // classes are numbered in sorted order, so one node of each class is enough
+    // to binary search for the class of every value of the right tree.
+    let mut representatives = vec![0; classes.iter().max().map_or(0, |class| class + 1)];
+    for (node, class) in classes.iter().enumerate() {
+        representatives[*class] = node;
+    }
+    for value in &right_tree.values {
+        let mut is_comparable = true;
+        let class = representatives.binary_search_by(|node| {
+            left_tree.values[*node]
+                .partial_cmp(value)
+                .unwrap_or_else(|| {
+                    is_comparable = false;
+                    Ordering::Equal
+                })
+        });
+        match class {
+            Ok(class) if is_comparable => classes.push(class),
+            _ => return false,
+        }
+    }
+
+    let offset = left_tree.values.len();
+    let mut children = shared_children(left_tree.children, right_tree.children);
+    let (ids, _) = canonical_forms(&mut children, &classes, Ordering::Less);
+    ids[offset - 1] == ids[ids.len() - 1]
+}
+
+/// Numbers the nodes of the right tree after those of the left tree, so that
+/// both trees can be given canonical ids together.
+fn shared_children(mut left: Vec<Vec<usize>>, right: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    let offset = left.len();
+    left.extend(right.into_iter().map(|children| {
+        children
+            .into_iter()
+            .map(|child| child + offset)
+            .collect::<Vec<_>>()
+    }));
+    left
+}
+
+/// Checks whether two flattened trees are equal when the order of each node's
+/// children is ignored. Both trees are given canonical ids together, first by
+/// shape alone and then by shape and value class, and they are equal if their
+/// roots get the same id.
+///
+/// `eq` is only used to group values into classes, so it is expected to be an
+/// equivalence relation. It is only called on nodes whose subtrees have the same
+/// shape, comparing each node against one node of every class found so far.
+fn postorder_unordered_eq<LeftValue, RightValue, F>(
+    left: PostorderTree<LeftValue>,
+    right: PostorderTree<RightValue>,
+    eq: &mut F,
+) -> bool
+where
+    F: FnMut(&LeftValue, &RightValue) -> bool,
+{
+    if left.values.len() != right.values.len() {
+        return false;
+    }
+
+    let offset = left.values.len();
+    let mut children = shared_children(left.children, right.children);
+    let left_root = offset - 1;
+    let right_root = children.len() - 1;
+
+    let no_classes = vec![0; children.len()];
+    let (shapes, shape_forms) = canonical_forms(&mut children, &no_classes, Ordering::Less);
+    if shapes[left_root] != shapes[right_root] {
+        return false;
+    }
+
+    let classes =
+        match shared_value_classes(&left.values, &right.values, &shapes, shape_forms.len(), eq) {
+            Some(classes) => classes,
+            None => return false,
+        };
+    let (ids, _) = canonical_forms(&mut children, &classes, Ordering::Less);
+    ids[left_root] == ids[right_root]
+}
+
+/// Groups the values of two flattened trees into classes of values that `eq`
+/// considers equal, numbering the nodes of the right tree after those of the
+/// left tree. Only nodes with the same shape are compared, since no other nodes
+/// can be paired up. Returns `None` if some node has no equal node with the same
+/// shape in the other tree.
+fn shared_value_classes<LeftValue, RightValue, F>(
+    left: &[LeftValue],
+    right: &[RightValue],
+    shapes: &[usize],
+    shape_count: usize,
+    eq: &mut F,
+) -> Option<Vec<usize>>
+where
+    F: FnMut(&LeftValue, &RightValue) -> bool,
+{
+    let offset = left.len();
+    let mut right_by_shape = vec![Vec::new(); shape_count];
+    for (node, shape) in shapes[offset..].iter().enumerate() {
+        right_by_shape[*shape].push(node);
+    }
+
+    // one left node and one right node that are equal to each other, for every
+    // class of every shape
+    let mut representatives: Vec<Vec<(usize, usize, usize)>> = vec![Vec::new(); shape_count];
+    let mut classes = vec![0; shapes.len()];
+    let mut class_count = 0;
+    for (node, value) in left.iter().enumerate() {
+        let shape = shapes[node];
+        let existing = representatives[shape]
+            .iter()
+            .find(|(_, right_node, _)| eq(value, &right[*right_node]))
+            .map(|(_, _, class)| *class);
+        classes[node] = match existing {
+            Some(class) => class,
+            None => {
+                let right_node = *right_by_shape[shape]
+                    .iter()
+                    .find(|right_node| eq(value, &right[**right_node]))?;
+                representatives[shape].push((node, right_node, class_count));
+                class_count += 1;
+                class_count - 1
+            }
+        };
+    }
+
+    for (node, value) in right.iter().enumerate() {
+        let shape = shapes[offset + node];
+        classes[offset + node] = representatives[shape]
+            .iter()
+            .find(|(left_node, _, _)| eq(&left[*left_node], value))
+            .map(|(_, _, class)| *class)?;
+    }
+    Some(classes)
+}
+
+/// Finds the paths of every subtree of `tree` that is isomorphic to `pattern`.
+pub(crate) fn find_subtree<'a, 'b, Node, Pattern, F>(
+    tree: &'a Node,
+    pattern: &'b Pattern,
+    mut eq: F,
+    ordered: bool,
+) -> Vec<Vec<usize>>
+where
+    Node: BorrowedTreeNode<'a>,
+    Pattern: BorrowedTreeNode<'b>,
+    F: FnMut(&Node::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+{
+    // only subtrees with the same number of nodes as the pattern can match it.
+    let pattern_size = pattern.size();
+    let mut candidates = Vec::new();
+    tree.fold_path_ref(|children: Vec<usize>, path, _value| {
+        let size = 1 + children.into_iter().sum::<usize>();
+        if size == pattern_size {
+            candidates.push(path.to_vec());
+        }
+        size
+    });
+
+    // sorting paths lexicographically puts them in preorder.
+    candidates.sort_unstable();
+    candidates.retain(|path| {
+        tree.at_path_ref(path)
+            .unwrap()
+            .is_isomorphic(pattern, &mut eq, ordered)
+    });
+    candidates
+}
+
+/// Identical to [`find_subtree`] except that it works on binary trees.
+pub(crate) fn binary_find_subtree<'a, 'b, Node, Pattern, F>(
+    tree: &'a Node,
+    pattern: &'b Pattern,
+    mut eq: F,
+    ordered: bool,
+) -> Vec<Vec<usize>>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+    Pattern: BorrowedBinaryTreeNode<'b>,
+    F: FnMut(&Node::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+{
+    let pattern_size = pattern.size();
+    let mut candidates = Vec::new();
+    tree.fold_path_ref(|children, path, _value| {
+        let size = 1 + children.into_iter().flatten().sum::<usize>();
+        if size == pattern_size {
+            candidates.push(path.to_vec());
+        }
+        size
+    });
+
+    candidates.sort_unstable();
+    candidates.retain(|path| {
+        tree.at_path_ref(path)
+            .unwrap()
+            .is_isomorphic(pattern, &mut eq, ordered)
+    });
+    candidates
+}
+
+/// Converts a flattened tree into a [`Tree`] where the children of every node
+/// are sorted into the order described by
+/// [`canonicalize_by`](crate::prelude::OwnedTreeNode::canonicalize_by).
+pub(crate) fn canonicalize<Value, F>(tree: PostorderTree<Value>, cmp: &mut F) -> Tree<Value>
+where
+    F: FnMut(&Value, &Value) -> Ordering,
+{
+    let classes = value_classes(&tree.values, cmp);
+    let mut children = tree.children;
+    canonical_forms(&mut children, &classes, Ordering::Less);
+
+    let mut subtrees: Vec<Option<Tree<Value>>> = Vec::with_capacity(children.len());
+    for (value, children) in tree.values.into_iter().zip(children) {
+        let children = children
+            .into_iter()
+            .map(|child| subtrees[child].take().unwrap())
+            .collect();
+        subtrees.push(Some(Tree { value, children }));
+    }
+    subtrees.pop().flatten().unwrap()
+}
+
+/// Builds the AHU encoding of a flattened tree. The children of each node are
+/// written in the order of their encodings, without building the encoding of
+/// each subtree separately.
+pub(crate) fn ahu_encoding(tree: PostorderTree<()>) -> String {
+    let classes = vec![0; tree.values.len()];
+    let mut children = tree.children;
+    // '(' sorts before ')', so an encoding whose children are a prefix of
+    // another encoding's children sorts after it.
+    let (ids, forms) = canonical_forms(&mut children, &classes, Ordering::Greater);
+
+    let mut encoding = String::with_capacity(2 * ids.len());
+    encoding.push('(');
+    let root = ids[ids.len() - 1];
+    let mut stack = vec![forms[root].1.iter()];
+    while let Some(top) = stack.last_mut() {
+        let next = top.next();
+        match next {
+            Some(child) => {
+                encoding.push('(');
+                stack.push(forms[*child].1.iter());
+            }
+            None => {
+                encoding.push(')');
+                stack.pop();
+            }
+        }
+    }
+    encoding
+}
+
+/// Groups values that `cmp` considers equal into classes, numbered in the
+/// order `cmp` sorts them in.
+fn value_classes<Value, F>(values: &[Value], cmp: &mut F) -> Vec<usize>
+where
+    F: FnMut(&Value, &Value) -> Ordering,
+{
+    let mut order = (0..values.len()).collect::<Vec<_>>();
+    order.sort_by(|left, right| cmp(&values[*left], &values[*right]));
+
+    let mut classes = vec![0; values.len()];
+    for pair in order.windows(2) {
+        let is_new_class = cmp(&values[pair[0]], &values[pair[1]]) != Ordering::Equal;
+        classes[pair[1]] = classes[pair[0]] + usize::from(is_new_class);
+    }
+    classes
+}
+
+/// Gives every node of a flattened tree a canonical id, so that two subtrees
+/// get the same id if and only if they are isomorphic and their values are in
+/// the same classes. Each distinct subtree is interned as its value class and
+/// the ids of its children, and the children of each node are sorted in place
+/// into canonical order along the way.
+///
+/// Canonical order compares the value classes first and then the children in
+/// order. `prefix_order` is how a subtree whose children are a prefix of the
+/// other subtree's children is ordered.
+///
+/// Returns the id of every node and the interned form of every id.
+fn canonical_forms(
+    children: &mut [Vec<usize>],
+    classes: &[usize],
+    prefix_order: Ordering,
+) -> (Vec<usize>, Vec<(usize, Vec<usize>)>) {
+    let mut ids: Vec<usize> = Vec::with_capacity(children.len());
+    let mut forms: Vec<(usize, Vec<usize>)> = Vec::new();
+    let mut interned = BTreeMap::new();
+    for (node, node_children) in children.iter_mut().enumerate() {
+        node_children.sort_by(|left, right| {
+            cmp_canonical_forms(&forms, ids[*left], ids[*right], prefix_order)
+        });
+
+        let form = (
+            classes[node],
+            node_children
+                .iter()
+                .map(|child| ids[*child])
+                .collect::<Vec<_>>(),
+        );
+        let id = match interned.get(&form) {
+            Some(id) => *id,
+            None => {
+                let id = forms.len();
+                forms.push(form.clone());
+                interned.insert(form, id);
+                id
+            }
+        };
+        ids.push(id);
+    }
+    (ids, forms)
+}
+
+/// Compares two canonical ids in canonical order. Distinct ids always differ
+/// in their value class or in one of their children, so only the first pair of
+/// children that differ needs to be compared.
+fn cmp_canonical_forms(
+    forms: &[(usize, Vec<usize>)],
+    mut left: usize,
+    mut right: usize,
+    prefix_order: Ordering,
+) -> Ordering {
+    loop {
+        if left == right {
+            return Ordering::Equal;
+        }
+
+        let (left_class, left_children) = &forms[left];
+        let (right_class, right_children) = &forms[right];
+        if left_class != right_class {
+            return left_class.cmp(right_class);
+        }
+
+        let first_difference = left_children
+            .iter()
+            .zip(right_children)
+            .find(|(left_child, right_child)| left_child != right_child);
+        match first_difference {
+            Some((left_child, right_child)) => {
+                left = *left_child;
+                right = *right_child;
+            }
+            None if left_children.len() < right_children.len() => return prefix_order,
+            None => return prefix_order.reverse(),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+use serde_json::{Map, Number, Value};
+
+use crate::prelude::{OwnedTreeNode, Tree};
+
+/// The value of a node in a JSON document that has been converted into a
+/// [`Tree`] with [`Tree::from_json`].
+///
+/// Scalars become leaves. Objects and arrays become [`Object`](JsonNode::Object)
+/// and [`Array`](JsonNode::Array) nodes with one [`Key`](JsonNode::Key) or
+/// [`Index`](JsonNode::Index) child per entry, and each entry node has the
+/// entry's value as its only child. This means the path to the value of
+/// `document["users"][1]` is `[i, 0, 1, 0]`, where `i` is the position of
+/// `"users"` in its object.
+///
+/// Ex. the document `{"a": [true, null]}` becomes the following tree.
+/// ```text
+///     Object
+///       |
+///    Key("a")
+///       |
+///     Array
+///     /    \
+/// Index(0) Index(1)
+///    |        |
+/// Bool(true) Null
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JsonNode {
+    /// A JSON `null`.
+    Null,
+    /// A JSON `true` or `false`.
+    Bool(bool),
+    /// A JSON number.
+    Number(Number),
+    /// A JSON string.
+    String(String),
+    /// A JSON array. Its children are [`Index`](JsonNode::Index) nodes.
+    Array,
+    /// A JSON object. Its children are [`Key`](JsonNode::Key) nodes.
+    Object,
+    /// An entry of an object. Its only child is the value stored under the key.
+    Key(String),
+    /// An entry of an array. Its only child is the value stored at the index.
+    /// The indexes are only informational: when converting back to JSON, the
+    /// elements of an array are taken in the order of the array's children, so
+    /// removing or reordering elements does not require renumbering them.
+    Index(usize),
+}
+
+/// The reasons a [`Tree<JsonNode>`](JsonNode) could not be converted back into
+/// a [`serde_json::Value`] with [`Tree::into_json`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonTreeError {
+    /// A [`Key`](JsonNode::Key) node is not a child of an
+    /// [`Object`](JsonNode::Object) node, or an [`Index`](JsonNode::Index) node
+    /// is not a child of an [`Array`](JsonNode::Array) node. This includes entry
+    /// nodes at the root of the tree.
+    MisplacedEntry,
+    /// A child of an [`Object`](JsonNode::Object) or [`Array`](JsonNode::Array)
+    /// node is a value rather than a [`Key`](JsonNode::Key) or
+    /// [`Index`](JsonNode::Index) node.
+    MissingEntry,
+    /// A [`Key`](JsonNode::Key) or [`Index`](JsonNode::Index) node does not have
+    /// exactly one child.
+    InvalidEntryValue,
+    /// An [`Object`](JsonNode::Object) node has more than one entry with the
+    /// same key.
+    DuplicateKey,
+    /// A null, boolean, number or string node has children.
+    ScalarWithChildren,
+}
+
+impl Display for JsonTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::MisplacedEntry => "a key or index node is not the child of an object or array",
+            Self::MissingEntry => "a child of an object or array is not a key or index node",
+            Self::InvalidEntryValue => "a key or index node does not have exactly one child",
+            Self::DuplicateKey => "an object has more than one entry with the same key",
+            Self::ScalarWithChildren => "a null, boolean, number or string node has children",
+        };
+        f.write_str(message)
+    }
+}
+
+impl core::error::Error for JsonTreeError {}
+
+/// A part of a JSON document that has not been added to the tree yet.
+enum Pending {
+    Value(Value),
+    Entry(JsonNode, Value),
+}
+
+impl Pending {
+    /// Splits this part of the document into the value of its node and the
+    /// parts that make up its children.
+    fn expand(self) -> (JsonNode, Vec<Pending>) {
+        let value = match self {
+            Pending::Entry(node, value) => return (node, vec![Pending::Value(value)]),
+            Pending::Value(value) => value,
+        };
+
+        match value {
+            Value::Null => (JsonNode::Null, Vec::new()),
+            Value::Bool(value) => (JsonNode::Bool(value), Vec::new()),
+            Value::Number(value) => (JsonNode::Number(value), Vec::new()),
+            Value::String(value) => (JsonNode::String(value), Vec::new()),
+            Value::Array(items) => (
+                JsonNode::Array,
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| Pending::Entry(JsonNode::Index(index), item))
+                    .collect(),
+            ),
+            Value::Object(entries) => (
+                JsonNode::Object,
+                entries
+                    .into_iter()
+                    .map(|(key, value)| Pending::Entry(JsonNode::Key(key), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// A subtree that has been converted back into JSON.
+enum Converted {
+    Value(Value),
+    Entry(JsonNode, Value),
+}
+
+impl Tree<JsonNode> {
+    /// Converts a JSON document into a tree so that it can be used with the rest
+    /// of this crate, such as [`prune_path`](OwnedTreeNode::prune_path),
+    /// [`map_path`](OwnedTreeNode::map_path) or
+    /// [`fold_ref`](crate::prelude::BorrowedTreeNode::fold_ref). See [`JsonNode`]
+    /// for the shape of the resulting tree. [`into_json`](Tree::into_json)
+    /// converts the tree back into the same document.
+    ///
+    /// This works on arbitrarily deep documents.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use serde_json::json;
+    /// use tree_iterators_rs::prelude::{OwnedTreeNode, Tree};
+    ///
+    /// let document = json!({
+    ///     "name": "tree_iterators_rs",
+    ///     "keywords": ["tree", "traversal", "iteration"],
+    /// });
+    ///
+    /// // drop the second keyword
+    /// let tree = Tree::from_json(document)
+    ///     .prune_path(|path, _| path.len() == 3 && path[2] == 1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     Ok(json!({
+    ///         "name": "tree_iterators_rs",
+    ///         "keywords": ["tree", "iteration"],
+    ///     })),
+    ///     tree.into_json()
+    /// );
+    /// ```
+    pub fn from_json(value: Value) -> Self {
+        let (root, children) = Pending::Value(value).expand();
+        let mut stack = vec![(root, children.into_iter(), Vec::new())];
+        loop {
+            let (_, pending, _) = stack.last_mut().unwrap();
+            let next_child = pending.next();
+            match next_child {
+                Some(child) => {
+                    let (value, children) = child.expand();
+                    stack.push((value, children.into_iter(), Vec::new()));
+                }
+                None => {
+                    let (value, _, children) = stack.pop().unwrap();
+                    let tree = Tree { value, children };
+                    match stack.last_mut() {
+                        Some((_, _, siblings)) => siblings.push(tree),
+                        None => return tree,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Converts a tree created by [`from_json`](Tree::from_json) back into a JSON
+    /// document. Any changes made to the tree along the way are carried over, as
+    /// long as the tree still has the shape described in [`JsonNode`]. The
+    /// elements of each array are taken in order, regardless of the values of
+    /// their [`Index`](JsonNode::Index) nodes.
+    ///
+    /// This works on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use serde_json::json;
+    /// use tree_iterators_rs::prelude::{JsonNode, JsonTreeError, OwnedTreeNode, Tree};
+    ///
+    /// let document = json!({ "scores": [1, 2, 3] });
+    /// let doubled = Tree::from_json(document).map(|node| match node {
+    ///     JsonNode::Number(number) => JsonNode::Number((number.as_u64().unwrap() * 2).into()),
+    ///     node => node,
+    /// });
+    /// assert_eq!(Ok(json!({ "scores": [2, 4, 6] })), doubled.into_json());
+    ///
+    /// // an entry can't be the root of a document
+    /// let orphaned_key = Tree {
+    ///     value: JsonNode::Key("scores".to_string()),
+    ///     children: vec![Tree { value: JsonNode::Null, children: vec![] }],
+    /// };
+    /// assert_eq!(Err(JsonTreeError::MisplacedEntry), orphaned_key.into_json());
+    /// ```
+    pub fn into_json(self) -> Result<Value, JsonTreeError> {
+        match self.fold(convert)? {
+            Converted::Value(value) => Ok(value),
+            Converted::Entry(_, _) => Err(JsonTreeError::MisplacedEntry),
+        }
+    }
+}
+
+/// Converts a node back into JSON once all of its children have been converted.
+fn convert(
+    children: Vec<Result<Converted, JsonTreeError>>,
+    node: JsonNode,
+) -> Result<Converted, JsonTreeError> {
+    let children = children.into_iter().collect::<Result<Vec<_>, _>>()?;
+    let scalar = |value| {
+        if children.is_empty() {
+            Ok(Converted::Value(value))
+        } else {
+            Err(JsonTreeError::ScalarWithChildren)
+        }
+    };
+
+    match node {
+        JsonNode::Null => scalar(Value::Null),
+        JsonNode::Bool(value) => scalar(Value::Bool(value)),
+        JsonNode::Number(value) => scalar(Value::Number(value)),
+        JsonNode::String(value) => scalar(Value::String(value)),
+        JsonNode::Key(_) | JsonNode::Index(_) => {
+            let mut children = children.into_iter();
+            match (children.next(), children.next()) {
+                (Some(Converted::Value(value)), None) => Ok(Converted::Entry(node, value)),
+                (Some(Converted::Entry(_, _)), None) => Err(JsonTreeError::MisplacedEntry),
+                _ => Err(JsonTreeError::InvalidEntryValue),
+            }
+        }
+        JsonNode::Array => {
+            let mut items = Vec::with_capacity(children.len());
+            for child in children {
+                match child {
+                    Converted::Entry(JsonNode::Index(_), value) => items.push(value),
+                    Converted::Entry(_, _) => return Err(JsonTreeError::MisplacedEntry),
+                    Converted::Value(_) => return Err(JsonTreeError::MissingEntry),
+                }
+            }
+            Ok(Converted::Value(Value::Array(items)))
+        }
+        JsonNode::Object => {
+            let mut entries = Map::new();
+            for child in children {
+                match child {
+                    Converted::Entry(JsonNode::Key(key), value) => {
+                        if entries.contains_key(&key) {
+                            return Err(JsonTreeError::DuplicateKey);
+                        }
+                        entries.insert(key, value);
+                    }
+                    Converted::Entry(_, _) => return Err(JsonTreeError::MisplacedEntry),
+                    Converted::Value(_) => return Err(JsonTreeError::MissingEntry),
+                }
+            }
+            Ok(Converted::Value(Value::Object(entries)))
+        }
+    }
+}
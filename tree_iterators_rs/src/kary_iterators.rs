@@ -0,0 +1,1869 @@
+use core::array::IntoIter;
+
+use alloc::{collections::VecDeque, vec::Vec};
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use crate::{
+    bfs_iterators::{
+        bfs_ancestors_advance_iterator, bfs_ancestors_streaming_iterator_impl,
+        bfs_context_advance_iterator, bfs_context_binary_streaming_iterator_impl, bfs_next,
+        get_mut_ancestors, get_mut_context, TreeNodeVecDeque,
+    },
+    dfs_postorder_iterators::{dfs_postorder_next, postorder_ancestors_streaming_iterator_impl},
+    dfs_preorder_iterators::{
+        dfs_preorder_binary_next_with_path_tracking, dfs_preorder_next,
+        preorder_ancestors_streaming_iterator_impl,
+        preorder_binary_context_streaming_iterator_impl,
+    },
+    kary_tree::KaryChildren,
+    leaves_iterators::{
+        ancestors_breadth_first::{
+            borrow::BorrowedKaryBFSLeavesIteratorWithAncestors,
+            mut_borrow::MutBorrowedKaryBFSLeavesIteratorWithAncestors,
+            owned::OwnedKaryBFSLeavesIteratorWithAncestors,
+        },
+        ancestors_depth_first::{
+            borrow::BorrowedKaryDFSLeavesPostorderIteratorWithAncestors,
+            mut_borrow::MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors,
+            owned::OwnedKaryDFSLeavesPostorderIteratorWithAncestors,
+        },
+        breadth_first,
+        context_breadth_first::{
+            borrow::BorrowedKaryBFSLeavesIteratorWithContext,
+            mut_borrow::MutBorrowedKaryBFSLeavesIteratorWithContext,
+            owned::OwnedKaryBFSLeavesIteratorWithContext,
+        },
+        context_depth_first::{
+            borrow::BorrowedKaryDFSLeavesIteratorWithContext,
+            mut_borrow::MutBorrowedKaryDFSLeavesIteratorWithContext,
+            owned::OwnedKaryDFSLeavesIteratorWithContext,
+        },
+        depth_first,
+    },
+    prelude::{
+        BorrowedKaryTreeNode, KaryTreeIterator, MutBorrowedKaryTreeNode, OwnedKaryTreeNode,
+        TreeContext, TreeIteratorBase,
+    },
+    tree_context::SiblingPosition,
+};
+
+pub struct OwnedKaryBFSIterator<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_queue: VecDeque<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> OwnedKaryBFSIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> breadth_first::owned::OwnedKaryLeavesIterator<Node, N> {
+        breadth_first::owned::OwnedKaryLeavesIterator {
+            root: self.root,
+            old_traversal_queue: self.traversal_queue,
+            new_traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> OwnedKaryBFSIteratorWithContext<Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => OwnedKaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> OwnedKaryBFSIteratorWithAncestors<Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => OwnedKaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryBFSIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+    bfs_next!(get_value_and_children);
+}
+
+pub struct OwnedKaryDFSPreorderIterator<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPreorderIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::owned::OwnedKaryLeavesIterator<Node, N> {
+        depth_first::owned::OwnedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> OwnedKaryDFSPreorderIteratorWithContext<Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                OwnedKaryDFSPreorderIteratorWithContext::new(root, Vec::new())
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> OwnedKaryDFSPreorderIteratorWithAncestors<Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                OwnedKaryDFSPreorderIteratorWithAncestors::new(root)
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryDFSPreorderIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+    dfs_preorder_next!(get_value_and_children);
+}
+
+pub struct OwnedKaryDFSPostorderIterator<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    item_stack: Vec<Node::OwnedValue>,
+    traversal_stack: Vec<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPostorderIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::owned::OwnedKaryLeavesIterator<Node, N> {
+        depth_first::owned::OwnedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> OwnedKaryDFSPostorderIteratorWithContext<Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedKaryDFSPostorderIteratorWithContext::new(root, Vec::new()),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> OwnedKaryDFSPostorderIteratorWithAncestors<Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedKaryDFSPostorderIteratorWithAncestors::new(root),
+        }
+    }
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryDFSPostorderIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+    dfs_postorder_next!(get_value_and_children);
+}
+
+pub(crate) struct OwnedKaryDFSPreorderIteratorWithPathTracking<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<IntoIter<Option<Node>, N>>,
+    path: Vec<usize>,
+    on_deck_into_iterator: Option<[Option<Node>; N]>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPreorderIteratorWithPathTracking<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            path: Vec::new(),
+            on_deck_into_iterator: None,
+        }
+    }
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryDFSPreorderIteratorWithPathTracking<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+    dfs_preorder_binary_next_with_path_tracking!(get_value_and_children_kary);
+}
+
+impl<Node, const N: usize> TreeIteratorBase<Node::OwnedValue, [Option<Node>; N]>
+    for OwnedKaryDFSPreorderIteratorWithPathTracking<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn current_path(&self) -> &[usize] {
+        &self.path
+    }
+
+    fn prune_current_subtree(&mut self) {
+        self.on_deck_into_iterator.take();
+    }
+}
+
+impl<Node, const N: usize> KaryTreeIterator<Node::OwnedValue, [Option<Node>; N], N>
+    for OwnedKaryDFSPreorderIteratorWithPathTracking<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+}
+
+pub struct OwnedKaryBFSIteratorWithAncestors<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) is_root: bool,
+    pub(crate) item_stack: Vec<Node::OwnedValue>,
+    pub(crate) tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    pub(crate) iterator_queue: VecDeque<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> OwnedKaryBFSIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node) -> Self {
+        let (value, children) = root.get_value_and_children();
+        let mut iterator_queue = VecDeque::new();
+        let mut item_stack = Vec::new();
+
+        item_stack.push(value);
+        iterator_queue.push_back(children);
+
+        Self {
+            is_root: true,
+            item_stack,
+            iterator_queue,
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> OwnedKaryBFSLeavesIteratorWithAncestors<Node, N> {
+        OwnedKaryBFSLeavesIteratorWithAncestors::new(self)
+    }
+
+    bfs_ancestors_advance_iterator!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryBFSIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = [Node::OwnedValue];
+
+    bfs_ancestors_streaming_iterator_impl!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryBFSIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct OwnedKaryBFSIteratorWithContext<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    is_root: bool,
+    tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<Node>, N>)>,
+    current_context: TreeContext<Node::OwnedValue, [Option<Node>; N]>,
+    path_counter: usize,
+}
+
+impl<Node, const N: usize> OwnedKaryBFSIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node, path: Vec<usize>) -> Self {
+        let (value, children) = root.get_value_and_children_kary();
+        let mut current_context = TreeContext {
+            path,
+            ancestors: Vec::new(),
+            children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            is_root: true,
+            current_context,
+            iterator_queue: VecDeque::new(),
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+            path_counter: 0,
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> OwnedKaryBFSLeavesIteratorWithContext<Node, N> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        OwnedKaryBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryBFSIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = TreeContext<Node::OwnedValue, [Option<Node>; N]>;
+    bfs_context_binary_streaming_iterator_impl!(get_value_and_children_kary);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryBFSIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedKaryDFSPreorderIteratorWithAncestors<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<KaryChildren<Node, N>>,
+    item_stack: Vec<Node::OwnedValue>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPreorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> OwnedKaryDFSLeavesPostorderIteratorWithAncestors<Node, N> {
+        OwnedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryDFSPreorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = [Node::OwnedValue];
+    preorder_ancestors_streaming_iterator_impl!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut
+    for OwnedKaryDFSPreorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct OwnedKaryDFSPreorderIteratorWithContext<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, N>)>,
+    current_context: TreeContext<Node::OwnedValue, [Option<Node>; N]>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPreorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedKaryDFSLeavesIteratorWithContext<Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryDFSPreorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = TreeContext<Node::OwnedValue, [Option<Node>; N]>;
+    preorder_binary_context_streaming_iterator_impl!(get_value_and_children_kary);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryDFSPreorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedKaryDFSPostorderIteratorWithAncestors<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    item_stack: Vec<Node::OwnedValue>,
+    traversal_stack: Vec<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPostorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> OwnedKaryDFSLeavesPostorderIteratorWithAncestors<Node, N> {
+        OwnedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryDFSPostorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = [Node::OwnedValue];
+    postorder_ancestors_streaming_iterator_impl!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut
+    for OwnedKaryDFSPostorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct OwnedKaryDFSPostorderIteratorWithContext<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, N>)>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSPostorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    fn new(root: Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> OwnedKaryDFSLeavesIteratorWithContext<Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => OwnedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryDFSPostorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    fn advance(&mut self) {
+        if let Some(next) = self.root.take() {
+            let (value, children) = next.get_value_and_children_kary();
+            let count = children.iter().flatten().count();
+            self.traversal_stack.push((count, children.into_iter()));
+            self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
+            self.current_context.path.push(usize::MAX);
+        } else {
+            self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
+            if self.current_context.ancestors.is_empty() {
+                return;
+            }
+        }
+
+        'outer: loop {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
+                let mut next = top.next();
+                while let Some(node) = next {
+                    let last = self
+                        .current_context
+                        .path
+                        .last_mut()
+                        .expect("There to be a path unless we are on the root element");
+                    *last = last.wrapping_add(1);
+
+                    if let Some(node) = node {
+                        let position = SiblingPosition::slot(*count, top.as_slice());
+                        let (value, children) = node.get_value_and_children_kary();
+
+                        let count = children.iter().flatten().count();
+                        self.traversal_stack.push((count, children.into_iter()));
+                        self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
+                        self.current_context.path.push(usize::MAX);
+                        continue 'outer;
+                    }
+
+                    next = top.next();
+                }
+            }
+
+            self.traversal_stack.pop();
+            self.current_context.path.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
+            return;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        if self.current_context.ancestors.is_empty() {
+            None
+        } else {
+            Some(&self.current_context)
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIteratorMut
+    for OwnedKaryDFSPostorderIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedKaryBFSIterator<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_queue: VecDeque<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryBFSIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> breadth_first::mut_borrow::MutBorrowedKaryLeavesIterator<'a, Node, N> {
+        breadth_first::mut_borrow::MutBorrowedKaryLeavesIterator {
+            root: self.root,
+            old_traversal_queue: self.traversal_queue,
+            new_traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> MutBorrowedKaryBFSIteratorWithContext<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedKaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => MutBorrowedKaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for MutBorrowedKaryBFSIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+    bfs_next!(get_value_and_children_iter_mut);
+}
+
+pub struct MutBorrowedKaryDFSPreorderIterator<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPreorderIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::mut_borrow::MutBorrowedKaryLeavesIterator<'a, Node, N> {
+        depth_first::mut_borrow::MutBorrowedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> MutBorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                MutBorrowedKaryDFSPreorderIteratorWithContext::new(root, Vec::new())
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> MutBorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                MutBorrowedKaryDFSPreorderIteratorWithAncestors::new(root)
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for MutBorrowedKaryDFSPreorderIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+    dfs_preorder_next!(get_value_and_children_iter_mut);
+}
+
+pub struct MutBorrowedKaryDFSPostorderIterator<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    item_stack: Vec<Node::MutBorrowedValue>,
+    traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPostorderIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::mut_borrow::MutBorrowedKaryLeavesIterator<'a, Node, N> {
+        depth_first::mut_borrow::MutBorrowedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> MutBorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedKaryDFSPostorderIteratorWithContext::new(root, Vec::new()),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> MutBorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedKaryDFSPostorderIteratorWithAncestors::new(root),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for MutBorrowedKaryDFSPostorderIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+    dfs_postorder_next!(get_value_and_children_iter_mut);
+}
+
+pub(crate) struct MutBorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<IntoIter<Option<&'a mut Node>, N>>,
+    path: Vec<usize>,
+    on_deck_into_iterator: Option<[Option<&'a mut Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            path: Vec::new(),
+            on_deck_into_iterator: None,
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator
+    for MutBorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+    dfs_preorder_binary_next_with_path_tracking!(get_value_and_children_kary_iter_mut);
+}
+
+impl<'a, Node, const N: usize> TreeIteratorBase<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>
+    for MutBorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn current_path(&self) -> &[usize] {
+        &self.path
+    }
+
+    fn prune_current_subtree(&mut self) {
+        self.on_deck_into_iterator.take();
+    }
+}
+
+impl<'a, Node, const N: usize>
+    KaryTreeIterator<Node::MutBorrowedValue, [Option<&'a mut Node>; N], N>
+    for MutBorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+}
+
+pub struct MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) is_root: bool,
+    pub(crate) item_stack: Vec<Node::MutBorrowedValue>,
+    pub(crate) tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    pub(crate) iterator_queue: VecDeque<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node) -> Self {
+        let (value, children) = root.get_value_and_children_iter_mut();
+        let mut iterator_queue = VecDeque::new();
+        let mut item_stack = Vec::new();
+
+        item_stack.push(value);
+        iterator_queue.push_back(children);
+
+        Self {
+            is_root: true,
+            item_stack,
+            iterator_queue,
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N> {
+        MutBorrowedKaryBFSLeavesIteratorWithAncestors::new(self)
+    }
+
+    bfs_ancestors_advance_iterator!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::MutBorrowedValue];
+
+    bfs_ancestors_streaming_iterator_impl!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct MutBorrowedKaryBFSIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    is_root: bool,
+    tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a mut Node>, N>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>,
+    path_counter: usize,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryBFSIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        let (value, children) = root.get_value_and_children_kary_iter_mut();
+        let mut current_context = TreeContext {
+            path,
+            ancestors: Vec::new(),
+            children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            is_root: true,
+            current_context,
+            iterator_queue: VecDeque::new(),
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+            path_counter: 0,
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> MutBorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        MutBorrowedKaryBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryBFSIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>;
+    bfs_context_binary_streaming_iterator_impl!(get_value_and_children_kary_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryBFSIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+    item_stack: Vec<Node::MutBorrowedValue>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N> {
+        MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::MutBorrowedValue];
+    preorder_ancestors_streaming_iterator_impl!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct MutBorrowedKaryDFSPreorderIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a mut Node>, N>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>;
+    preorder_binary_context_streaming_iterator_impl!(get_value_and_children_kary_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    item_stack: Vec<Node::MutBorrowedValue>,
+    traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N> {
+        MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::MutBorrowedValue];
+    postorder_ancestors_streaming_iterator_impl!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_ancestors!();
+}
+
+pub struct MutBorrowedKaryDFSPostorderIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<*mut Node>, N>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>,
+    into_iterator_stack: Vec<[Option<*mut Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+            into_iterator_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => MutBorrowedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, [Option<&'a mut Node>; N]>;
+    fn advance(&mut self) {
+        self.current_context.children = None;
+
+        if let Some(next) = self.root.take() {
+            let (value, children) = next.get_value_and_children_kary_iter_mut();
+            let children = children.map(|child| child.map(|child| child as *mut Node));
+
+            // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
+            // If that assumption is not true, this code will cause Undefined Behavior.
+            let count = children.iter().flatten().count();
+            self.traversal_stack.push((count, children.into_iter()));
+            self.into_iterator_stack.push(children);
+
+            self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
+            self.current_context.path.push(usize::MAX);
+        } else {
+            self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
+            if self.current_context.ancestors.is_empty() {
+                return;
+            }
+        }
+
+        'outer: loop {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
+                let mut next = top.next();
+                while let Some(node) = next {
+                    let last = self
+                        .current_context
+                        .path
+                        .last_mut()
+                        .expect("There to be a path unless we are on the root element");
+                    *last = last.wrapping_add(1);
+
+                    if let Some(node) = node {
+                        let position = SiblingPosition::slot(*count, top.as_slice());
+                        let (value, children) =
+                            unsafe { &mut *node }.get_value_and_children_kary_iter_mut();
+                        let children = children.map(|child| child.map(|child| child as *mut Node));
+
+                        // ASSUMPTION: self.into_iterator_stack will always outlive self.traversal_stack.
+                        // If that assumption is not true, this code will cause Undefined Behavior.
+                        let count = children.iter().flatten().count();
+                        self.traversal_stack.push((count, children.into_iter()));
+                        self.into_iterator_stack.push(children);
+
+                        self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
+                        self.current_context.path.push(usize::MAX);
+                        continue 'outer;
+                    }
+
+                    next = top.next();
+                }
+            }
+
+            self.current_context.children = Some(
+                self.into_iterator_stack
+                    .pop()
+                    .expect("There to be a children IntoIterator")
+                    .map(|opt| opt.map(|val| unsafe { &mut *val })),
+            );
+            self.current_context.path.pop();
+            self.traversal_stack.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
+            return;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        if self.current_context.ancestors.is_empty() {
+            None
+        } else {
+            Some(&self.current_context)
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_context!();
+}
+
+pub struct BorrowedKaryBFSIterator<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_queue: VecDeque<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryBFSIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> breadth_first::borrow::BorrowedKaryLeavesIterator<'a, Node, N> {
+        breadth_first::borrow::BorrowedKaryLeavesIterator {
+            root: self.root,
+            old_traversal_queue: self.traversal_queue,
+            new_traversal_queue: VecDeque::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> BorrowedKaryBFSIteratorWithContext<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => BorrowedKaryBFSIteratorWithContext::new(root, Vec::new()),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> BorrowedKaryBFSIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_queue.is_empty() => BorrowedKaryBFSIteratorWithAncestors::new(root),
+            _ => panic!("Attempted to attach metadata to a BFS iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for BorrowedKaryBFSIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+    bfs_next!(get_value_and_children_iter);
+}
+
+pub struct BorrowedKaryDFSPreorderIterator<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPreorderIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::borrow::BorrowedKaryLeavesIterator<'a, Node, N> {
+        depth_first::borrow::BorrowedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> BorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                BorrowedKaryDFSPreorderIteratorWithContext::new(root, Vec::new())
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> BorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            Some(root) if self.traversal_stack.is_empty() => {
+                BorrowedKaryDFSPreorderIteratorWithAncestors::new(root)
+            }
+            _ => panic!("Attempted to attach metadata to a DFS preorder iterator in the middle of a tree traversal. This is forbidden."),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for BorrowedKaryDFSPreorderIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+    dfs_preorder_next!(get_value_and_children_iter);
+}
+
+pub struct BorrowedKaryDFSPostorderIterator<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    item_stack: Vec<Node::BorrowedValue>,
+    traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPostorderIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/leaves.md")]
+    pub fn leaves(self) -> depth_first::borrow::BorrowedKaryLeavesIterator<'a, Node, N> {
+        depth_first::borrow::BorrowedKaryLeavesIterator {
+            root: self.root,
+            traversal_stack_bottom: self.traversal_stack,
+            traversal_stack_top: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_context.md")]
+    pub fn attach_context(self) -> BorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedKaryDFSPostorderIteratorWithContext::new(root, Vec::new()),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/attach_ancestors.md")]
+    pub fn attach_ancestors(self) -> BorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to attach metadata to a DFS postorder iterator in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedKaryDFSPostorderIteratorWithAncestors::new(root),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator for BorrowedKaryDFSPostorderIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+    dfs_postorder_next!(get_value_and_children_iter);
+}
+
+pub(crate) struct BorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<IntoIter<Option<&'a Node>, N>>,
+    path: Vec<usize>,
+    on_deck_into_iterator: Option<[Option<&'a Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            path: Vec::new(),
+            on_deck_into_iterator: None,
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> Iterator
+    for BorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+    dfs_preorder_binary_next_with_path_tracking!(get_value_and_children_kary_iter);
+}
+
+impl<'a, Node, const N: usize> TreeIteratorBase<Node::BorrowedValue, [Option<&'a Node>; N]>
+    for BorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn current_path(&self) -> &[usize] {
+        &self.path
+    }
+
+    fn prune_current_subtree(&mut self) {
+        self.on_deck_into_iterator.take();
+    }
+}
+
+impl<'a, Node, const N: usize> KaryTreeIterator<Node::BorrowedValue, [Option<&'a Node>; N], N>
+    for BorrowedKaryDFSPreorderIteratorWithPathTracking<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+}
+
+pub struct BorrowedKaryBFSIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) is_root: bool,
+    pub(crate) item_stack: Vec<Node::BorrowedValue>,
+    pub(crate) tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    pub(crate) traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    pub(crate) iterator_queue: VecDeque<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryBFSIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node) -> Self {
+        let (value, children) = root.get_value_and_children_iter();
+        let mut iterator_queue = VecDeque::new();
+        let mut item_stack = Vec::new();
+
+        item_stack.push(value);
+        iterator_queue.push_back(children);
+
+        Self {
+            is_root: true,
+            item_stack,
+            iterator_queue,
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> BorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N> {
+        BorrowedKaryBFSLeavesIteratorWithAncestors::new(self)
+    }
+
+    bfs_ancestors_advance_iterator!(get_value_and_children_iter);
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryBFSIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::BorrowedValue];
+
+    bfs_ancestors_streaming_iterator_impl!(get_value_and_children_iter);
+}
+
+pub struct BorrowedKaryBFSIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    is_root: bool,
+    tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a Node>, N>)>,
+    current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>,
+    path_counter: usize,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryBFSIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        let (value, children) = root.get_value_and_children_kary_iter();
+        let mut current_context = TreeContext {
+            path,
+            ancestors: Vec::new(),
+            children: Some(children),
+            siblings: Vec::new(),
+            index: usize::MAX,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            is_root: true,
+            current_context,
+            iterator_queue: VecDeque::new(),
+            traversal_stack: Vec::new(),
+            tree_cache: TreeNodeVecDeque::default(),
+            path_counter: 0,
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(mut self) -> BorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N> {
+        if !self.is_root {
+            panic!("Attempted to get the leaves of a BFS iterator with context in the middle of a tree traversal. This is forbidden.");
+        }
+
+        let value = self
+            .current_context
+            .ancestors
+            .pop()
+            .expect("the root to be the only ancestor");
+        let children = self
+            .current_context
+            .children
+            .take()
+            .expect("the root's children to be populated");
+        BorrowedKaryBFSLeavesIteratorWithContext::new(value, children, self.current_context.path)
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node, const N: usize> StreamingIterator for BorrowedKaryBFSIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>;
+    bfs_context_binary_streaming_iterator_impl!(get_value_and_children_kary_iter);
+}
+
+pub struct BorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+    item_stack: Vec<Node::BorrowedValue>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> BorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N> {
+        BorrowedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSPreorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::BorrowedValue];
+    preorder_ancestors_streaming_iterator_impl!(get_value_and_children_iter);
+}
+
+pub struct BorrowedKaryDFSPreorderIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, N>)>,
+    current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS preorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSPreorderIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>;
+    preorder_binary_context_streaming_iterator_impl!(get_value_and_children_kary_iter);
+}
+
+pub struct BorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    item_stack: Vec<Node::BorrowedValue>,
+    traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            item_stack: Vec::new(),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_leaves.md")]
+    pub fn leaves(self) -> BorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N> {
+        BorrowedKaryDFSLeavesPostorderIteratorWithAncestors {
+            root: self.root,
+            item_stack: self.item_stack,
+            old_traversal_stack: self.traversal_stack,
+            new_traversal_stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::BorrowedValue];
+    postorder_ancestors_streaming_iterator_impl!(get_value_and_children_iter);
+}
+
+pub struct BorrowedKaryDFSPostorderIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, N>)>,
+    current_context: TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>,
+    into_iterator_stack: Vec<[Option<&'a Node>; N]>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: None,
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+            into_iterator_stack: Vec::new(),
+        }
+    }
+
+    #[doc = include_str!("../doc_files/context_leaves.md")]
+    pub fn leaves(self) -> BorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N> {
+        match self.root {
+            None => panic!("Attempted to get the leaves of a DFS postorder iterator with context in the middle of a tree traversal. This is forbidden."),
+            Some(root) => BorrowedKaryDFSLeavesIteratorWithContext::new(root, self.current_context.path),
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSPostorderIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::BorrowedValue, [Option<&'a Node>; N]>;
+    fn advance(&mut self) {
+        if let Some(next) = self.root.take() {
+            let (value, children) = next.get_value_and_children_kary_iter();
+            let count = children.iter().flatten().count();
+            self.traversal_stack.push((count, children.into_iter()));
+            self.into_iterator_stack.push(children);
+            self.current_context.ancestors.push(value);
+            self.current_context.siblings.push(SiblingPosition::ROOT);
+            self.current_context.path.push(usize::MAX);
+        } else {
+            self.current_context.ancestors.pop();
+            self.current_context.siblings.pop();
+            if self.current_context.ancestors.is_empty() {
+                return;
+            }
+        }
+
+        'outer: loop {
+            if let Some((count, top)) = self.traversal_stack.last_mut() {
+                let mut next = top.next();
+                while let Some(node) = next {
+                    let last = self
+                        .current_context
+                        .path
+                        .last_mut()
+                        .expect("There to be a path unless we are on the root element");
+                    *last = last.wrapping_add(1);
+
+                    if let Some(node) = node {
+                        let position = SiblingPosition::slot(*count, top.as_slice());
+                        let (value, children) = node.get_value_and_children_kary_iter();
+
+                        let count = children.iter().flatten().count();
+                        self.traversal_stack.push((count, children.into_iter()));
+                        self.into_iterator_stack.push(children);
+                        self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
+                        self.current_context.path.push(usize::MAX);
+                        continue 'outer;
+                    }
+
+                    next = top.next();
+                }
+            }
+
+            self.current_context.children = Some(
+                self.into_iterator_stack
+                    .pop()
+                    .expect("There to be a children IntoIterator"),
+            );
+            self.current_context.path.pop();
+            self.traversal_stack.pop();
+            self.current_context.index = self.current_context.index.wrapping_add(1);
+            return;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        if self.current_context.ancestors.is_empty() {
+            None
+        } else {
+            Some(&self.current_context)
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use alloc::boxed::Box;
+use core::iter::FlatMap;
+
+use crate::{
+    kary_iterators::{
+        BorrowedKaryBFSIterator, BorrowedKaryDFSPostorderIterator, BorrowedKaryDFSPreorderIterator,
+        BorrowedKaryDFSPreorderIteratorWithPathTracking, MutBorrowedKaryBFSIterator,
+        MutBorrowedKaryDFSPostorderIterator, MutBorrowedKaryDFSPreorderIterator,
+        MutBorrowedKaryDFSPreorderIteratorWithPathTracking, OwnedKaryBFSIterator,
+        OwnedKaryDFSPostorderIterator, OwnedKaryDFSPreorderIterator,
+        OwnedKaryDFSPreorderIteratorWithPathTracking,
+    },
+    prelude::{opt_to_opt, KaryTreeIterator, TreeIteratorBase},
+};
+
+/// Helper type to define the KaryTreeNode's
+/// Children iterator type.
+pub(crate) type KaryChildren<T, const N: usize> =
+    FlatMap<core::array::IntoIter<Option<T>, N>, Option<T>, fn(Option<T>) -> Option<T>>;
+
+/// A tree node with exactly N child slots, each of which may be empty. This
+/// generalizes [`BinaryTree`](crate::prelude::BinaryTree) to quadtrees,
+/// octrees, and other fixed-fanout structures.
+///
+/// Like [`BinaryTree`](crate::prelude::BinaryTree), paths into a [`KaryTree`]
+/// are made up of slot indexes, so empty slots still count towards a child's
+/// position. The child in the last slot of a quadtree always has a path
+/// segment of 3, regardless of which of the other slots are populated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KaryTree<T, const N: usize> {
+    /// This node's value
+    pub value: T,
+    /// The child slots of the current node.
+    pub children: [Option<Box<KaryTree<T, N>>>; N],
+}
+
+impl<T, const N: usize> KaryTree<T, N> {
+    /// Creates a new leaf node with all N child slots empty.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::KaryTree;
+    ///
+    /// let leaf = KaryTree::<_, 4>::new(0);
+    /// assert!(leaf.children.iter().all(Option::is_none));
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: core::array::from_fn(|_| None),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for KaryTree<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, const N: usize> KaryTree<&T, N>
+where
+    T: Clone,
+{
+    /// Maps a [`KaryTree<&T, N>`] to a [`KaryTree<T, N>`] by cloning the contents of the [`KaryTree`].
+    pub fn cloned(&self) -> KaryTree<T, N> {
+        self.map_ref(|item| (*item).clone())
+    }
+}
+
+impl<T, const N: usize> KaryTree<&mut T, N>
+where
+    T: Clone,
+{
+    /// Maps a [`KaryTree<&mut T, N>`] to a [`KaryTree<T, N>`] by cloning the contents of the [`KaryTree`].
+    pub fn cloned(&self) -> KaryTree<T, N> {
+        self.map_ref(|item| (*item).clone())
+    }
+}
+
+/// A tree node with N child slots where getting its children consumes its value.
+pub trait OwnedKaryTreeNode<const N: usize>
+where
+    Self: Sized,
+{
+    /// The value of each node in the tree.
+    type OwnedValue;
+
+    /// This method gets the value and child slots from this node, consuming it
+    /// in the process. The other methods of this trait assume that the children
+    /// do not contain any circular references. If they do, it will create an
+    /// infinite loop.
+    fn get_value_and_children_kary(self) -> (Self::OwnedValue, [Option<Self>; N]);
+
+    /// This method gets the value and the populated children from this node,
+    /// consuming it in the process. Empty slots are skipped.
+    fn get_value_and_children(self) -> (Self::OwnedValue, KaryChildren<Self, N>) {
+        let (value, children) = self.get_value_and_children_kary();
+        (
+            value,
+            children
+                .into_iter()
+                .flat_map(opt_to_opt as fn(Option<Self>) -> Option<Self>),
+        )
+    }
+
+    #[doc = include_str!("../doc_files/at_path.md")]
+    ///
+    /// Each path segment is a slot index, so a path through an empty slot
+    /// returns [`None`].
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{KaryTree, OwnedKaryTreeNode};
+    ///
+    /// let mut tree = KaryTree::<_, 3>::new(0);
+    /// tree.children[2] = Some(Box::new(KaryTree::new(2)));
+    ///
+    /// assert_eq!(Some(2), tree.clone().at_path(&[2]).map(|node| node.value));
+    /// assert!(tree.at_path(&[0]).is_none());
+    /// ```
+    fn at_path(self, path: &[usize]) -> Option<Self> {
+        let mut current = self;
+        for path_segment in path {
+            current = current
+                .get_value_and_children_kary()
+                .1
+                .into_iter()
+                .nth(*path_segment)??;
+        }
+        Some(current)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Breadth First (Queue - specifically VecDeque-based) searches of a tree.
+    /// Each level of the tree is scanned from the lowest slot index to the
+    /// highest before going down to the next level.
+    #[must_use]
+    fn bfs(self) -> OwnedKaryBFSIterator<Self, N> {
+        OwnedKaryBFSIterator::new(self)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Depth First Preorder searches of a tree. Each node is traversed before
+    /// any of its children, and children are traversed in slot order.
+    #[must_use]
+    fn dfs_preorder(self) -> OwnedKaryDFSPreorderIterator<Self, N> {
+        OwnedKaryDFSPreorderIterator::new(self)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Depth First Postorder searches of a tree. Each node is traversed after
+    /// all of its children, and children are traversed in slot order.
+    #[must_use]
+    fn dfs_postorder(self) -> OwnedKaryDFSPostorderIterator<Self, N> {
+        OwnedKaryDFSPostorderIterator::new(self)
+    }
+
+    /// This method converts the current KaryTreeNode into a KaryTreeIterator.
+    /// See [`OwnedBinaryTreeNode::into_pipeline`](crate::prelude::OwnedBinaryTreeNode::into_pipeline)
+    /// for more details.
+    ///
+    /// ### Example Usage:
+    /// ```rust
+    /// use tree_iterators_rs::prelude::*;
+    ///
+    /// let mut tree = KaryTree::<usize, 4>::new(0);
+    /// tree.children[1] = Some(Box::new(KaryTree::new(1)));
+    /// tree.children[3] = Some(Box::new(KaryTree::new(2)));
+    ///
+    /// let result = tree
+    ///     .into_pipeline()
+    ///     .prune_depth(1)
+    ///     .map_tree(|value| value * 10)
+    ///     .collect_tree()
+    ///     .expect("all non-prune methods to collect into a Some()");
+    ///
+    /// assert_eq!(Some(10), result.children[1].as_ref().map(|child| child.value));
+    /// assert_eq!(Some(20), result.children[3].as_ref().map(|child| child.value));
+    /// ```
+    #[must_use]
+    fn into_pipeline(self) -> impl KaryTreeIterator<Self::OwnedValue, [Option<Self>; N], N> {
+        OwnedKaryDFSPreorderIteratorWithPathTracking::new(self)
+    }
+
+    /// Identical to [`OwnedBinaryTreeNode::prune`](crate::prelude::OwnedBinaryTreeNode::prune)
+    /// except that it works on trees with N child slots. Pruned subtrees leave
+    /// their slot empty.
+    fn prune<F>(self, f: F) -> Option<KaryTree<Self::OwnedValue, N>>
+    where
+        F: FnMut(&Self::OwnedValue) -> bool,
+    {
+        self.into_pipeline().prune(f).collect_tree()
+    }
+
+    /// Prunes every node with a depth that is strictly greater than the
+    /// max_depth parameter. Depth is zero-based, so the root node is
+    /// considered to be at depth zero.
+    fn prune_depth(self, max_depth: usize) -> KaryTree<Self::OwnedValue, N> {
+        self.into_pipeline()
+            .prune_depth(max_depth)
+            .collect_tree()
+            .expect("this should never prune the root of the tree")
+    }
+
+    /// Identical to [`prune`](OwnedKaryTreeNode::prune) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn prune_path<F>(self, f: F) -> Option<KaryTree<Self::OwnedValue, N>>
+    where
+        F: FnMut(&[usize], &Self::OwnedValue) -> bool,
+    {
+        self.into_pipeline().prune_path(f).collect_tree()
+    }
+
+    /// map is a tree-based analog to [map](core::iter::Iterator::map). The
+    /// resulting tree has the same shape as this one, including its empty slots.
+    fn map<Output, F>(self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(Self::OwnedValue) -> Output,
+    {
+        self.into_pipeline().map_tree(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`map`](OwnedKaryTreeNode::map) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn map_path<Output, F>(self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(&[usize], Self::OwnedValue) -> Output,
+    {
+        self.into_pipeline().map_path(f).collect_tree().unwrap()
+    }
+
+    /// fold is a tree-based analog to [fold](core::iter::Iterator::fold). The
+    /// closure is passed the accumulations of all N child slots, with [`None`]
+    /// for each empty slot.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{KaryTree, OwnedKaryTreeNode};
+    ///
+    /// let mut tree = KaryTree::<usize, 3>::new(1);
+    /// tree.children[0] = Some(Box::new(KaryTree::new(2)));
+    /// tree.children[2] = Some(Box::new(KaryTree::new(3)));
+    ///
+    /// let sum = tree.fold(|children: [Option<usize>; 3], value| {
+    ///     children.into_iter().flatten().sum::<usize>() + value
+    /// });
+    /// assert_eq!(6, sum);
+    /// ```
+    fn fold<Output, F>(self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], Self::OwnedValue) -> Output,
+    {
+        self.into_pipeline()
+            .fold_tree(f)
+            .expect("there to always be at least the root to fold")
+    }
+
+    /// Identical to [`fold`](OwnedKaryTreeNode::fold) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn fold_path<Output, F>(self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], &[usize], Self::OwnedValue) -> Output,
+    {
+        self.into_pipeline()
+            .fold_path(f)
+            .expect("there to always be at least the root to fold")
+    }
+}
+
+/// A tree node with N child slots where getting its children mutably borrows its value.
+pub trait MutBorrowedKaryTreeNode<'a, const N: usize>
+where
+    Self: Sized + 'a,
+{
+    /// A mutable reference to the value of each node in the tree.
+    type MutBorrowedValue;
+
+    /// This method gets the value and child slots from this node. The other
+    /// methods of this trait assume that the children do not contain any
+    /// circular references. If they do, it will create an infinite loop.
+    fn get_value_and_children_kary_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, [Option<&'a mut Self>; N]);
+
+    /// This method gets the value and the populated children from this node.
+    /// Empty slots are skipped.
+    fn get_value_and_children_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, KaryChildren<&'a mut Self, N>) {
+        let (value, children) = self.get_value_and_children_kary_iter_mut();
+        (
+            value,
+            children
+                .into_iter()
+                .flat_map(opt_to_opt as fn(Option<&'a mut Self>) -> Option<&'a mut Self>),
+        )
+    }
+
+    #[doc = include_str!("../doc_files/at_path.md")]
+    ///
+    /// Each path segment is a slot index, so a path through an empty slot
+    /// returns [`None`].
+    fn at_path_mut(&'a mut self, path: &[usize]) -> Option<&'a mut Self> {
+        let mut current = self;
+        for path_segment in path {
+            current = current
+                .get_value_and_children_kary_iter_mut()
+                .1
+                .into_iter()
+                .nth(*path_segment)??;
+        }
+        Some(current)
+    }
+
+    /// Identical to [`bfs`](OwnedKaryTreeNode::bfs) except that it mutably
+    /// borrows the tree instead of consuming it.
+    #[must_use]
+    fn bfs_iter_mut(&'a mut self) -> MutBorrowedKaryBFSIterator<'a, Self, N> {
+        MutBorrowedKaryBFSIterator::new(self)
+    }
+
+    /// Identical to [`dfs_preorder`](OwnedKaryTreeNode::dfs_preorder) except
+    /// that it mutably borrows the tree instead of consuming it.
+    #[must_use]
+    fn dfs_preorder_iter_mut(&'a mut self) -> MutBorrowedKaryDFSPreorderIterator<'a, Self, N> {
+        MutBorrowedKaryDFSPreorderIterator::new(self)
+    }
+
+    /// Identical to [`dfs_postorder`](OwnedKaryTreeNode::dfs_postorder) except
+    /// that it mutably borrows the tree instead of consuming it.
+    #[must_use]
+    fn dfs_postorder_iter_mut(&'a mut self) -> MutBorrowedKaryDFSPostorderIterator<'a, Self, N> {
+        MutBorrowedKaryDFSPostorderIterator::new(self)
+    }
+
+    /// Identical to [`into_pipeline`](OwnedKaryTreeNode::into_pipeline) except
+    /// that it mutably borrows the tree instead of consuming it.
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    fn into_pipeline_mut(
+        &'a mut self,
+    ) -> impl KaryTreeIterator<Self::MutBorrowedValue, [Option<&'a mut Self>; N], N> {
+        MutBorrowedKaryDFSPreorderIteratorWithPathTracking::new(self)
+    }
+
+    /// Identical to [`prune`](OwnedKaryTreeNode::prune) except that it mutably
+    /// borrows the tree instead of consuming it.
+    fn prune_mut<F>(&'a mut self, f: F) -> Option<KaryTree<Self::MutBorrowedValue, N>>
+    where
+        F: FnMut(&Self::MutBorrowedValue) -> bool,
+    {
+        self.into_pipeline_mut().prune(f).collect_tree()
+    }
+
+    /// Identical to [`prune_depth`](OwnedKaryTreeNode::prune_depth) except that
+    /// it mutably borrows the tree instead of consuming it.
+    fn prune_depth_mut(&'a mut self, max_depth: usize) -> KaryTree<Self::MutBorrowedValue, N> {
+        self.into_pipeline_mut()
+            .prune_depth(max_depth)
+            .collect_tree()
+            .expect("this should never prune the root of the tree")
+    }
+
+    /// Identical to [`prune_path`](OwnedKaryTreeNode::prune_path) except that
+    /// it mutably borrows the tree instead of consuming it.
+    fn prune_path_mut<F>(&'a mut self, f: F) -> Option<KaryTree<Self::MutBorrowedValue, N>>
+    where
+        F: FnMut(&[usize], &Self::MutBorrowedValue) -> bool,
+    {
+        self.into_pipeline_mut().prune_path(f).collect_tree()
+    }
+
+    /// Identical to [`map`](OwnedKaryTreeNode::map) except that it mutably
+    /// borrows the tree instead of consuming it.
+    fn map_mut<Output, F>(&'a mut self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(Self::MutBorrowedValue) -> Output,
+    {
+        self.into_pipeline_mut().map_tree(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`map_path`](OwnedKaryTreeNode::map_path) except that it
+    /// mutably borrows the tree instead of consuming it.
+    fn map_path_mut<Output, F>(&'a mut self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(&[usize], Self::MutBorrowedValue) -> Output,
+    {
+        self.into_pipeline_mut().map_path(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`fold`](OwnedKaryTreeNode::fold) except that it mutably
+    /// borrows the tree instead of consuming it.
+    fn fold_mut<Output, F>(&'a mut self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], Self::MutBorrowedValue) -> Output,
+    {
+        self.into_pipeline_mut()
+            .fold_tree(f)
+            .expect("there to always be at least the root to fold")
+    }
+
+    /// Identical to [`fold_path`](OwnedKaryTreeNode::fold_path) except that it
+    /// mutably borrows the tree instead of consuming it.
+    fn fold_path_mut<Output, F>(&'a mut self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], &[usize], Self::MutBorrowedValue) -> Output,
+    {
+        self.into_pipeline_mut()
+            .fold_path(f)
+            .expect("there to always be at least the root to fold")
+    }
+}
+
+/// A tree node with N child slots where getting its children borrows its value.
+pub trait BorrowedKaryTreeNode<'a, const N: usize>
+where
+    Self: Sized + 'a,
+{
+    /// A reference to the value of each node in the tree.
+    type BorrowedValue;
+
+    /// This method gets the value and child slots from this node. The other
+    /// methods of this trait assume that the children do not contain any
+    /// circular references. If they do, it will create an infinite loop.
+    fn get_value_and_children_kary_iter(&'a self) -> (Self::BorrowedValue, [Option<&'a Self>; N]);
+
+    /// This method gets the value and the populated children from this node.
+    /// Empty slots are skipped.
+    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, KaryChildren<&'a Self, N>) {
+        let (value, children) = self.get_value_and_children_kary_iter();
+        (
+            value,
+            children
+                .into_iter()
+                .flat_map(opt_to_opt as fn(Option<&'a Self>) -> Option<&'a Self>),
+        )
+    }
+
+    #[doc = include_str!("../doc_files/at_path.md")]
+    ///
+    /// Each path segment is a slot index, so a path through an empty slot
+    /// returns [`None`].
+    fn at_path_ref(&'a self, path: &[usize]) -> Option<&'a Self> {
+        let mut current = self;
+        for path_segment in path {
+            current = current
+                .get_value_and_children_kary_iter()
+                .1
+                .into_iter()
+                .nth(*path_segment)??;
+        }
+        Some(current)
+    }
+
+    /// Identical to [`bfs`](OwnedKaryTreeNode::bfs) except that it borrows the
+    /// tree instead of consuming it.
+    #[must_use]
+    fn bfs_iter(&'a self) -> BorrowedKaryBFSIterator<'a, Self, N> {
+        BorrowedKaryBFSIterator::new(self)
+    }
+
+    /// Identical to [`dfs_preorder`](OwnedKaryTreeNode::dfs_preorder) except
+    /// that it borrows the tree instead of consuming it.
+    #[must_use]
+    fn dfs_preorder_iter(&'a self) -> BorrowedKaryDFSPreorderIterator<'a, Self, N> {
+        BorrowedKaryDFSPreorderIterator::new(self)
+    }
+
+    /// Identical to [`dfs_postorder`](OwnedKaryTreeNode::dfs_postorder) except
+    /// that it borrows the tree instead of consuming it.
+    #[must_use]
+    fn dfs_postorder_iter(&'a self) -> BorrowedKaryDFSPostorderIterator<'a, Self, N> {
+        BorrowedKaryDFSPostorderIterator::new(self)
+    }
+
+    /// Identical to [`into_pipeline`](OwnedKaryTreeNode::into_pipeline) except
+    /// that it borrows the tree instead of consuming it.
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    fn into_pipeline_ref(
+        &'a self,
+    ) -> impl KaryTreeIterator<Self::BorrowedValue, [Option<&'a Self>; N], N> {
+        BorrowedKaryDFSPreorderIteratorWithPathTracking::new(self)
+    }
+
+    /// Identical to [`prune`](OwnedKaryTreeNode::prune) except that it borrows
+    /// the tree instead of consuming it.
+    fn prune_ref<F>(&'a self, f: F) -> Option<KaryTree<Self::BorrowedValue, N>>
+    where
+        F: FnMut(&Self::BorrowedValue) -> bool,
+    {
+        self.into_pipeline_ref().prune(f).collect_tree()
+    }
+
+    /// Identical to [`prune_depth`](OwnedKaryTreeNode::prune_depth) except that
+    /// it borrows the tree instead of consuming it.
+    fn prune_depth_ref(&'a self, max_depth: usize) -> KaryTree<Self::BorrowedValue, N> {
+        self.into_pipeline_ref()
+            .prune_depth(max_depth)
+            .collect_tree()
+            .expect("this should never prune the root of the tree")
+    }
+
+    /// Identical to [`prune_path`](OwnedKaryTreeNode::prune_path) except that
+    /// it borrows the tree instead of consuming it.
+    fn prune_path_ref<F>(&'a self, f: F) -> Option<KaryTree<Self::BorrowedValue, N>>
+    where
+        F: FnMut(&[usize], &Self::BorrowedValue) -> bool,
+    {
+        self.into_pipeline_ref().prune_path(f).collect_tree()
+    }
+
+    /// Identical to [`map`](OwnedKaryTreeNode::map) except that it borrows the
+    /// tree instead of consuming it.
+    fn map_ref<Output, F>(&'a self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().map_tree(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`map_path`](OwnedKaryTreeNode::map_path) except that it
+    /// borrows the tree instead of consuming it.
+    fn map_path_ref<Output, F>(&'a self, f: F) -> KaryTree<Output, N>
+    where
+        F: FnMut(&[usize], Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().map_path(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`fold`](OwnedKaryTreeNode::fold) except that it borrows
+    /// the tree instead of consuming it.
+    fn fold_ref<Output, F>(&'a self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref()
+            .fold_tree(f)
+            .expect("there to always be at least the root to fold")
+    }
+
+    /// Identical to [`fold_path`](OwnedKaryTreeNode::fold_path) except that it
+    /// borrows the tree instead of consuming it.
+    fn fold_path_ref<Output, F>(&'a self, f: F) -> Output
+    where
+        F: FnMut([Option<Output>; N], &[usize], Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref()
+            .fold_path(f)
+            .expect("there to always be at least the root to fold")
+    }
+}
+
+impl<T, const N: usize> OwnedKaryTreeNode<N> for KaryTree<T, N> {
+    type OwnedValue = T;
+
+    fn get_value_and_children_kary(self) -> (Self::OwnedValue, [Option<Self>; N]) {
+        (
+            self.value,
+            self.children.map(|child| child.map(|child| *child)),
+        )
+    }
+}
+
+impl<'a, T, const N: usize> MutBorrowedKaryTreeNode<'a, N> for KaryTree<T, N>
+where
+    T: 'a,
+{
+    type MutBorrowedValue = &'a mut T;
+
+    fn get_value_and_children_kary_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, [Option<&'a mut Self>; N]) {
+        (
+            &mut self.value,
+            self.children.each_mut().map(|child| child.as_deref_mut()),
+        )
+    }
+}
+
+impl<'a, T, const N: usize> BorrowedKaryTreeNode<'a, N> for KaryTree<T, N>
+where
+    T: 'a,
+{
+    type BorrowedValue = &'a T;
+
+    fn get_value_and_children_kary_iter(&'a self) -> (Self::BorrowedValue, [Option<&'a Self>; N]) {
+        (
+            &self.value,
+            self.children.each_ref().map(|child| child.as_deref()),
+        )
+    }
+}
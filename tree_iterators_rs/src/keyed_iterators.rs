@@ -0,0 +1,330 @@
+use alloc::vec::Vec;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use crate::prelude::{
+    BorrowedKeyedTreeNode, KeyedTreeContext, MutBorrowedKeyedTreeNode, OwnedKeyedTreeNode,
+};
+
+macro_rules! keyed_dfs_preorder_next {
+    ($get_value_and_keyed_children: ident) => {
+        fn next(&mut self) -> Option<Self::Item> {
+            if let Some(root) = self.root.take() {
+                let (value, children) = root.$get_value_and_keyed_children();
+                self.traversal_stack.push(children.into_iter());
+                return Some(value);
+            }
+
+            let (_, next) = loop {
+                let top = self.traversal_stack.last_mut()?;
+                if let Some(next) = top.next() {
+                    break next;
+                }
+
+                self.traversal_stack.pop();
+            };
+
+            let (value, children) = next.$get_value_and_keyed_children();
+            self.traversal_stack.push(children.into_iter());
+            Some(value)
+        }
+    };
+}
+
+macro_rules! keyed_preorder_context_streaming_iterator_impl {
+    ($get_value_and_keyed_children: ident) => {
+        fn advance(&mut self) {
+            if let Some(root) = self.root.take() {
+                let (value, children) = root.$get_value_and_keyed_children();
+                self.current_context.ancestors.push(value);
+                self.current_context.children = Some(children);
+                return;
+            }
+
+            if let Some(children) = self.current_context.children.take() {
+                self.traversal_stack.push(children.into_iter());
+            }
+
+            let next = loop {
+                if let Some(top) = self.traversal_stack.last_mut() {
+                    if let Some(next) = top.next() {
+                        break Some(next);
+                    }
+
+                    self.traversal_stack.pop();
+                    self.current_context.ancestors.pop();
+                    self.current_context.key_path.pop();
+                } else {
+                    break None;
+                }
+            };
+
+            if let Some((key, next)) = next {
+                let (value, children) = next.$get_value_and_keyed_children();
+                self.current_context.key_path.push(key);
+                self.current_context.ancestors.push(value);
+                self.current_context.children = Some(children);
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&self.current_context)
+            }
+        }
+    };
+}
+
+macro_rules! get_mut_keyed_context {
+    () => {
+        fn get_mut(&mut self) -> Option<&mut Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&mut self.current_context)
+            }
+        }
+    };
+}
+
+const ATTACH_CONTEXT_PANIC: &str = "Attempted to attach metadata to a keyed DFS preorder iterator in the middle of a tree traversal. This is forbidden.";
+
+pub struct OwnedKeyedDFSPreorderIterator<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<<Node::OwnedKeyedChildren as IntoIterator>::IntoIter>,
+}
+
+impl<Node> OwnedKeyedDFSPreorderIterator<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    pub(crate) fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    /// This method will panic if called after an element has already been
+    /// yielded from the iterator it is called on! This method attaches the
+    /// context of where a node is in the tree to the node during iteration,
+    /// converting the current iterator into a streaming iterator. See
+    /// [`KeyedTreeContext`] for the information it provides.
+    pub fn attach_context(self) -> OwnedKeyedDFSPreorderIteratorWithContext<Node> {
+        match self.root {
+            None => panic!("{}", ATTACH_CONTEXT_PANIC),
+            Some(root) => OwnedKeyedDFSPreorderIteratorWithContext::new(root),
+        }
+    }
+}
+
+impl<Node> Iterator for OwnedKeyedDFSPreorderIterator<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    type Item = Node::OwnedValue;
+    keyed_dfs_preorder_next!(get_value_and_keyed_children);
+}
+
+pub struct OwnedKeyedDFSPreorderIteratorWithContext<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<<Node::OwnedKeyedChildren as IntoIterator>::IntoIter>,
+    current_context: KeyedTreeContext<Node::Key, Node::OwnedValue, Node::OwnedKeyedChildren>,
+}
+
+impl<Node> OwnedKeyedDFSPreorderIteratorWithContext<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    fn new(root: Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: KeyedTreeContext {
+                key_path: Vec::new(),
+                ancestors: Vec::new(),
+                children: None,
+            },
+        }
+    }
+}
+
+impl<Node> StreamingIterator for OwnedKeyedDFSPreorderIteratorWithContext<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    type Item = KeyedTreeContext<Node::Key, Node::OwnedValue, Node::OwnedKeyedChildren>;
+    keyed_preorder_context_streaming_iterator_impl!(get_value_and_keyed_children);
+}
+
+impl<Node> StreamingIteratorMut for OwnedKeyedDFSPreorderIteratorWithContext<Node>
+where
+    Node: OwnedKeyedTreeNode,
+{
+    get_mut_keyed_context!();
+}
+
+pub struct MutBorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<<Node::MutBorrowedKeyedChildren as IntoIterator>::IntoIter>,
+}
+
+impl<'a, Node> MutBorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    /// This method will panic if called after an element has already been
+    /// yielded from the iterator it is called on! This method attaches the
+    /// context of where a node is in the tree to the node during iteration,
+    /// converting the current iterator into a streaming iterator. See
+    /// [`KeyedTreeContext`] for the information it provides.
+    pub fn attach_context(self) -> MutBorrowedKeyedDFSPreorderIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("{}", ATTACH_CONTEXT_PANIC),
+            Some(root) => MutBorrowedKeyedDFSPreorderIteratorWithContext::new(root),
+        }
+    }
+}
+
+impl<'a, Node> Iterator for MutBorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    type Item = Node::MutBorrowedValue;
+    keyed_dfs_preorder_next!(get_value_and_keyed_children_iter_mut);
+}
+
+pub struct MutBorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<<Node::MutBorrowedKeyedChildren as IntoIterator>::IntoIter>,
+    current_context:
+        KeyedTreeContext<&'a Node::Key, Node::MutBorrowedValue, Node::MutBorrowedKeyedChildren>,
+}
+
+impl<'a, Node> MutBorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    fn new(root: &'a mut Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: KeyedTreeContext {
+                key_path: Vec::new(),
+                ancestors: Vec::new(),
+                children: None,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    type Item =
+        KeyedTreeContext<&'a Node::Key, Node::MutBorrowedValue, Node::MutBorrowedKeyedChildren>;
+    keyed_preorder_context_streaming_iterator_impl!(get_value_and_keyed_children_iter_mut);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedKeyedTreeNode<'a>,
+{
+    get_mut_keyed_context!();
+}
+
+pub struct BorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<<Node::BorrowedKeyedChildren as IntoIterator>::IntoIter>,
+}
+
+impl<'a, Node> BorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+        }
+    }
+
+    /// This method will panic if called after an element has already been
+    /// yielded from the iterator it is called on! This method attaches the
+    /// context of where a node is in the tree to the node during iteration,
+    /// converting the current iterator into a streaming iterator. See
+    /// [`KeyedTreeContext`] for the information it provides.
+    pub fn attach_context(self) -> BorrowedKeyedDFSPreorderIteratorWithContext<'a, Node> {
+        match self.root {
+            None => panic!("{}", ATTACH_CONTEXT_PANIC),
+            Some(root) => BorrowedKeyedDFSPreorderIteratorWithContext::new(root),
+        }
+    }
+}
+
+impl<'a, Node> Iterator for BorrowedKeyedDFSPreorderIterator<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    type Item = Node::BorrowedValue;
+    keyed_dfs_preorder_next!(get_value_and_keyed_children_iter);
+}
+
+pub struct BorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<<Node::BorrowedKeyedChildren as IntoIterator>::IntoIter>,
+    current_context:
+        KeyedTreeContext<&'a Node::Key, Node::BorrowedValue, Node::BorrowedKeyedChildren>,
+}
+
+impl<'a, Node> BorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    fn new(root: &'a Node) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: KeyedTreeContext {
+                key_path: Vec::new(),
+                ancestors: Vec::new(),
+                children: None,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for BorrowedKeyedDFSPreorderIteratorWithContext<'a, Node>
+where
+    Node: BorrowedKeyedTreeNode<'a>,
+{
+    type Item = KeyedTreeContext<&'a Node::Key, Node::BorrowedValue, Node::BorrowedKeyedChildren>;
+    keyed_preorder_context_streaming_iterator_impl!(get_value_and_keyed_children_iter);
+}
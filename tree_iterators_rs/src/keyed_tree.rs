@@ -0,0 +1,423 @@
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    vec::Vec,
+};
+use core::borrow::Borrow;
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    keyed_iterators::{
+        BorrowedKeyedDFSPreorderIterator, MutBorrowedKeyedDFSPreorderIterator,
+        OwnedKeyedDFSPreorderIterator,
+    },
+    prelude::{BorrowedTreeNode, MutBorrowedTreeNode, OwnedTreeNode, Tree},
+};
+
+/// A tree node whose children are stored in an ordered map, so that each
+/// child can be addressed by its key instead of its position. This makes
+/// it a good fit for file-system and JSON-like data, where inserting or
+/// reordering siblings would invalidate positional paths.
+///
+/// [`KeyedTree`] implements both the positional *TreeNode traits (children
+/// are visited in key order) and the keyed *KeyedTreeNode traits.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: serde::Serialize, V: serde::Serialize",
+        deserialize = "K: Ord + serde::Deserialize<'de>, V: serde::Deserialize<'de>"
+    ))
+)]
+pub struct KeyedTree<K, V> {
+    /// This node's value
+    pub value: V,
+    /// The children of the current node, ordered by key.
+    pub children: BTreeMap<K, KeyedTree<K, V>>,
+}
+
+impl<K, V> KeyedTree<K, V>
+where
+    K: Ord,
+{
+    /// Converts a [`Tree`] whose values are (key, value) pairs into a [`KeyedTree`].
+    /// The root's key is returned alongside the [`KeyedTree`] since the root
+    /// node has no parent to be keyed under. If any siblings share a key, the
+    /// last of them wins.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{KeyedTree, Tree};
+    ///
+    /// let tree = Tree {
+    ///     value: ("/", 0),
+    ///     children: vec![
+    ///         Tree {
+    ///             value: ("usr", 1),
+    ///             children: vec![],
+    ///         },
+    ///         Tree {
+    ///             value: ("etc", 2),
+    ///             children: vec![],
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// let (root_key, keyed) = KeyedTree::from_tree(tree.clone());
+    /// assert_eq!("/", root_key);
+    /// assert_eq!(Some(&2), keyed.children.get("etc").map(|child| &child.value));
+    ///
+    /// // children come back out in key order.
+    /// let round_trip = keyed.into_tree(root_key);
+    /// assert_eq!(("etc", 2), round_trip.children[0].value);
+    /// assert_eq!(("usr", 1), round_trip.children[1].value);
+    /// ```
+    pub fn from_tree(tree: Tree<(K, V)>) -> (K, Self) {
+        tree.fold(|children: Vec<(K, Self)>, (key, value)| {
+            (
+                key,
+                Self {
+                    value,
+                    children: children.into_iter().collect(),
+                },
+            )
+        })
+    }
+}
+
+impl<K, V> KeyedTree<K, V> {
+    /// Converts this [`KeyedTree`] into a [`Tree`] whose values are (key, value)
+    /// pairs, using `key` as the root's key. Children are placed in key order.
+    /// See [`from_tree`](KeyedTree::from_tree) for an example.
+    pub fn into_tree(self, key: K) -> Tree<(K, V)> {
+        let mut stack = Vec::new();
+        stack.push((key, self.value, Vec::new(), self.children.into_iter()));
+
+        loop {
+            let top = stack.last_mut().expect("the stack to never be empty");
+            if let Some((key, child)) = top.3.next() {
+                stack.push((key, child.value, Vec::new(), child.children.into_iter()));
+                continue;
+            }
+
+            let (key, value, children, _) = stack.pop().expect("the stack to never be empty");
+            let tree = Tree {
+                value: (key, value),
+                children,
+            };
+
+            match stack.last_mut() {
+                Some(parent) => parent.2.push(tree),
+                None => return tree,
+            }
+        }
+    }
+}
+
+/// A tree node with keyed children where getting its children consumes its value.
+pub trait OwnedKeyedTreeNode: OwnedTreeNode {
+    /// The key each child is stored under.
+    type Key;
+
+    /// The type of iterator that can be used to iterate over each node's
+    /// (key, child) pairs.
+    type OwnedKeyedChildren: IntoIterator<Item = (Self::Key, Self)>;
+
+    /// This method gets the value and keyed children from this node, consuming it
+    /// in the process. The other methods of this trait assume that the 'Children'
+    /// list does not contain any circular references. If it does, it will create
+    /// an infinite loop.
+    fn get_value_and_keyed_children(self) -> (Self::OwnedValue, Self::OwnedKeyedChildren);
+
+    /// Gets the child stored under the given key, consuming this node in the
+    /// process. By default this searches through every child, so implementations
+    /// that store their children in a map should look the key up directly.
+    fn get_keyed_child<Q>(self, key: &Q) -> Option<Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.get_value_and_keyed_children()
+            .1
+            .into_iter()
+            .find(|(child_key, _)| child_key.borrow() == key)
+            .map(|(_, child)| child)
+    }
+
+    /// Given a key path (list of keys - see
+    /// [`KeyedTreeContext::key_path`](crate::prelude::KeyedTreeContext::key_path)
+    /// for more information) down the nodes of the tree, at_key_path will walk
+    /// the list of keys and fetch the node at the given path.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use tree_iterators_rs::prelude::*;
+    ///
+    /// let mut src = KeyedTree {
+    ///     value: "src",
+    ///     children: BTreeMap::new(),
+    /// };
+    /// src.children.insert(
+    ///     "lib.rs".to_string(),
+    ///     KeyedTree {
+    ///         value: "lib.rs",
+    ///         children: BTreeMap::new(),
+    ///     },
+    /// );
+    ///
+    /// let mut root = KeyedTree {
+    ///     value: "crate",
+    ///     children: BTreeMap::new(),
+    /// };
+    /// root.children.insert("src".to_string(), src);
+    ///
+    /// assert_eq!(
+    ///     Some("lib.rs"),
+    ///     root.clone().at_key_path(&["src", "lib.rs"]).map(|node| node.value)
+    /// );
+    /// assert!(root.at_key_path(&["src", "main.rs"]).is_none());
+    /// ```
+    fn at_key_path<Q>(self, path: &[&Q]) -> Option<Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self;
+        for path_segment in path {
+            current = current.get_keyed_child(*path_segment)?;
+        }
+        Some(current)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Depth First Preorder searches of a tree. It yields the same values in
+    /// the same order as [`dfs_preorder`](OwnedTreeNode::dfs_preorder), but its
+    /// [`attach_context`](OwnedKeyedDFSPreorderIterator::attach_context) API
+    /// tracks the key path to each node instead of its positional path.
+    fn dfs_preorder_keyed(self) -> OwnedKeyedDFSPreorderIterator<Self> {
+        OwnedKeyedDFSPreorderIterator::new(self)
+    }
+}
+
+/// A tree node with keyed children where getting its children mutably borrows its value.
+pub trait MutBorrowedKeyedTreeNode<'a>: MutBorrowedTreeNode<'a> {
+    /// The key each child is stored under.
+    type Key: 'a;
+
+    /// The type of iterator that can be used to iterate over each node's
+    /// (key, child) pairs.
+    type MutBorrowedKeyedChildren: IntoIterator<Item = (&'a Self::Key, &'a mut Self)>;
+
+    /// This method gets the value and keyed children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_keyed_children_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, Self::MutBorrowedKeyedChildren);
+
+    /// Identical to [`get_keyed_child`](OwnedKeyedTreeNode::get_keyed_child)
+    /// except that it mutably borrows this node.
+    fn get_keyed_child_mut<Q>(&'a mut self, key: &Q) -> Option<&'a mut Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.get_value_and_keyed_children_iter_mut()
+            .1
+            .into_iter()
+            .find(|(child_key, _)| (*child_key).borrow() == key)
+            .map(|(_, child)| child)
+    }
+
+    /// Identical to [`at_key_path`](OwnedKeyedTreeNode::at_key_path) except
+    /// that it mutably borrows the tree.
+    fn at_key_path_mut<Q>(&'a mut self, path: &[&Q]) -> Option<&'a mut Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self;
+        for path_segment in path {
+            current = current.get_keyed_child_mut(*path_segment)?;
+        }
+        Some(current)
+    }
+
+    /// Identical to [`dfs_preorder_keyed`](OwnedKeyedTreeNode::dfs_preorder_keyed)
+    /// except that it mutably borrows the tree.
+    fn dfs_preorder_keyed_iter_mut(&'a mut self) -> MutBorrowedKeyedDFSPreorderIterator<'a, Self> {
+        MutBorrowedKeyedDFSPreorderIterator::new(self)
+    }
+}
+
+/// A tree node with keyed children where getting its children borrows its value.
+pub trait BorrowedKeyedTreeNode<'a>: BorrowedTreeNode<'a> {
+    /// The key each child is stored under.
+    type Key: 'a;
+
+    /// The type of iterator that can be used to iterate over each node's
+    /// (key, child) pairs.
+    type BorrowedKeyedChildren: IntoIterator<Item = (&'a Self::Key, &'a Self)>;
+
+    /// This method gets the value and keyed children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_keyed_children_iter(
+        &'a self,
+    ) -> (Self::BorrowedValue, Self::BorrowedKeyedChildren);
+
+    /// Identical to [`get_keyed_child`](OwnedKeyedTreeNode::get_keyed_child)
+    /// except that it borrows this node.
+    fn get_keyed_child_ref<Q>(&'a self, key: &Q) -> Option<&'a Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.get_value_and_keyed_children_iter()
+            .1
+            .into_iter()
+            .find(|(child_key, _)| (*child_key).borrow() == key)
+            .map(|(_, child)| child)
+    }
+
+    /// Identical to [`at_key_path`](OwnedKeyedTreeNode::at_key_path) except
+    /// that it borrows the tree.
+    fn at_key_path_ref<Q>(&'a self, path: &[&Q]) -> Option<&'a Self>
+    where
+        Self::Key: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut current = self;
+        for path_segment in path {
+            current = current.get_keyed_child_ref(*path_segment)?;
+        }
+        Some(current)
+    }
+
+    /// Identical to [`dfs_preorder_keyed`](OwnedKeyedTreeNode::dfs_preorder_keyed)
+    /// except that it borrows the tree.
+    fn dfs_preorder_keyed_iter(&'a self) -> BorrowedKeyedDFSPreorderIterator<'a, Self> {
+        BorrowedKeyedDFSPreorderIterator::new(self)
+    }
+}
+
+impl<K, V> OwnedTreeNode for KeyedTree<K, V> {
+    type OwnedValue = V;
+    type OwnedChildren = btree_map::IntoValues<K, Self>;
+
+    /// This method gets the value and children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_children(self) -> (Self::OwnedValue, Self::OwnedChildren) {
+        (self.value, self.children.into_values())
+    }
+}
+
+impl<'a, K, V> MutBorrowedTreeNode<'a> for KeyedTree<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type MutBorrowedValue = &'a mut V;
+    type MutBorrowedChildren = btree_map::ValuesMut<'a, K, Self>;
+
+    /// This method gets the value and children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_children_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, Self::MutBorrowedChildren) {
+        (&mut self.value, self.children.values_mut())
+    }
+}
+
+impl<'a, K, V> BorrowedTreeNode<'a> for KeyedTree<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type BorrowedValue = &'a V;
+    type BorrowedChildren = btree_map::Values<'a, K, Self>;
+
+    /// This method gets the value and children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, Self::BorrowedChildren) {
+        (&self.value, self.children.values())
+    }
+}
+
+impl<K, V> OwnedKeyedTreeNode for KeyedTree<K, V> {
+    type Key = K;
+    type OwnedKeyedChildren = BTreeMap<K, Self>;
+
+    /// This method gets the value and keyed children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_keyed_children(self) -> (Self::OwnedValue, Self::OwnedKeyedChildren) {
+        (self.value, self.children)
+    }
+
+    fn get_keyed_child<Q>(mut self, key: &Q) -> Option<Self>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.children.remove(key)
+    }
+}
+
+impl<'a, K, V> MutBorrowedKeyedTreeNode<'a> for KeyedTree<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type Key = K;
+    type MutBorrowedKeyedChildren = &'a mut BTreeMap<K, Self>;
+
+    /// This method gets the value and keyed children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_keyed_children_iter_mut(
+        &'a mut self,
+    ) -> (Self::MutBorrowedValue, Self::MutBorrowedKeyedChildren) {
+        (&mut self.value, &mut self.children)
+    }
+
+    fn get_keyed_child_mut<Q>(&'a mut self, key: &Q) -> Option<&'a mut Self>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.children.get_mut(key)
+    }
+}
+
+impl<'a, K, V> BorrowedKeyedTreeNode<'a> for KeyedTree<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type Key = K;
+    type BorrowedKeyedChildren = &'a BTreeMap<K, Self>;
+
+    /// This method gets the value and keyed children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_keyed_children_iter(
+        &'a self,
+    ) -> (Self::BorrowedValue, Self::BorrowedKeyedChildren) {
+        (&self.value, &self.children)
+    }
+
+    fn get_keyed_child_ref<Q>(&'a self, key: &Q) -> Option<&'a Self>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.children.get(key)
+    }
+}
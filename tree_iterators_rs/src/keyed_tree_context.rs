@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+
+/// The context of a node in a keyed tree. This is identical to
+/// [`TreeContext`](crate::prelude::TreeContext) except that the node's
+/// path is made up of the keys of its ancestors instead of their
+/// positions among their siblings.
+#[derive(Clone, Debug)]
+pub struct KeyedTreeContext<Key, Value, Children> {
+    /// The keys of each node between the root and the current node.
+    pub(crate) key_path: Vec<Key>,
+
+    #[doc = include_str!("../doc_files/ancestors_vec.md")]
+    pub(crate) ancestors: Vec<Value>,
+
+    #[doc = include_str!("../doc_files/tree_context_children.md")]
+    pub(crate) children: Option<Children>,
+}
+
+impl<Key, Value, Children> KeyedTreeContext<Key, Value, Children> {
+    /// Gets the depth of the current node in the tree. This is zero-based,
+    /// so the root node is at depth zero.
+    pub fn depth(&self) -> usize {
+        self.ancestors().len() - 1
+    }
+
+    /// Gets the key path to the current node. Each entry is the key the
+    /// corresponding node is stored under in its parent's children, so
+    /// the root node's key path is empty and the key path always has one
+    /// fewer entry than [`ancestors`](KeyedTreeContext::ancestors).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use streaming_iterator::StreamingIterator;
+    /// use tree_iterators_rs::prelude::*;
+    ///
+    /// let mut root = KeyedTree {
+    ///     value: 0,
+    ///     children: BTreeMap::new(),
+    /// };
+    /// root.children.insert(
+    ///     "a",
+    ///     KeyedTree {
+    ///         value: 1,
+    ///         children: BTreeMap::new(),
+    ///     },
+    /// );
+    ///
+    /// let mut key_paths = Vec::new();
+    /// let mut iter = root.dfs_preorder_keyed_iter().attach_context();
+    /// while let Some(context) = iter.next() {
+    ///     key_paths.push(context.key_path().to_vec());
+    /// }
+    ///
+    /// assert_eq!(vec![vec![], vec![&"a"]], key_paths);
+    /// ```
+    pub fn key_path(&self) -> &[Key] {
+        &self.key_path
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_vec.md")]
+    pub fn ancestors(&self) -> &[Value] {
+        &self.ancestors
+    }
+
+    #[doc = include_str!("../doc_files/ancestors_vec.md")]
+    pub fn ancestors_mut(&mut self) -> &mut [Value] {
+        &mut self.ancestors
+    }
+
+    #[doc = include_str!("../doc_files/tree_context_children.md")]
+    pub fn children(&self) -> &Children {
+        // children should always be populated unless the iterator is in the middle of its .next() method.
+        self.children.as_ref().unwrap()
+    }
+
+    #[doc = include_str!("../doc_files/tree_context_children.md")]
+    pub fn children_mut(&mut self) -> &mut Children {
+        // children should always be populated unless the iterator is in the middle of its .next() method.
+        self.children.as_mut().unwrap()
+    }
+}
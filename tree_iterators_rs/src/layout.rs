@@ -0,0 +1,517 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use streaming_iterator::StreamingIterator;
+
+use crate::prelude::{BinaryTree, BorrowedBinaryTreeNode, BorrowedTreeNode, Tree};
+
+/// The position of a node in a tree laid out by
+/// [`layout_ref`](crate::prelude::BorrowedTreeNode::layout_ref). `x` is the
+/// horizontal center of the node and `y` is its top edge, with `y` growing
+/// downwards as is usual for screen coordinates. The leftmost edge of any node in
+/// the tree is at `x = 0` and the root is at `y = 0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// The sizes and spacing used by
+/// [`layout_ref`](crate::prelude::BorrowedTreeNode::layout_ref) to position each
+/// node, and by [`to_svg`](Tree::to_svg) to draw it.
+///
+/// By default, every node is 40 wide and 20 tall, siblings are 20 apart and levels
+/// are 40 apart.
+///
+/// ### Example Usage
+/// ```rust
+/// use tree_iterators_rs::prelude::{BorrowedTreeNode, LayoutOptions, Position, Tree};
+///
+/// let leaf = |value| Tree { value, children: Vec::new() };
+/// let tree = Tree { value: "root", children: vec![leaf("a"), leaf("bcd")] };
+///
+/// // size each node to fit its label
+/// let options = LayoutOptions::new()
+///     .node_width(|value: &&&str| 10.0 * value.len() as f64)
+///     .sibling_separation(5.0);
+/// let laid_out = tree.layout_ref(&options);
+///
+/// assert_eq!(Position { x: 7.5, y: 60.0 }, laid_out.children[0].value.1);
+/// assert_eq!(Position { x: 32.5, y: 60.0 }, laid_out.children[1].value.1);
+/// assert_eq!(Position { x: 20.0, y: 0.0 }, laid_out.value.1);
+/// ```
+pub struct LayoutOptions<'f, Value> {
+    node_width: Box<dyn Fn(&Value) -> f64 + 'f>,
+    node_height: f64,
+    sibling_separation: f64,
+    level_separation: f64,
+}
+
+impl<Value> Default for LayoutOptions<'_, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'f, Value> LayoutOptions<'f, Value> {
+    /// Creates the default options, where every node is 40 wide and 20 tall,
+    /// siblings are 20 apart and levels are 40 apart.
+    pub fn new() -> Self {
+        Self {
+            node_width: Box::new(|_| 40.0),
+            node_height: 20.0,
+            sibling_separation: 20.0,
+            level_separation: 40.0,
+        }
+    }
+
+    /// Sets the width of each node based on its value.
+    #[must_use]
+    pub fn node_width<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Value) -> f64 + 'f,
+    {
+        self.node_width = Box::new(f);
+        self
+    }
+
+    /// Sets the height of every node.
+    #[must_use]
+    pub fn node_height(mut self, height: f64) -> Self {
+        self.node_height = height;
+        self
+    }
+
+    /// Sets the minimum horizontal gap between any two nodes at the same depth.
+    #[must_use]
+    pub fn sibling_separation(mut self, separation: f64) -> Self {
+        self.sibling_separation = separation;
+        self
+    }
+
+    /// Sets the vertical gap between the bottom of one level and the top of the next.
+    #[must_use]
+    pub fn level_separation(mut self, separation: f64) -> Self {
+        self.level_separation = separation;
+        self
+    }
+}
+
+/// A laid out tree whose node positions can be filled in from each node's offset
+/// from its parent.
+pub(crate) trait Positioned {
+    /// The position of the root of this tree.
+    fn position_mut(&mut self) -> &mut Position;
+
+    /// Replaces the horizontal offset of each node from its parent with its final
+    /// position, moving the root to `x` and placing each depth `level` below the
+    /// previous one.
+    fn resolve(&mut self, x: f64, level: f64);
+}
+
+impl<Value> Positioned for Tree<(Value, Position)> {
+    fn position_mut(&mut self) -> &mut Position {
+        &mut self.value.1
+    }
+
+    fn resolve(&mut self, x: f64, level: f64) {
+        let mut stack = vec![(self, x, 0.0)];
+        while let Some((node, parent_x, y)) = stack.pop() {
+            let position = &mut node.value.1;
+            position.x += parent_x;
+            position.y = y;
+            for child in &mut node.children {
+                stack.push((child, position.x, y + level));
+            }
+        }
+    }
+}
+
+impl<Value> Positioned for BinaryTree<(Value, Position)> {
+    fn position_mut(&mut self) -> &mut Position {
+        &mut self.value.1
+    }
+
+    fn resolve(&mut self, x: f64, level: f64) {
+        let mut stack = vec![(self, x, 0.0)];
+        while let Some((node, parent_x, y)) = stack.pop() {
+            let position = &mut node.value.1;
+            position.x += parent_x;
+            position.y = y;
+            for child in [node.left.as_deref_mut(), node.right.as_deref_mut()]
+                .into_iter()
+                .flatten()
+            {
+                stack.push((child, position.x, y + level));
+            }
+        }
+    }
+}
+
+/// A node as seen by the contours of the subtrees that contain it.
+struct ContourNode {
+    half_width: f64,
+    /// The horizontal offset of this node from its parent.
+    offset: f64,
+    first_child: Option<usize>,
+    last_child: Option<usize>,
+    /// The next node on the left contour of a subtree that this node is the
+    /// deepest leftmost node of, along with its horizontal offset from this node.
+    left_thread: Option<(usize, f64)>,
+    /// The same as `left_thread`, but for the right contour.
+    right_thread: Option<(usize, f64)>,
+}
+
+/// A laid out subtree. Positions are relative to the center of its root, which
+/// sits at `x = 0`.
+pub(crate) struct Subtree<T> {
+    /// `None` for the placeholders that hold the place of a missing binary child.
+    tree: Option<T>,
+    root: usize,
+    /// The depth of the deepest nodes of the subtree below its root.
+    depth: usize,
+    /// The leftmost of the deepest nodes of the subtree and its position.
+    left_bottom: (usize, f64),
+    /// The rightmost of the deepest nodes of the subtree and its position.
+    right_bottom: (usize, f64),
+}
+
+/// Lays out a tree one node at a time, from the bottom up. Used with
+/// [`fold_ref`](crate::prelude::BorrowedTreeNode::fold_ref).
+///
+/// This follows the Reingold–Tilford algorithm. Each node only records its
+/// offset from its parent, and the contours of each subtree are threaded through
+/// its nodes, so placing a subtree next to its siblings only visits the depths they
+/// share and the whole layout takes linear time.
+pub(crate) struct Layout<'o, 'f, Value> {
+    options: &'o LayoutOptions<'f, Value>,
+    nodes: Vec<ContourNode>,
+}
+
+impl<'o, 'f, Value> Layout<'o, 'f, Value> {
+    pub(crate) fn new(options: &'o LayoutOptions<'f, Value>) -> Self {
+        Self {
+            options,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Lays out a node above its already laid out children.
+    pub(crate) fn node(
+        &mut self,
+        mut children: Vec<Subtree<Tree<(Value, Position)>>>,
+        value: Value,
+    ) -> Subtree<Tree<(Value, Position)>> {
+        let mut subtree = self.place(&mut children, (self.options.node_width)(&value));
+        subtree.tree = Some(Tree {
+            value: (value, Position::default()),
+            children: children
+                .into_iter()
+                .filter_map(|child| child.tree)
+                .collect(),
+        });
+        subtree
+    }
+
+    /// Lays out a binary node above its already laid out children. A lone child is
+    /// laid out as if it had a sibling as wide as itself in the other slot, so that
+    /// left children end up to the left of their parent and right children to the
+    /// right.
+    pub(crate) fn binary_node(
+        &mut self,
+        [left, right]: [Option<Subtree<BinaryTree<(Value, Position)>>>; 2],
+        value: Value,
+    ) -> Subtree<BinaryTree<(Value, Position)>> {
+        let mut children = match (left, right) {
+            (Some(left), None) => {
+                let placeholder = self.placeholder(self.root_width(&left));
+                vec![left, placeholder]
+            }
+            (None, Some(right)) => vec![self.placeholder(self.root_width(&right)), right],
+            (left, right) => left.into_iter().chain(right).collect(),
+        };
+
+        let mut subtree = self.place(&mut children, (self.options.node_width)(&value));
+        let mut children = children.into_iter().map(|child| child.tree.map(Box::new));
+        subtree.tree = Some(BinaryTree {
+            value: (value, Position::default()),
+            left: children.next().flatten(),
+            right: children.next().flatten(),
+        });
+        subtree
+    }
+
+    /// Computes the final position of every node, moving the whole tree so that its
+    /// leftmost edge is at `x = 0`.
+    pub(crate) fn finish<T: Positioned>(self, root: Subtree<T>) -> T {
+        let mut left = f64::INFINITY;
+        let mut next = Some((root.root, 0.0));
+        while let Some((node, x)) = next {
+            left = left.min(x - self.nodes[node].half_width);
+            next = self.next_left(node, x);
+        }
+
+        let mut tree = root.tree.unwrap();
+        tree.resolve(
+            -left,
+            self.options.node_height + self.options.level_separation,
+        );
+        tree
+    }
+
+    /// Creates a subtree that takes up as much room as a node of the given width
+    /// without holding anything.
+    fn placeholder<T: Positioned>(&mut self, width: f64) -> Subtree<T> {
+        self.place(&mut [], width)
+    }
+
+    /// The width of the root of the given subtree.
+    fn root_width<T>(&self, subtree: &Subtree<T>) -> f64 {
+        self.nodes[subtree.root].half_width * 2.0
+    }
+
+    /// The next node on the left contour after the given node at `x`, along with
+    /// its position.
+    fn next_left(&self, node: usize, x: f64) -> Option<(usize, f64)> {
+        let node = &self.nodes[node];
+        match node.first_child {
+            Some(child) => Some((child, x + self.nodes[child].offset)),
+            None => node.left_thread.map(|(next, offset)| (next, x + offset)),
+        }
+    }
+
+    /// The next node on the right contour after the given node at `x`, along with
+    /// its position.
+    fn next_right(&self, node: usize, x: f64) -> Option<(usize, f64)> {
+        let node = &self.nodes[node];
+        match node.last_child {
+            Some(child) => Some((child, x + self.nodes[child].offset)),
+            None => node.right_thread.map(|(next, offset)| (next, x + offset)),
+        }
+    }
+
+    /// Adds a node with the given width above its already laid out children, which
+    /// are left without a tree of their own.
+    ///
+    /// Each child is pushed to the right of its previous siblings until it is
+    /// `sibling_separation` away from them at every depth they share, then the node
+    /// is centered above its first and last children.
+    fn place<T: Positioned>(&mut self, children: &mut [Subtree<T>], width: f64) -> Subtree<T> {
+        let root = self.nodes.len();
+        self.nodes.push(ContourNode {
+            half_width: width / 2.0,
+            offset: 0.0,
+            first_child: children.first().map(|child| child.root),
+            last_child: children.last().map(|child| child.root),
+            left_thread: None,
+            right_thread: None,
+        });
+
+        let first = match children.first() {
+            Some(first) => first,
+            None => {
+                return Subtree {
+                    tree: None,
+                    root,
+                    depth: 0,
+                    left_bottom: (root, 0.0),
+                    right_bottom: (root, 0.0),
+                }
+            }
+        };
+
+        // positions are relative to the first child until the node is centered
+        let mut offsets = Vec::with_capacity(children.len());
+        offsets.push(0.0);
+        let mut depth = first.depth;
+        let mut left_bottom = first.left_bottom;
+        let mut right_bottom = first.right_bottom;
+        for (previous, child) in children.iter().zip(&children[1..]) {
+            let mut offset = f64::NEG_INFINITY;
+            let mut right = Some((previous.root, offsets[offsets.len() - 1]));
+            let mut left = Some((child.root, 0.0));
+            while let (Some((right_node, right_x)), Some((left_node, left_x))) = (right, left) {
+                let right_edge = right_x + self.nodes[right_node].half_width;
+                let left_edge = left_x - self.nodes[left_node].half_width;
+                offset = offset.max(right_edge + self.options.sibling_separation - left_edge);
+                right = self.next_right(right_node, right_x);
+                left = self.next_left(left_node, left_x);
+            }
+
+            let (child_right, child_right_x) = child.right_bottom;
+            match (right, left) {
+                (Some((right_node, right_x)), None) => {
+                    // the previous siblings are deeper, so the right contour continues
+                    // into them below this child
+                    self.nodes[child_right].right_thread =
+                        Some((right_node, right_x - (offset + child_right_x)));
+                }
+                (None, Some((left_node, left_x))) => {
+                    // this child is deeper, so the left contour continues into it
+                    // below the previous siblings
+                    let (forest_left, forest_left_x) = left_bottom;
+                    self.nodes[forest_left].left_thread =
+                        Some((left_node, offset + left_x - forest_left_x));
+                    let (child_left, child_left_x) = child.left_bottom;
+                    left_bottom = (child_left, offset + child_left_x);
+                    right_bottom = (child_right, offset + child_right_x);
+                    depth = child.depth;
+                }
+                _ => right_bottom = (child_right, offset + child_right_x),
+            }
+            offsets.push(offset);
+        }
+
+        let middle = (offsets[0] + offsets[offsets.len() - 1]) / 2.0;
+        for (child, offset) in children.iter_mut().zip(offsets) {
+            self.nodes[child.root].offset = offset - middle;
+            if let Some(tree) = &mut child.tree {
+                tree.position_mut().x = offset - middle;
+            }
+        }
+
+        Subtree {
+            tree: None,
+            root,
+            depth: depth + 1,
+            left_bottom: (left_bottom.0, left_bottom.1 - middle),
+            right_bottom: (right_bottom.0, right_bottom.1 - middle),
+        }
+    }
+}
+
+/// Draws each node as a labeled box with a line to its parent. `nodes` must
+/// yield each node after its parent, along with all of its ancestors.
+fn render_svg<'v, Value: 'v>(
+    mut nodes: impl StreamingIterator<Item = [&'v (Value, Position)]>,
+    options: &LayoutOptions<'_, Value>,
+    mut label: impl FnMut(&Value) -> String,
+) -> String {
+    let height = options.node_height;
+    let mut edges = String::new();
+    let mut boxes = String::new();
+    let (mut image_width, mut image_height) = (0.0_f64, 0.0_f64);
+    while let Some(ancestors) = nodes.next() {
+        let (value, position) = ancestors[ancestors.len() - 1];
+        let width = (options.node_width)(value);
+        image_width = image_width.max(position.x + width / 2.0);
+        image_height = image_height.max(position.y + height);
+
+        if let Some((_, parent)) = ancestors.len().checked_sub(2).map(|i| ancestors[i]) {
+            writeln!(
+                edges,
+                r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="black" />"#,
+                parent.x,
+                parent.y + height,
+                position.x,
+                position.y
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            boxes,
+            r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="white" stroke="black" />"#,
+            position.x - width / 2.0,
+            position.y,
+            width,
+            height
+        )
+        .unwrap();
+        writeln!(
+            boxes,
+            r#"  <text x="{}" y="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+            position.x,
+            position.y + height / 2.0,
+            escape(&label(value))
+        )
+        .unwrap();
+    }
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{image_width}" height="{image_height}" viewBox="0 0 {image_width} {image_height}">"#
+    )
+    .unwrap();
+    svg.push_str(&edges);
+    svg.push_str(&boxes);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Escapes the characters that have special meaning in XML text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+impl<Value> Tree<(Value, Position)> {
+    /// Renders a tree laid out by
+    /// [`layout_ref`](crate::prelude::BorrowedTreeNode::layout_ref) as an SVG
+    /// image. Each node is drawn as a box, sized according to `options`, containing
+    /// the text returned by `label`, with a line from the bottom of each node to the
+    /// top of each of its children. The text is escaped, so any label is safe to use.
+    ///
+    /// `options` should be the same options that were used to lay out the tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, LayoutOptions, Tree};
+    ///
+    /// let tree = Tree {
+    ///     value: "a < b",
+    ///     children: vec![Tree { value: "b", children: Vec::new() }],
+    /// };
+    ///
+    /// let options = LayoutOptions::new();
+    /// let svg = tree.layout_ref(&options).to_svg(&options, |value| value.to_string());
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains(r#"<line x1="20" y1="20" x2="20" y2="60" stroke="black" />"#));
+    /// assert!(svg.contains(">a &lt; b</text>"));
+    /// ```
+    pub fn to_svg<F>(&self, options: &LayoutOptions<'_, Value>, label: F) -> String
+    where
+        F: FnMut(&Value) -> String,
+    {
+        render_svg(self.dfs_preorder_iter().attach_ancestors(), options, label)
+    }
+}
+
+impl<Value> BinaryTree<(Value, Position)> {
+    /// Renders a binary tree laid out by
+    /// [`layout_ref`](crate::prelude::BorrowedBinaryTreeNode::layout_ref) as an SVG
+    /// image. See [`Tree::to_svg`] for more details.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, LayoutOptions}
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// let options = LayoutOptions::new();
+    /// let svg = tree.layout_ref(&options).to_svg(&options, |value| value.to_string());
+    ///
+    /// assert_eq!(11, svg.matches("<rect").count());
+    /// assert_eq!(10, svg.matches("<line").count());
+    /// ```
+    pub fn to_svg<F>(&self, options: &LayoutOptions<'_, Value>, label: F) -> String
+    where
+        F: FnMut(&Value) -> String,
+    {
+        render_svg(self.dfs_preorder_iter().attach_ancestors(), options, label)
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    prelude::{BinaryTree, Tree},
+    reconstruction::assemble,
+};
+
+impl<T> Tree<T> {
+    /// Converts this tree into its left-child right-sibling encoding. Each
+    /// node's first child becomes its left child, and each node's next sibling
+    /// becomes its right child. The root has no siblings, so its right child is
+    /// always [`None`].
+    ///
+    /// This is the inverse of [`BinaryTree::into_lcrs_forest`], and works on
+    /// arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, BorrowedTreeNode},
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// let binary = tree.clone().into_lcrs_binary();
+    ///
+    /// // an in order traversal of the encoding visits each node after all of its
+    /// // descendants, but before its later siblings.
+    /// assert_eq!(
+    ///     tree.dfs_postorder_iter().collect::<Vec<_>>(),
+    ///     binary.dfs_inorder_iter().collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(vec![tree], binary.into_lcrs_forest());
+    /// ```
+    pub fn into_lcrs_binary(self) -> BinaryTree<T> {
+        BinaryTree::from_lcrs(self)
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// Builds the left-child right-sibling encoding of `tree`. This is the same
+    /// as [`Tree::into_lcrs_binary`].
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, Tree};
+    ///
+    /// let tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree { value: 1, children: Vec::new() },
+    ///         Tree { value: 2, children: Vec::new() },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     BinaryTree {
+    ///         value: 0,
+    ///         left: Some(Box::new(BinaryTree {
+    ///             value: 1,
+    ///             left: None,
+    ///             right: Some(Box::new(BinaryTree {
+    ///                 value: 2,
+    ///                 left: None,
+    ///                 right: None,
+    ///             })),
+    ///         })),
+    ///         right: None,
+    ///     },
+    ///     BinaryTree::from_lcrs(tree)
+    /// );
+    /// ```
+    pub fn from_lcrs(tree: Tree<T>) -> Self {
+        let mut values = Vec::new();
+        let mut children: Vec<[Option<usize>; 2]> = Vec::new();
+        // each entry holds the siblings that have not been visited yet and the
+        // slot that the next of those siblings should be attached to.
+        let mut stack = vec![(vec![tree].into_iter(), None)];
+        while let Some((siblings, slot)) = stack.last_mut() {
+            let Tree {
+                value,
+                children: grandchildren,
+            } = match siblings.next() {
+                Some(node) => node,
+                None => {
+                    stack.pop();
+                    continue;
+                }
+            };
+
+            let index = values.len();
+            if let Some((previous, side)) = slot.replace((index, 1)) {
+                children[previous][side] = Some(index);
+            }
+
+            values.push(value);
+            children.push([None, None]);
+            stack.push((grandchildren.into_iter(), Some((index, 0))));
+        }
+
+        assemble(values, &children, false)
+    }
+
+    /// Decodes a left-child right-sibling encoded tree (see
+    /// [`Tree::into_lcrs_binary`]). Each node's left child becomes its first
+    /// child and each node's right child becomes its next sibling. Since the
+    /// root may have a right child, the result is the list of root-level
+    /// siblings, which has a single entry for trees built by
+    /// [`Tree::into_lcrs_binary`].
+    ///
+    /// This works on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, Tree};
+    ///
+    /// let binary = BinaryTree {
+    ///     value: 0,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 2,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Tree {
+    ///             value: 0,
+    ///             children: vec![Tree { value: 1, children: Vec::new() }],
+    ///         },
+    ///         Tree { value: 2, children: Vec::new() },
+    ///     ],
+    ///     binary.into_lcrs_forest()
+    /// );
+    /// ```
+    pub fn into_lcrs_forest(self) -> Vec<Tree<T>> {
+        let mut values = Vec::new();
+        let mut parents = Vec::new();
+        let mut stack = vec![(self, None)];
+        let mut next = stack.pop();
+        while let Some((node, parent)) = next {
+            let index = values.len();
+            values.push(node.value);
+            parents.push(parent);
+
+            // the left subtree must be visited before the right subtree so
+            // that siblings are added to their parent in order.
+            if let Some(right) = node.right {
+                stack.push((*right, parent));
+            }
+            if let Some(left) = node.left {
+                stack.push((*left, Some(index)));
+            }
+            next = stack.pop();
+        }
+
+        assemble_forest(values, &parents)
+    }
+
+    /// Converts this tree into a [`Tree`] without losing the position of any
+    /// node. Empty child slots are filled with a [`None`] leaf when they are
+    /// followed by a right child, so every node keeps the same path in both
+    /// trees. Trailing empty slots are dropped.
+    ///
+    /// This works on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, OwnedTreeNode, Tree};
+    ///
+    /// let binary = BinaryTree {
+    ///     value: 0,
+    ///     left: None,
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    /// };
+    ///
+    /// let tree = binary.into_tree_with_slots();
+    /// assert_eq!(
+    ///     Tree {
+    ///         value: Some(0),
+    ///         children: vec![
+    ///             Tree { value: None, children: Vec::new() },
+    ///             Tree { value: Some(1), children: Vec::new() },
+    ///         ],
+    ///     },
+    ///     tree
+    /// );
+    /// assert_eq!(Some(Some(1)), tree.at_path(&[1]).map(|node| node.value));
+    /// ```
+    pub fn into_tree_with_slots(self) -> Tree<Option<T>> {
+        let mut values = Vec::new();
+        let mut parents = Vec::new();
+        let mut stack = vec![(self, None)];
+        let mut next = stack.pop();
+        while let Some((node, parent)) = next {
+            let index = values.len();
+            values.push(Some(node.value));
+            parents.push(parent);
+
+            let slots = (node.left, node.right);
+            match slots {
+                (None, None) => {}
+                (Some(left), None) => stack.push((*left, Some(index))),
+                (left, Some(right)) => {
+                    stack.push((*right, Some(index)));
+                    match left {
+                        Some(left) => stack.push((*left, Some(index))),
+                        None => {
+                            // the placeholder has no children, so it can be
+                            // added right away without breaking the ordering.
+                            values.push(None);
+                            parents.push(Some(index));
+                        }
+                    }
+                }
+            }
+            next = stack.pop();
+        }
+
+        let mut roots = assemble_forest(values, &parents);
+        roots.pop().expect("the root to have been built")
+    }
+}
+
+/// Builds the trees out of `values` and the parent index of each value. Every
+/// value must come after its parent in `values`, and the children of each node
+/// are added in the order they appear. Values without a parent are returned as
+/// the roots of the forest.
+fn assemble_forest<T>(values: Vec<T>, parents: &[Option<usize>]) -> Vec<Tree<T>> {
+    let mut children = (0..values.len()).map(|_| Vec::new()).collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    for (index, value) in values.into_iter().enumerate().rev() {
+        let mut node_children = core::mem::take(&mut children[index]);
+        node_children.reverse();
+        let node = Tree {
+            value,
+            children: node_children,
+        };
+
+        match parents[index] {
+            Some(parent) => children[parent].push(node),
+            None => roots.push(node),
+        }
+    }
+
+    roots.reverse();
+    roots
+}
@@ -4,7 +4,9 @@ use streaming_iterator::StreamingIterator;
 
 use crate::{
     bfs_iterators::borrow::BorrowedBinaryBFSIteratorWithAncestors,
-    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode},
+    kary_iterators::BorrowedKaryBFSIteratorWithAncestors,
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode},
 };
 
 use super::{bfs_next, streaming_leaves};
@@ -81,3 +83,45 @@ where
 
     streaming_leaves!(get_value_and_children_iter);
 }
+
+pub struct BorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    is_root: bool,
+    item_stack: Vec<Node::BorrowedValue>,
+    tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    iterator_queue: VecDeque<Peekable<KaryChildren<&'a Node, N>>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(source: BorrowedKaryBFSIteratorWithAncestors<'a, Node, N>) -> Self {
+        Self {
+            is_root: source.is_root,
+            item_stack: source.item_stack,
+            iterator_queue: source
+                .iterator_queue
+                .into_iter()
+                .map(|val| val.peekable())
+                .collect(),
+            traversal_stack: source.traversal_stack,
+            tree_cache: source.tree_cache,
+        }
+    }
+
+    bfs_next!(get_value_and_children_iter);
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::BorrowedValue];
+
+    streaming_leaves!(get_value_and_children_iter);
+}
@@ -72,6 +72,7 @@ macro_rules! bfs_next {
             tree_node.children.push_back(Some(TreeNodeVecDeque {
                 value: core::mem::MaybeUninit::new(self.item_stack.pop().unwrap()),
                 path_segment: 0,
+                sibling_position: crate::tree_context::SiblingPosition::ROOT,
                 children: VecDeque::new(),
             }));
         }
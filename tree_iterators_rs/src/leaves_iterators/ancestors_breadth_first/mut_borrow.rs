@@ -4,7 +4,11 @@ use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use crate::{
     bfs_iterators::mut_borrow::MutBorrowedBinaryBFSIteratorWithAncestors,
-    prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode},
+    kary_iterators::MutBorrowedKaryBFSIteratorWithAncestors,
+    kary_tree::KaryChildren,
+    prelude::{
+        BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode,
+    },
 };
 
 use super::{bfs_next, get_mut, streaming_leaves};
@@ -95,3 +99,53 @@ where
 {
     get_mut!();
 }
+
+pub struct MutBorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    is_root: bool,
+    item_stack: Vec<Node::MutBorrowedValue>,
+    tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    iterator_queue: VecDeque<Peekable<KaryChildren<&'a mut Node, N>>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(source: MutBorrowedKaryBFSIteratorWithAncestors<'a, Node, N>) -> Self {
+        Self {
+            is_root: source.is_root,
+            item_stack: source.item_stack,
+            iterator_queue: source
+                .iterator_queue
+                .into_iter()
+                .map(|val| val.peekable())
+                .collect(),
+            traversal_stack: source.traversal_stack,
+            tree_cache: source.tree_cache,
+        }
+    }
+
+    bfs_next!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::MutBorrowedValue];
+
+    streaming_leaves!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryBFSLeavesIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut!();
+}
@@ -4,7 +4,9 @@ use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use crate::{
     bfs_iterators::owned::OwnedBinaryBFSIteratorWithAncestors,
-    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode},
+    kary_iterators::OwnedKaryBFSIteratorWithAncestors,
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode},
 };
 
 use super::{bfs_next, get_mut, streaming_leaves};
@@ -94,3 +96,51 @@ where
 {
     get_mut!();
 }
+
+pub struct OwnedKaryBFSLeavesIteratorWithAncestors<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    is_root: bool,
+    item_stack: Vec<Node::OwnedValue>,
+    tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    iterator_queue: VecDeque<Peekable<KaryChildren<Node, N>>>,
+}
+
+impl<Node, const N: usize> OwnedKaryBFSLeavesIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(source: OwnedKaryBFSIteratorWithAncestors<Node, N>) -> Self {
+        Self {
+            is_root: source.is_root,
+            item_stack: source.item_stack,
+            iterator_queue: source
+                .iterator_queue
+                .into_iter()
+                .map(|val| val.peekable())
+                .collect(),
+            traversal_stack: source.traversal_stack,
+            tree_cache: source.tree_cache,
+        }
+    }
+
+    bfs_next!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryBFSLeavesIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = [Node::OwnedValue];
+
+    streaming_leaves!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryBFSLeavesIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut!();
+}
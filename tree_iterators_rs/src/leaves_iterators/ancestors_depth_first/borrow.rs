@@ -1,4 +1,7 @@
-use crate::prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode},
+};
 use alloc::vec::Vec;
 use streaming_iterator::StreamingIterator;
 
@@ -45,3 +48,22 @@ where
     type Item = [Node::BorrowedValue];
     streaming_leaves!(get_value_and_children_iter);
 }
+
+pub struct BorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a Node>,
+    pub(crate) item_stack: Vec<Node::BorrowedValue>,
+    pub(crate) old_traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+    pub(crate) new_traversal_stack: Vec<KaryChildren<&'a Node, N>>,
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::BorrowedValue];
+    streaming_leaves!(get_value_and_children_iter);
+}
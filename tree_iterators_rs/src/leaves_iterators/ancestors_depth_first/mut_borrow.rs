@@ -1,4 +1,9 @@
-use crate::prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{
+        BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode,
+    },
+};
 use alloc::vec::Vec;
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
@@ -63,3 +68,30 @@ where
 {
     get_mut!();
 }
+
+pub struct MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a mut Node>,
+    pub(crate) item_stack: Vec<Node::MutBorrowedValue>,
+    pub(crate) old_traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+    pub(crate) new_traversal_stack: Vec<KaryChildren<&'a mut Node, N>>,
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = [Node::MutBorrowedValue];
+    streaming_leaves!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSLeavesPostorderIteratorWithAncestors<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut!();
+}
@@ -1,4 +1,7 @@
-use crate::prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode},
+};
 use alloc::vec::Vec;
 use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
@@ -61,3 +64,30 @@ where
 {
     get_mut!();
 }
+
+pub struct OwnedKaryDFSLeavesPostorderIteratorWithAncestors<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) root: Option<Node>,
+    pub(crate) item_stack: Vec<Node::OwnedValue>,
+    pub(crate) old_traversal_stack: Vec<KaryChildren<Node, N>>,
+    pub(crate) new_traversal_stack: Vec<KaryChildren<Node, N>>,
+}
+
+impl<Node, const N: usize> StreamingIterator
+    for OwnedKaryDFSLeavesPostorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = [Node::OwnedValue];
+    streaming_leaves!(get_value_and_children);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut
+    for OwnedKaryDFSLeavesPostorderIteratorWithAncestors<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut!();
+}
@@ -1,6 +1,9 @@
 use alloc::collections::VecDeque;
 
-use crate::prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode},
+};
 
 use core::iter::Peekable;
 
@@ -54,3 +57,27 @@ where
     type Item = Node::BorrowedValue;
     next!();
 }
+
+pub struct BorrowedKaryLeavesIterator<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a Node>,
+    pub(crate) old_traversal_queue: VecDeque<KaryChildren<&'a Node, N>>,
+    pub(crate) new_traversal_queue: VecDeque<Peekable<KaryChildren<&'a Node, N>>>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    bfs_next!(get_value_and_children_iter, Node::BorrowedValue);
+}
+
+impl<'a, Node, const N: usize> Iterator for BorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+    next!();
+}
@@ -1,6 +1,11 @@
 use alloc::collections::VecDeque;
 
-use crate::prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{
+        BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode,
+    },
+};
 
 use core::iter::Peekable;
 
@@ -57,3 +62,27 @@ where
     type Item = Node::MutBorrowedValue;
     next!();
 }
+
+pub struct MutBorrowedKaryLeavesIterator<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a mut Node>,
+    pub(crate) old_traversal_queue: VecDeque<KaryChildren<&'a mut Node, N>>,
+    pub(crate) new_traversal_queue: VecDeque<Peekable<KaryChildren<&'a mut Node, N>>>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    bfs_next!(get_value_and_children_iter_mut, Node::MutBorrowedValue);
+}
+
+impl<'a, Node, const N: usize> Iterator for MutBorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+    next!();
+}
@@ -1,6 +1,9 @@
 use alloc::collections::VecDeque;
 
-use crate::prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode},
+};
 
 use core::iter::Peekable;
 
@@ -60,3 +63,27 @@ where
     type Item = Node::OwnedValue;
     next!();
 }
+
+pub struct OwnedKaryLeavesIterator<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) root: Option<Node>,
+    pub(crate) old_traversal_queue: VecDeque<KaryChildren<Node, N>>,
+    pub(crate) new_traversal_queue: VecDeque<Peekable<KaryChildren<Node, N>>>,
+}
+
+impl<Node, const N: usize> OwnedKaryLeavesIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    bfs_next!(get_value_and_children, Node::OwnedValue);
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryLeavesIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+    next!();
+}
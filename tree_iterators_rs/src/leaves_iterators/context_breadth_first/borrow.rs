@@ -0,0 +1,169 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::array::IntoIter;
+use streaming_iterator::StreamingIterator;
+
+use super::{
+    bfs_binary_leaves_context_streaming_iterator_impl, bfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    bfs_iterators::{bfs_context_advance_iterator, TreeNodeVecDeque},
+    prelude::{BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode, TreeContext},
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct BorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    root_children: Option<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
+    tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    iterator_queue: VecDeque<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node> BorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(
+        value: Node::BorrowedValue,
+        children: Node::BorrowedChildren,
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(Siblings::new(children)),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node> StreamingIterator for BorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    bfs_leaves_context_streaming_iterator_impl!(get_value_and_children_iter);
+}
+
+pub struct BorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    root_children: Option<[Option<&'a Node>; 2]>,
+    tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a Node>, 2>)>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node> BorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(
+        value: Node::BorrowedValue,
+        children: [Option<&'a Node>; 2],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node> StreamingIterator for BorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary_iter);
+}
+
+pub struct BorrowedKaryBFSLeavesIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root_children: Option<[Option<&'a Node>; N]>,
+    tree_cache: TreeNodeVecDeque<Node::BorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::BorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a Node>, N>)>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(
+        value: Node::BorrowedValue,
+        children: [Option<&'a Node>; N],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary_iter);
+}
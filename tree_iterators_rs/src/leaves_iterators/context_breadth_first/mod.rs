@@ -0,0 +1,190 @@
+pub mod borrow;
+pub mod mut_borrow;
+pub mod owned;
+
+macro_rules! bfs_leaves_context_streaming_iterator_impl {
+    ($get_value_and_children: ident) => {
+        fn advance(&mut self) {
+            if let Some(children) = self.root_children.take() {
+                if children.len() == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.iterator_queue.push_back(children);
+            } else if self.current_context.ancestors.is_empty() {
+                return;
+            } else {
+                // the current node is a leaf, but the queue still needs an
+                // entry for it to stay in step with the cached tree.
+                self.iterator_queue.push_back(Siblings::default());
+            }
+
+            loop {
+                if self.current_context.ancestors.len() == self.traversal_stack.len() + 2 {
+                    self.pop_from_item_stack();
+                }
+
+                let iter = &mut self.iterator_queue[0];
+
+                if let Some(next) = iter.next() {
+                    let position = SiblingPosition {
+                        index: self.path_counter,
+                        count: self.path_counter + 1 + iter.len(),
+                    };
+                    self.current_context.path.push(self.path_counter);
+                    self.path_counter += 1;
+
+                    let (value, children) = next.$get_value_and_children();
+                    self.current_context.ancestors.push(value);
+                    self.current_context.siblings.push(position);
+
+                    let children = Siblings::new(children);
+                    if children.len() == 0 {
+                        self.current_context.index = self.current_context.index.wrapping_add(1);
+                        break;
+                    }
+
+                    self.iterator_queue.push_back(children);
+                    continue;
+                }
+
+                self.path_counter = 0;
+                let top_of_traversal_stack = self
+                    .traversal_stack
+                    .last_mut()
+                    .unwrap_or(&mut self.tree_cache);
+
+                if !top_of_traversal_stack.children.is_empty() {
+                    top_of_traversal_stack.children.push_front(None);
+                } else {
+                    while let Some(last) = self.traversal_stack.last() {
+                        if last.children.len() > 1 {
+                            break;
+                        }
+
+                        self.traversal_stack.pop();
+                        self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
+                        self.current_context.path.pop();
+                    }
+                }
+
+                self.advance_dfs();
+                self.iterator_queue.pop_front();
+
+                if self.iterator_queue.is_empty() {
+                    self.current_context.ancestors.clear();
+                    self.current_context.siblings.clear();
+                    break;
+                }
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&self.current_context)
+            }
+        }
+    };
+}
+
+macro_rules! bfs_binary_leaves_context_streaming_iterator_impl {
+    ($get_value_and_children_binary: ident) => {
+        fn advance(&mut self) {
+            if let Some(children) = self.root_children.take() {
+                let count = children.iter().flatten().count();
+                if count == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.iterator_queue.push_back((count, children.into_iter()));
+            } else if self.current_context.ancestors.is_empty() {
+                return;
+            } else {
+                // the current node is a leaf, but the queue still needs an
+                // entry for it to stay in step with the cached tree.
+                self.iterator_queue
+                    .push_back((0, core::array::from_fn(|_| None).into_iter()));
+            }
+
+            'outer: loop {
+                if self.current_context.ancestors.len() == self.traversal_stack.len() + 2 {
+                    self.pop_from_item_stack();
+                }
+
+                let (count, iter) = &mut self.iterator_queue[0];
+
+                let mut slot = iter.next();
+                while let Some(next) = slot {
+                    if let Some(next) = next {
+                        let position = SiblingPosition::slot(*count, iter.as_slice());
+                        self.current_context.path.push(self.path_counter);
+                        self.path_counter += 1;
+
+                        let (value, children) = next.$get_value_and_children_binary();
+                        self.current_context.ancestors.push(value);
+                        self.current_context.siblings.push(position);
+
+                        let count = children.iter().flatten().count();
+                        if count == 0 {
+                            self.current_context.index = self.current_context.index.wrapping_add(1);
+                            break 'outer;
+                        }
+
+                        self.iterator_queue.push_back((count, children.into_iter()));
+                        continue 'outer;
+                    } else {
+                        self.path_counter += 1;
+                    }
+
+                    slot = iter.next();
+                }
+
+                self.path_counter = 0;
+                let top_of_traversal_stack = self
+                    .traversal_stack
+                    .last_mut()
+                    .unwrap_or(&mut self.tree_cache);
+
+                if !top_of_traversal_stack.children.is_empty() {
+                    top_of_traversal_stack.children.push_front(None);
+                } else {
+                    // used up all the values, so just pop it
+                    while let Some(last) = self.traversal_stack.last() {
+                        if last.children.len() > 1 {
+                            break;
+                        }
+
+                        self.traversal_stack.pop();
+                        self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
+                        self.current_context.path.pop();
+                    }
+                }
+
+                self.advance_dfs();
+                self.iterator_queue.pop_front();
+                if self.iterator_queue.is_empty() {
+                    self.current_context.ancestors.clear();
+                    self.current_context.siblings.clear();
+                    break;
+                }
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&self.current_context)
+            }
+        }
+    };
+}
+
+pub(crate) use bfs_binary_leaves_context_streaming_iterator_impl;
+pub(crate) use bfs_leaves_context_streaming_iterator_impl;
@@ -0,0 +1,193 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::array::IntoIter;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use super::{
+    bfs_binary_leaves_context_streaming_iterator_impl, bfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    bfs_iterators::{bfs_context_advance_iterator, get_mut_context, TreeNodeVecDeque},
+    prelude::{
+        MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode, TreeContext,
+    },
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct MutBorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    root_children: Option<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
+    tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    iterator_queue: VecDeque<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node> MutBorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(
+        value: Node::MutBorrowedValue,
+        children: Node::MutBorrowedChildren,
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(Siblings::new(children)),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    bfs_leaves_context_streaming_iterator_impl!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    root_children: Option<[Option<&'a mut Node>; 2]>,
+    tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a mut Node>, 2>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node> MutBorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(
+        value: Node::MutBorrowedValue,
+        children: [Option<&'a mut Node>; 2],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary_iter_mut);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedBinaryBFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedKaryBFSLeavesIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root_children: Option<[Option<&'a mut Node>; N]>,
+    tree_cache: TreeNodeVecDeque<Node::MutBorrowedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::MutBorrowedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<&'a mut Node>, N>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+    path_counter: usize,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(
+        value: Node::MutBorrowedValue,
+        children: [Option<&'a mut Node>; N],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryBFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_context!();
+}
@@ -0,0 +1,189 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::array::IntoIter;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use super::{
+    bfs_binary_leaves_context_streaming_iterator_impl, bfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    bfs_iterators::{bfs_context_advance_iterator, get_mut_context, TreeNodeVecDeque},
+    prelude::{OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode, TreeContext},
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct OwnedBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    root_children: Option<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
+    tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    iterator_queue: VecDeque<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+    path_counter: usize,
+}
+
+impl<Node> OwnedBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    pub(crate) fn new(
+        value: Node::OwnedValue,
+        children: Node::OwnedChildren,
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(Siblings::new(children)),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<Node> StreamingIterator for OwnedBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    bfs_leaves_context_streaming_iterator_impl!(get_value_and_children);
+}
+
+impl<Node> StreamingIteratorMut for OwnedBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedBinaryBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    root_children: Option<[Option<Node>; 2]>,
+    tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<Node>, 2>)>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+    path_counter: usize,
+}
+
+impl<Node> OwnedBinaryBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    pub(crate) fn new(
+        value: Node::OwnedValue,
+        children: [Option<Node>; 2],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<Node> StreamingIterator for OwnedBinaryBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary);
+}
+
+impl<Node> StreamingIteratorMut for OwnedBinaryBFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedKaryBFSLeavesIteratorWithContext<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root_children: Option<[Option<Node>; N]>,
+    tree_cache: TreeNodeVecDeque<Node::OwnedValue>,
+    traversal_stack: Vec<TreeNodeVecDeque<Node::OwnedValue>>,
+    iterator_queue: VecDeque<(usize, IntoIter<Option<Node>, N>)>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+    path_counter: usize,
+}
+
+impl<Node, const N: usize> OwnedKaryBFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(
+        value: Node::OwnedValue,
+        children: [Option<Node>; N],
+        path: Vec<usize>,
+    ) -> Self {
+        let mut current_context = TreeContext {
+            ancestors: Vec::new(),
+            children: Some(()),
+            siblings: Vec::new(),
+            index: usize::MAX,
+            path,
+        };
+        current_context.ancestors.push(value);
+        current_context.siblings.push(SiblingPosition::ROOT);
+
+        Self {
+            root_children: Some(children),
+            tree_cache: TreeNodeVecDeque::default(),
+            traversal_stack: Vec::new(),
+            iterator_queue: VecDeque::new(),
+            current_context,
+            path_counter: 0,
+        }
+    }
+
+    bfs_context_advance_iterator!();
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryBFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    bfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryBFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_context!();
+}
@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+use core::array::IntoIter;
+use streaming_iterator::StreamingIterator;
+
+use super::{
+    dfs_binary_leaves_context_streaming_iterator_impl, dfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    prelude::{BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode, TreeContext},
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct BorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<Siblings<<Node::BorrowedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+}
+
+impl<'a, Node> BorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for BorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedTreeNode<'a>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    dfs_leaves_context_streaming_iterator_impl!(get_value_and_children_iter);
+}
+
+pub struct BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, 2>)>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+}
+
+impl<'a, Node> BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for BorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: BorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary_iter);
+}
+
+pub struct BorrowedKaryDFSLeavesIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a Node>, N>)>,
+    current_context: TreeContext<Node::BorrowedValue, ()>,
+}
+
+impl<'a, Node, const N: usize> BorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for BorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::BorrowedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary_iter);
+}
@@ -0,0 +1,151 @@
+pub mod borrow;
+pub mod mut_borrow;
+pub mod owned;
+
+macro_rules! dfs_leaves_context_streaming_iterator_impl {
+    ($get_value_and_children: ident) => {
+        fn advance(&mut self) {
+            if let Some(root) = self.root.take() {
+                let (value, children) = root.$get_value_and_children();
+                self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(SiblingPosition::ROOT);
+
+                let children = Siblings::new(children);
+                if children.len() == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.traversal_stack.push(children);
+                self.current_context.path.push(usize::MAX);
+            } else if self.current_context.ancestors.is_empty() {
+                return;
+            } else {
+                // the current node is a leaf, so there is nothing below it to visit.
+                self.current_context.ancestors.pop();
+                self.current_context.siblings.pop();
+            }
+
+            while let Some(top) = self.traversal_stack.last_mut() {
+                let next = match top.next() {
+                    Some(next) => next,
+                    None => {
+                        self.traversal_stack.pop();
+                        self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
+                        self.current_context.path.pop();
+                        continue;
+                    }
+                };
+
+                let last = self
+                    .current_context
+                    .path
+                    .last_mut()
+                    .expect("There to always be a value in the path list");
+                *last = last.wrapping_add(1);
+                let position = SiblingPosition {
+                    index: *last,
+                    count: *last + 1 + top.len(),
+                };
+
+                let (value, children) = next.$get_value_and_children();
+                self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(position);
+
+                let children = Siblings::new(children);
+                if children.len() == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.traversal_stack.push(children);
+                self.current_context.path.push(usize::MAX);
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&self.current_context)
+            }
+        }
+    };
+}
+
+macro_rules! dfs_binary_leaves_context_streaming_iterator_impl {
+    ($get_value_and_children_binary: ident) => {
+        fn advance(&mut self) {
+            if let Some(root) = self.root.take() {
+                let (value, children) = root.$get_value_and_children_binary();
+                self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(SiblingPosition::ROOT);
+
+                let count = children.iter().flatten().count();
+                if count == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.traversal_stack.push((count, children.into_iter()));
+                self.current_context.path.push(usize::MAX);
+            } else if self.current_context.ancestors.is_empty() {
+                return;
+            } else {
+                // the current node is a leaf, so there is nothing below it to visit.
+                self.current_context.ancestors.pop();
+                self.current_context.siblings.pop();
+            }
+
+            while let Some((count, top)) = self.traversal_stack.last_mut() {
+                let next = match top.next() {
+                    Some(next) => next,
+                    None => {
+                        self.traversal_stack.pop();
+                        self.current_context.ancestors.pop();
+                        self.current_context.siblings.pop();
+                        self.current_context.path.pop();
+                        continue;
+                    }
+                };
+
+                let last = self
+                    .current_context
+                    .path
+                    .last_mut()
+                    .expect("There to always be a value in the path list");
+                *last = last.wrapping_add(1);
+                let next = match next {
+                    Some(next) => next,
+                    None => continue,
+                };
+                let position = SiblingPosition::slot(*count, top.as_slice());
+
+                let (value, children) = next.$get_value_and_children_binary();
+                self.current_context.ancestors.push(value);
+                self.current_context.siblings.push(position);
+
+                let count = children.iter().flatten().count();
+                if count == 0 {
+                    self.current_context.index = self.current_context.index.wrapping_add(1);
+                    return;
+                }
+
+                self.traversal_stack.push((count, children.into_iter()));
+                self.current_context.path.push(usize::MAX);
+            }
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            if self.current_context.ancestors.is_empty() {
+                None
+            } else {
+                Some(&self.current_context)
+            }
+        }
+    };
+}
+
+pub(crate) use dfs_binary_leaves_context_streaming_iterator_impl;
+pub(crate) use dfs_leaves_context_streaming_iterator_impl;
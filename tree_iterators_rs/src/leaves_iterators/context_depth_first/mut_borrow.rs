@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use core::array::IntoIter;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use super::{
+    dfs_binary_leaves_context_streaming_iterator_impl, dfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    dfs_preorder_iterators::get_mut_context,
+    prelude::{
+        MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode, TreeContext,
+    },
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct MutBorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<Siblings<<Node::MutBorrowedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+}
+
+impl<'a, Node> MutBorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    dfs_leaves_context_streaming_iterator_impl!(get_value_and_children_iter_mut);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedTreeNode<'a>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a mut Node>, 2>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+}
+
+impl<'a, Node> MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    pub(crate) fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node> StreamingIterator for MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary_iter_mut);
+}
+
+impl<'a, Node> StreamingIteratorMut for MutBorrowedBinaryDFSLeavesIteratorWithContext<'a, Node>
+where
+    Node: MutBorrowedBinaryTreeNode<'a>,
+{
+    get_mut_context!();
+}
+
+pub struct MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    root: Option<&'a mut Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<&'a mut Node>, N>)>,
+    current_context: TreeContext<Node::MutBorrowedValue, ()>,
+}
+
+impl<'a, Node, const N: usize> MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) fn new(root: &'a mut Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<'a, Node, const N: usize> StreamingIterator
+    for MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = TreeContext<Node::MutBorrowedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary_iter_mut);
+}
+
+impl<'a, Node, const N: usize> StreamingIteratorMut
+    for MutBorrowedKaryDFSLeavesIteratorWithContext<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    get_mut_context!();
+}
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::array::IntoIter;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use super::{
+    dfs_binary_leaves_context_streaming_iterator_impl, dfs_leaves_context_streaming_iterator_impl,
+};
+use crate::{
+    dfs_preorder_iterators::get_mut_context,
+    prelude::{OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode, TreeContext},
+    tree_context::{SiblingPosition, Siblings},
+};
+
+pub struct OwnedDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<Siblings<<Node::OwnedChildren as IntoIterator>::IntoIter>>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+}
+
+impl<Node> OwnedDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    pub(crate) fn new(root: Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<Node> StreamingIterator for OwnedDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    dfs_leaves_context_streaming_iterator_impl!(get_value_and_children);
+}
+
+impl<Node> StreamingIteratorMut for OwnedDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedTreeNode,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedBinaryDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, 2>)>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+}
+
+impl<Node> OwnedBinaryDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    pub(crate) fn new(root: Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<Node> StreamingIterator for OwnedBinaryDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_binary);
+}
+
+impl<Node> StreamingIteratorMut for OwnedBinaryDFSLeavesIteratorWithContext<Node>
+where
+    Node: OwnedBinaryTreeNode,
+{
+    get_mut_context!();
+}
+
+pub struct OwnedKaryDFSLeavesIteratorWithContext<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    root: Option<Node>,
+    traversal_stack: Vec<(usize, IntoIter<Option<Node>, N>)>,
+    current_context: TreeContext<Node::OwnedValue, ()>,
+}
+
+impl<Node, const N: usize> OwnedKaryDFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) fn new(root: Node, path: Vec<usize>) -> Self {
+        Self {
+            root: Some(root),
+            traversal_stack: Vec::new(),
+            current_context: TreeContext {
+                path,
+                ancestors: Vec::new(),
+                children: Some(()),
+                siblings: Vec::new(),
+                index: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<Node, const N: usize> StreamingIterator for OwnedKaryDFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = TreeContext<Node::OwnedValue, ()>;
+    dfs_binary_leaves_context_streaming_iterator_impl!(get_value_and_children_kary);
+}
+
+impl<Node, const N: usize> StreamingIteratorMut for OwnedKaryDFSLeavesIteratorWithContext<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    get_mut_context!();
+}
@@ -1,4 +1,7 @@
-use crate::prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, BorrowedBinaryTreeNode, BorrowedKaryTreeNode, BorrowedTreeNode},
+};
 use alloc::vec::Vec;
 
 use super::dfs_postorder_leaves_next;
@@ -44,3 +47,22 @@ where
 
     dfs_postorder_leaves_next!(get_value_and_children_iter);
 }
+
+pub struct BorrowedKaryLeavesIterator<'a, Node, const N: usize>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a Node>,
+    pub(crate) traversal_stack_bottom: Vec<KaryChildren<&'a Node, N>>,
+    pub(crate) traversal_stack_top: Vec<KaryChildren<&'a Node, N>>,
+    pub(crate) item_stack: Vec<Node::BorrowedValue>,
+}
+
+impl<'a, Node, const N: usize> Iterator for BorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: BorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::BorrowedValue;
+
+    dfs_postorder_leaves_next!(get_value_and_children_iter);
+}
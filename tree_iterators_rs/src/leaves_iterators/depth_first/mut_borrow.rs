@@ -1,4 +1,9 @@
-use crate::prelude::{BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{
+        BinaryChildren, MutBorrowedBinaryTreeNode, MutBorrowedKaryTreeNode, MutBorrowedTreeNode,
+    },
+};
 use alloc::vec::Vec;
 
 use super::dfs_postorder_leaves_next;
@@ -44,3 +49,22 @@ where
 
     dfs_postorder_leaves_next!(get_value_and_children_iter_mut);
 }
+
+pub struct MutBorrowedKaryLeavesIterator<'a, Node, const N: usize>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    pub(crate) root: Option<&'a mut Node>,
+    pub(crate) traversal_stack_bottom: Vec<KaryChildren<&'a mut Node, N>>,
+    pub(crate) traversal_stack_top: Vec<KaryChildren<&'a mut Node, N>>,
+    pub(crate) item_stack: Vec<Node::MutBorrowedValue>,
+}
+
+impl<'a, Node, const N: usize> Iterator for MutBorrowedKaryLeavesIterator<'a, Node, N>
+where
+    Node: MutBorrowedKaryTreeNode<'a, N>,
+{
+    type Item = Node::MutBorrowedValue;
+
+    dfs_postorder_leaves_next!(get_value_and_children_iter_mut);
+}
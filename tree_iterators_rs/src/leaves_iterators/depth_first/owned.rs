@@ -1,4 +1,7 @@
-use crate::prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedTreeNode};
+use crate::{
+    kary_tree::KaryChildren,
+    prelude::{BinaryChildren, OwnedBinaryTreeNode, OwnedKaryTreeNode, OwnedTreeNode},
+};
 use alloc::vec::Vec;
 
 use super::dfs_postorder_leaves_next;
@@ -42,3 +45,22 @@ where
 
     dfs_postorder_leaves_next!(get_value_and_children);
 }
+
+pub struct OwnedKaryLeavesIterator<Node, const N: usize>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    pub(crate) root: Option<Node>,
+    pub(crate) traversal_stack_bottom: Vec<KaryChildren<Node, N>>,
+    pub(crate) traversal_stack_top: Vec<KaryChildren<Node, N>>,
+    pub(crate) item_stack: Vec<Node::OwnedValue>,
+}
+
+impl<Node, const N: usize> Iterator for OwnedKaryLeavesIterator<Node, N>
+where
+    Node: OwnedKaryTreeNode<N>,
+{
+    type Item = Node::OwnedValue;
+
+    dfs_postorder_leaves_next!(get_value_and_children);
+}
@@ -1,4 +1,6 @@
 pub mod ancestors_breadth_first;
 pub mod ancestors_depth_first;
 pub mod breadth_first;
+pub mod context_breadth_first;
+pub mod context_depth_first;
 pub mod depth_first;
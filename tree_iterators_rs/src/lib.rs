@@ -5,14 +5,39 @@
 extern crate alloc;
 extern crate core;
 
+mod avl_tree;
 pub mod bfs_iterators;
+mod binary_search_tree;
 pub(crate) mod collection_iterators;
 pub mod dfs_inorder_iterators;
 pub mod dfs_postorder_iterators;
 pub mod dfs_preorder_iterators;
+mod edit_distance;
+pub mod events_iterators;
 pub mod examples;
+mod forest;
+mod hashing;
+mod isomorphism;
+#[cfg(feature = "json")]
+mod json;
+pub mod kary_iterators;
+mod kary_tree;
+pub mod keyed_iterators;
+mod keyed_tree;
+mod keyed_tree_context;
+mod layout;
+mod lcrs;
 pub mod leaves_iterators;
+mod metrics;
+pub mod morris_iterators;
 pub mod prelude;
+mod reconstruction;
+mod search;
+mod selectors;
+mod shared_tree;
+mod sized_tree;
 mod tree_collection_iterators;
 mod tree_context;
+mod tree_event;
 pub(crate) mod tree_iterators;
+mod trie;
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate::prelude::TreeIteratorBase;
+
+/// A summary of the structure of a subtree. See
+/// [`annotate_metrics_ref`](crate::prelude::BorrowedTreeNode::annotate_metrics_ref)
+/// for more details on how these are calculated.
+///
+/// Ex. given a tree like the following, the metrics of the root node (0) would be
+/// `size: 11`, `height: 6`, `leaf_count: 4` and `max_branching_factor: 2`.
+/// ```text
+///        0
+///       / \
+///      1   2
+///     / \ / \
+///    3  4 5  6
+///           /
+///          7
+///           \
+///            8
+///           /
+///          9
+///           \
+///           10
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Metrics {
+    /// The number of nodes in the subtree, including the subtree's root.
+    pub size: usize,
+    /// The number of edges on the longest path from the subtree's root down
+    /// to one of its leaves. A subtree consisting of only a leaf has a height of 0.
+    pub height: usize,
+    /// The number of nodes in the subtree that have no children.
+    pub leaf_count: usize,
+    /// The largest number of children that any single node in the subtree has.
+    pub max_branching_factor: usize,
+}
+
+impl Metrics {
+    /// Combines the metrics of each of a node's children into that node's metrics.
+    pub(crate) fn from_children<'b>(children: impl IntoIterator<Item = &'b Metrics>) -> Self {
+        let mut result = Metrics {
+            size: 1,
+            height: 0,
+            leaf_count: 0,
+            max_branching_factor: 0,
+        };
+
+        let mut num_children = 0;
+        for child in children {
+            num_children += 1;
+            result.size += child.size;
+            result.height = result.height.max(child.height + 1);
+            result.leaf_count += child.leaf_count;
+            result.max_branching_factor =
+                result.max_branching_factor.max(child.max_branching_factor);
+        }
+
+        if num_children == 0 {
+            result.leaf_count = 1;
+        }
+
+        result.max_branching_factor = result.max_branching_factor.max(num_children);
+        result
+    }
+}
+
+pub(crate) fn height<Value, Children>(mut iter: impl TreeIteratorBase<Value, Children>) -> usize {
+    let mut height = 0;
+    while iter.next().is_some() {
+        height = height.max(iter.current_depth());
+    }
+    height
+}
+
+pub(crate) fn widths<Value, Children>(
+    mut iter: impl TreeIteratorBase<Value, Children>,
+) -> Vec<usize> {
+    let mut widths = Vec::new();
+    while iter.next().is_some() {
+        let depth = iter.current_depth();
+        if widths.len() <= depth {
+            widths.resize(depth + 1, 0);
+        }
+        widths[depth] += 1;
+    }
+    widths
+}
+
+pub(crate) fn width_at_depth<Value, Children>(
+    iter: impl TreeIteratorBase<Value, Children>,
+    depth: usize,
+) -> usize {
+    let mut iter = iter.prune_depth(depth);
+    let mut width = 0;
+    while iter.next().is_some() {
+        if iter.current_depth() == depth {
+            width += 1;
+        }
+    }
+    width
+}
@@ -0,0 +1,254 @@
+//! Morris traversals temporarily "thread" a [`BinaryTree`] by pointing the
+//! empty right slot of each node's in order predecessor back at the node. The
+//! threads replace the stack that the other depth first iterators keep, so the
+//! traversal only needs a constant amount of extra memory regardless of the
+//! height of the tree. Every thread is removed again by the time the traversal
+//! finishes.
+//!
+//! While a thread is in place, the tree is not a valid [`BinaryTree`], so the
+//! iterators only ever touch the tree through raw pointers and never hand out
+//! anything but the values. The root's subtrees are detached from the tree for
+//! the lifetime of the iterator so that leaking the iterator (for example with
+//! [`core::mem::forget`]) leaks the subtrees instead of leaving the threads in
+//! the tree.
+
+use alloc::boxed::Box;
+use core::{marker::PhantomData, ptr};
+
+use crate::prelude::BinaryTree;
+
+impl<T> BinaryTree<T> {
+    /// Creates an iterator that visits each value of the tree in order (left
+    /// subtree, node, right subtree), the same as
+    /// [`dfs_inorder_iter_mut`](crate::prelude::MutBorrowedBinaryTreeNode::dfs_inorder_iter_mut).
+    ///
+    /// Unlike [`dfs_inorder_iter_mut`](crate::prelude::MutBorrowedBinaryTreeNode::dfs_inorder_iter_mut),
+    /// this iterator uses the Morris traversal algorithm, which only needs a
+    /// constant amount of extra memory but visits each edge up to three times.
+    /// The tree is temporarily modified during the traversal and restored
+    /// afterwards. If the iterator is dropped early, it finishes walking the
+    /// tree to restore it.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode,
+    /// };
+    ///
+    /// let mut tree = create_example_binary_tree();
+    /// let expected = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+    ///
+    /// let mut result = Vec::new();
+    /// for value in tree.morris_inorder_iter_mut() {
+    ///     result.push(*value);
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(expected, result);
+    /// assert_eq!(
+    ///     expected.iter().map(|value| value * 10).collect::<Vec<_>>(),
+    ///     tree.dfs_inorder_iter().copied().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn morris_inorder_iter_mut(&mut self) -> MutBorrowedMorrisInorderIterator<'_, T> {
+        MutBorrowedMorrisInorderIterator {
+            traversal: MorrisTraversal::new(self),
+        }
+    }
+
+    /// Creates an iterator that visits each value of the tree in preorder (node,
+    /// left subtree, right subtree), the same as
+    /// [`dfs_preorder_iter_mut`](crate::prelude::MutBorrowedBinaryTreeNode::dfs_preorder_iter_mut).
+    ///
+    /// Unlike [`dfs_preorder_iter_mut`](crate::prelude::MutBorrowedBinaryTreeNode::dfs_preorder_iter_mut),
+    /// this iterator uses the Morris traversal algorithm, which only needs a
+    /// constant amount of extra memory but visits each edge up to three times.
+    /// The tree is temporarily modified during the traversal and restored
+    /// afterwards. If the iterator is dropped early, it finishes walking the
+    /// tree to restore it.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode,
+    /// };
+    ///
+    /// let mut tree = create_example_binary_tree();
+    /// let expected = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     expected,
+    ///     tree.morris_preorder_iter_mut()
+    ///         .map(|value| *value)
+    ///         .collect::<Vec<_>>()
+    /// );
+    ///
+    /// // stopping early still restores the tree.
+    /// assert_eq!(Some(&mut 0), tree.morris_preorder_iter_mut().next());
+    /// assert_eq!(expected, tree.dfs_preorder_iter().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn morris_preorder_iter_mut(&mut self) -> MutBorrowedMorrisPreorderIterator<'_, T> {
+        MutBorrowedMorrisPreorderIterator {
+            traversal: MorrisTraversal::new(self),
+        }
+    }
+}
+
+/// An in order iterator over a [`BinaryTree`] that uses constant extra memory.
+/// See [`BinaryTree::morris_inorder_iter_mut`].
+pub struct MutBorrowedMorrisInorderIterator<'a, T> {
+    traversal: MorrisTraversal<'a, T>,
+}
+
+impl<'a, T> Iterator for MutBorrowedMorrisInorderIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.traversal.advance(false)?;
+        // SAFETY: each node is visited exactly once and the traversal never
+        // creates references to the values, so this is the only reference to
+        // the value for the lifetime of the borrow of the tree.
+        Some(unsafe { &mut *ptr::addr_of_mut!((*node).value) })
+    }
+}
+
+/// A preorder iterator over a [`BinaryTree`] that uses constant extra memory.
+/// See [`BinaryTree::morris_preorder_iter_mut`].
+pub struct MutBorrowedMorrisPreorderIterator<'a, T> {
+    traversal: MorrisTraversal<'a, T>,
+}
+
+impl<'a, T> Iterator for MutBorrowedMorrisPreorderIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.traversal.advance(true)?;
+        // SAFETY: see MutBorrowedMorrisInorderIterator::next.
+        Some(unsafe { &mut *ptr::addr_of_mut!((*node).value) })
+    }
+}
+
+struct MorrisTraversal<'a, T> {
+    root: *mut BinaryTree<T>,
+    /// The root's subtrees, which are detached from the root until the
+    /// traversal is dropped.
+    root_children: [*mut BinaryTree<T>; 2],
+    current: *mut BinaryTree<T>,
+    phantom: PhantomData<&'a mut BinaryTree<T>>,
+}
+
+impl<'a, T> MorrisTraversal<'a, T> {
+    fn new(root: &'a mut BinaryTree<T>) -> Self {
+        let root_children = [root.left.take(), root.right.take()]
+            .map(|child| child.map_or(ptr::null_mut(), Box::into_raw));
+        let root: *mut BinaryTree<T> = root;
+        Self {
+            root,
+            root_children,
+            current: root,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves to the next node in the traversal, returning a pointer to it.
+    fn advance(&mut self, preorder: bool) -> Option<*mut BinaryTree<T>> {
+        // SAFETY: every pointer followed here is either the root, one of the
+        // root's detached subtrees, or was read out of a node's child slot, all
+        // of which are exclusively borrowed by this traversal.
+        unsafe {
+            while !self.current.is_null() {
+                let current = self.current;
+                let left = self.child(current, 0);
+                if left.is_null() {
+                    self.current = self.child(current, 1);
+                    return Some(current);
+                }
+
+                let mut predecessor = left;
+                loop {
+                    let right = self.child(predecessor, 1);
+                    if right.is_null() || right == current {
+                        break;
+                    }
+                    predecessor = right;
+                }
+
+                if self.child(predecessor, 1).is_null() {
+                    // first visit: thread the predecessor back to this node
+                    // and descend into the left subtree.
+                    self.set_right(predecessor, current);
+                    self.current = left;
+                    if preorder {
+                        return Some(current);
+                    }
+                } else {
+                    // second visit: the left subtree is done, so remove the
+                    // thread and move on to the right subtree.
+                    self.set_right(predecessor, ptr::null_mut());
+                    self.current = self.child(current, 1);
+                    if !preorder {
+                        return Some(current);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Reads the left (0) or right (1) child slot of `node`, including any
+    /// thread stored in it. `node` must be the root or a node in one of the
+    /// root's detached subtrees.
+    unsafe fn child(&self, node: *mut BinaryTree<T>, side: usize) -> *mut BinaryTree<T> {
+        if node == self.root {
+            return self.root_children[side];
+        }
+
+        // SAFETY: the caller guarantees that node is a node of this traversal.
+        // Option<Box<BinaryTree<T>>> is guaranteed to have the same
+        // representation as a nullable pointer.
+        unsafe {
+            let slot = if side == 0 {
+                ptr::addr_of!((*node).left)
+            } else {
+                ptr::addr_of!((*node).right)
+            };
+            *slot.cast::<*mut BinaryTree<T>>()
+        }
+    }
+
+    /// Overwrites the right child slot of `node` without dropping or creating
+    /// a [`Box`], since the slot may hold a thread to one of its ancestors.
+    unsafe fn set_right(&mut self, node: *mut BinaryTree<T>, right: *mut BinaryTree<T>) {
+        if node == self.root {
+            self.root_children[1] = right;
+            return;
+        }
+
+        // SAFETY: see MorrisTraversal::child.
+        unsafe {
+            ptr::addr_of_mut!((*node).right)
+                .cast::<*mut BinaryTree<T>>()
+                .write(right);
+        }
+    }
+}
+
+impl<T> Drop for MorrisTraversal<'_, T> {
+    fn drop(&mut self) {
+        // finishing the traversal removes every remaining thread.
+        while self.advance(false).is_some() {}
+
+        // SAFETY: the traversal is finished, so the detached subtrees are valid
+        // trees again, and the root is still exclusively borrowed.
+        unsafe {
+            let [left, right] = self
+                .root_children
+                .map(|child| (!child.is_null()).then(|| Box::from_raw(child)));
+            (*self.root).left = left;
+            (*self.root).right = right;
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::vec::Vec;
 
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
 use core::slice::{Iter, IterMut};
 use core::{fmt::Debug, iter::FusedIterator};
 
@@ -69,17 +73,45 @@ use super::dfs_inorder_iterators::{
     owned::OwnedDFSInorderIterator,
 };
 
+use super::events_iterators::{
+    borrow::{BorrowedBinaryEventsIterator, BorrowedEventsIterator},
+    mut_borrow::{MutBorrowedBinaryEventsIterator, MutBorrowedEventsIterator},
+    owned::{OwnedBinaryEventsIterator, OwnedEventsIterator},
+};
+
 use super::dfs_postorder_iterators::{
     borrow::{BorrowedBinaryDFSPostorderIterator, BorrowedDFSPostorderIterator},
     mut_borrow::{MutBorrowedBinaryDFSPostorderIterator, MutBorrowedDFSPostorderIterator},
     owned::{OwnedBinaryDFSPostorderIterator, OwnedDFSPostorderIterator},
 };
 
+pub use super::avl_tree::{AvlNode, AvlTree};
+pub use super::binary_search_tree::BinarySearchTreeRange;
+pub use super::edit_distance::{EditCosts, TreeEditDistance};
+pub use super::forest::{BorrowedSingleTree, MutBorrowedSingleTree, OwnedSingleTree};
+#[cfg(feature = "json")]
+pub use super::json::{JsonNode, JsonTreeError};
+pub use super::kary_tree::{
+    BorrowedKaryTreeNode, KaryTree, MutBorrowedKaryTreeNode, OwnedKaryTreeNode,
+};
+pub use super::keyed_tree::{
+    BorrowedKeyedTreeNode, KeyedTree, MutBorrowedKeyedTreeNode, OwnedKeyedTreeNode,
+};
+pub use super::keyed_tree_context::KeyedTreeContext;
+pub use super::layout::{LayoutOptions, Position};
+pub use super::metrics::Metrics;
+pub use super::reconstruction::ReconstructionError;
+pub use super::search::SearchOrder;
+pub use super::selectors::{Select, Selector};
+pub use super::shared_tree::SharedTree;
+pub use super::sized_tree::{ExactSizeTreeIterator, SizedTreeNode};
 pub use super::tree_context::TreeContext;
+pub use super::tree_event::{TreeEvent, TreeEventContext};
 pub use super::tree_iterators::{
-    BinaryPrune, BinaryPrunePath, BinaryTreeIterator, Map, MapPath, Prune, PruneDepth, PrunePath,
-    TreeIterator, TreeIteratorBase,
+    BinaryPrune, BinaryPrunePath, BinaryTreeIterator, KaryTreeIterator, Map, MapPath, Prune,
+    PruneDepth, PrunePath, TreeIterator, TreeIteratorBase,
 };
+pub use super::trie::{Trie, TrieIter, TrieNode};
 
 pub use super::tree_collection_iterators::{
     BinaryCollectionPrune, BinaryCollectionPrunePath, BinaryFold, BinaryFoldPath,
@@ -334,6 +366,12 @@ where
         OwnedBinaryDFSPostorderIterator::new(self)
     }
 
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events(self) -> OwnedBinaryEventsIterator<Self> {
+        OwnedBinaryEventsIterator::new(self)
+    }
+
     /// This method converts the current BinaryTreeNode into a BinaryTreeIterator.
     ///
     /// BinaryTreeIterators have 2 purposes:
@@ -870,6 +908,12 @@ where
         OwnedDFSPostorderIterator::new(self)
     }
 
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events(self) -> OwnedEventsIterator<Self> {
+        OwnedEventsIterator::new(self)
+    }
+
     /// This method converts the current TreeNode into a TreeIterator.
     ///
     /// TreeIterators have 2 purposes:
@@ -1262,6 +1306,47 @@ where
     {
         self.into_pipeline().fold_path(f).unwrap()
     }
+
+    /// Converts this tree into a [`Tree`] in a canonical form where the children
+    /// of every node are sorted. Children are ordered by their values according to
+    /// `cmp`, with ties broken by comparing their children in order. Any two trees
+    /// that only differ in the order of their children will produce identical
+    /// canonical trees, so they can be compared with `==` or hashed directly.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{OwnedTreeNode, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let tree = Tree {
+    ///     value: "admin",
+    ///     children: vec![
+    ///         Tree { value: "write", children: vec![leaf("users"), leaf("posts")] },
+    ///         leaf("read"),
+    ///     ],
+    /// };
+    /// let reordered = Tree {
+    ///     value: "admin",
+    ///     children: vec![
+    ///         leaf("read"),
+    ///         Tree { value: "write", children: vec![leaf("posts"), leaf("users")] },
+    ///     ],
+    /// };
+    ///
+    /// assert_ne!(tree, reordered);
+    /// assert_eq!(
+    ///     tree.canonicalize_by(|left, right| left.cmp(right)),
+    ///     reordered.canonicalize_by(|left, right| left.cmp(right))
+    /// );
+    /// ```
+    fn canonicalize_by<F>(self, mut cmp: F) -> Tree<Self::OwnedValue>
+    where
+        F: FnMut(&Self::OwnedValue, &Self::OwnedValue) -> Ordering,
+    {
+        let mut tree = crate::isomorphism::PostorderTree::new();
+        self.fold(|children, value| tree.push(children, value));
+        crate::isomorphism::canonicalize(tree, &mut cmp)
+    }
 }
 
 /// A binary tree node where getting its children mutably borrows its value.
@@ -1442,6 +1527,12 @@ where
         MutBorrowedBinaryDFSPostorderIterator::new(self)
     }
 
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events_iter_mut(&'a mut self) -> MutBorrowedBinaryEventsIterator<'a, Self> {
+        MutBorrowedBinaryEventsIterator::new(self)
+    }
+
     /// This method converts the current BinaryTreeNode into a BinaryTreeIterator.
     ///
     /// BinaryTreeIterators have 2 purposes:
@@ -1842,6 +1933,44 @@ where
     {
         self.into_pipeline_mut().fold_path(f).unwrap()
     }
+
+    /// Identical to [`MutBorrowedTreeNode::find_mut`] except that each path segment
+    /// is the slot of the child (0 for left, 1 for right).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{MutBorrowedBinaryTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let mut tree = create_example_binary_tree();
+    /// let (value, path) = tree
+    ///     .find_mut(SearchOrder::DepthFirst, |value| **value > 6)
+    ///     .unwrap();
+    /// *value = 100;
+    ///
+    /// assert_eq!(vec![1, 1, 0], path);
+    /// ```
+    fn find_mut<F>(
+        &'a mut self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<(Self::MutBorrowedValue, Vec<usize>)>
+    where
+        F: FnMut(&Self::MutBorrowedValue) -> bool,
+    {
+        crate::search::search(
+            self,
+            order,
+            |node: &'a mut Self| {
+                let (value, children) = node.get_value_and_children_binary_iter_mut();
+                (value, crate::search::binary_slots(children))
+            },
+            |value| predicate(value),
+            |value, path| Some((value, path)),
+        )
+    }
 }
 
 /// A tree node where getting its children mutably borrows its value.
@@ -1977,6 +2106,12 @@ where
         MutBorrowedDFSPostorderIterator::new(self)
     }
 
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events_iter_mut(&'a mut self) -> MutBorrowedEventsIterator<'a, Self> {
+        MutBorrowedEventsIterator::new(self)
+    }
+
     /// This method converts the current TreeNode into a TreeIterator.
     ///
     /// TreeIterators have 2 purposes:
@@ -2371,6 +2506,49 @@ where
     {
         self.into_pipeline_mut().fold_path(f).unwrap()
     }
+
+    /// Searches this tree for the first node whose value matches `predicate`,
+    /// visiting the nodes in the given [`SearchOrder`]. Returns a mutable
+    /// reference to the value of the matching node along with its path (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details). No nodes
+    /// are visited after the first match.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, MutBorrowedTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let mut tree = create_example_tree();
+    /// let (value, path) = tree
+    ///     .find_mut(SearchOrder::BreadthFirst, |value| **value > 4)
+    ///     .unwrap();
+    /// *value = 100;
+    ///
+    /// assert_eq!(vec![1, 0], path);
+    /// assert!(tree.contains_value(&100));
+    /// assert!(!tree.contains_value(&5));
+    /// ```
+    fn find_mut<F>(
+        &'a mut self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<(Self::MutBorrowedValue, Vec<usize>)>
+    where
+        F: FnMut(&Self::MutBorrowedValue) -> bool,
+    {
+        crate::search::search(
+            self,
+            order,
+            |node: &'a mut Self| {
+                let (value, children) = node.get_value_and_children_iter_mut();
+                (value, children.into_iter().enumerate())
+            },
+            |value| predicate(value),
+            |value, path| Some((value, path)),
+        )
+    }
 }
 
 /// A binary tree node where getting its children borrows its value.
@@ -2548,6 +2726,12 @@ where
         BorrowedBinaryDFSPostorderIterator::new(self)
     }
 
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events_iter(&'a self) -> BorrowedBinaryEventsIterator<'a, Self> {
+        BorrowedBinaryEventsIterator::new(self)
+    }
+
     /// This method converts the current BinaryTreeNode into a BinaryTreeIterator.
     ///
     /// BinaryTreeIterators have 2 purposes:
@@ -2948,225 +3132,1372 @@ where
     {
         self.into_pipeline_ref().fold_path(f).unwrap()
     }
-}
-
-/// A tree node where getting its children borrows its value.
-pub trait BorrowedTreeNode<'a>
-where
-    Self: Sized + 'a,
-{
-    /// A reference to the value of each node in the tree.
-    type BorrowedValue: Sized;
-    /// The type of iterator that can be used to iterate over each node's children
-    /// collection.
-    type BorrowedChildren: IntoIterator<Item = &'a Self, IntoIter: FusedIterator>;
-
-    /// This method gets the value and children from this node, consuming it
-    /// in the process. The other methods of this trait assume that the 'Children'
-    /// list does not contain and circular references back to parent nodes.
-    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, Self::BorrowedChildren);
-
-    #[doc = include_str!("../doc_files/at_path.md")]
-    #[doc = include_str!("../doc_files/at_path_tree_example.md")]
-    fn at_path_ref(&'a self, path: &[usize]) -> Option<&'a Self> {
-        let mut current = self;
-        for path_segment in path {
-            current = current
-                .get_value_and_children_iter()
-                .1
-                .into_iter()
-                .nth(*path_segment)?;
-        }
-        Some(current)
-    }
 
-    /// This method retrieves an iterator that can be used to perform
-    /// Breadth First (Queue - specifically VecDeque-based) searches of a tree.
+    /// Gets the number of nodes in this tree, including the root node.
     ///
-    /// A Breadth First Search (BFS) is defined as:
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
     ///
-    /// A tree traversal that involves breadth-first searching a tree
-    /// from the top down. Given a tree of the following shape, this
-    /// traversal type would traverse the elements in the order
-    /// 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10.
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(11, tree.size());
+    /// ```
+    fn size(&'a self) -> usize {
+        self.dfs_preorder_iter().count()
+    }
+
+    /// Gets the height of this tree. The height is the number of edges on the
+    /// longest path from the root down to a leaf, so a tree consisting of only
+    /// a root node has a height of zero.
     ///
-    /// In this traversal, we scan each level of the tree from left to
-    /// right before going down to the next level.
+    /// Ex. given a tree like the following, the height would be 6.
     /// ```text
-    ///        0
+    ///        0       <- depth: 0
     ///       / \
-    ///      1   2
+    ///      1   2     <- depth: 1
     ///     / \ / \
-    ///    3  4 5  6
+    ///    3  4 5  6   <- depth: 2
     ///           /
-    ///          7
+    ///          7     <- depth: 3
     ///           \
-    ///            8
+    ///            8   <- depth: 4
     ///           /
-    ///          9
+    ///          9     <- depth: 5
     ///           \
-    ///           10
+    ///           10   <- depth: 6
     /// ```
-    #[must_use]
-    fn bfs_iter(&'a self) -> BorrowedBFSIterator<'a, Self> {
-        BorrowedBFSIterator::new(self)
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(6, tree.height());
+    /// ```
+    fn height(&'a self) -> usize {
+        crate::metrics::height(self.into_pipeline_ref())
     }
 
-    /// This method retrieves an iterator that can be used to perform
-    /// Depth First Preorder searches of a tree.
-    ///
-    /// A Depth First Preorder search is defined as:
+    /// Gets the number of leaves (nodes without any children) in this tree.
     ///
-    /// A tree traversal that involves depth-first searching a tree
-    /// from the top down. Given a tree of the following shape, this
-    /// traversal type would traverse the elements in the order
-    /// 0, 1, 3, 4, 2, 5, 6, 7, 8, 9, 10.
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
     ///
-    /// In this traversal, each node will only be traversed before any
-    /// of its children have been traversed.
-    /// ```text
-    ///        0
-    ///       / \
-    ///      1   2
-    ///     / \ / \
-    ///    3  4 5  6
-    ///           /
-    ///          7
-    ///           \
-    ///            8
-    ///           /
-    ///          9
-    ///           \
-    ///           10
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(4, tree.leaf_count());
     /// ```
-    #[must_use]
-    fn dfs_preorder_iter(&'a self) -> BorrowedDFSPreorderIterator<'a, Self> {
-        BorrowedDFSPreorderIterator::new(self)
+    fn leaf_count(&'a self) -> usize {
+        self.dfs_preorder_iter().leaves().count()
     }
 
-    /// This method retrieves an iterator that can be used to perform
-    /// Depth First Postorder searches of a tree.
-    ///
-    /// A Depth First Postorder search (referred to as DFS Postorder)
-    /// is defined as:
+    /// Gets the largest number of nodes that are found at any single depth
+    /// of this tree.
     ///
-    /// A tree traversal that involves depth-first searching a tree
-    /// from the bottom up. Given a tree of the following shape, this
-    /// traversal type would traverse the elements in the order
-    /// 3, 4, 1, 5, 10, 9, 8, 7, 6, 2, 0.
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
     ///
-    /// In this traversal, each node will only be traversed after all
-    /// of its children have been traversed.
-    /// ```text
-    ///        0
-    ///       / \
-    ///      1   2
-    ///     / \ / \
-    ///    3  4 5  6
-    ///           /
-    ///          7
-    ///           \
-    ///            8
-    ///           /
-    ///          9
-    ///           \
-    ///           10
+    /// let tree = create_example_binary_tree();
+    /// // depth 2 contains 3, 4, 5 and 6
+    /// assert_eq!(4, tree.max_width());
     /// ```
-    ///
-    /// This traversal type guarantees that getChildren() will only be
-    /// called once per node of the tree.
-    #[must_use]
-    fn dfs_postorder_iter(&'a self) -> BorrowedDFSPostorderIterator<'a, Self> {
-        BorrowedDFSPostorderIterator::new(self)
+    fn max_width(&'a self) -> usize {
+        crate::metrics::widths(self.into_pipeline_ref())
+            .into_iter()
+            .max()
+            .unwrap_or_default()
     }
 
-    /// This method converts the current TreeNode into a TreeIterator.
-    ///
-    /// TreeIterators have 2 purposes:
-    /// 1. they serve as the internal piping of tree_iterators_rs
-    /// 2. they can efficiently chain the prune, map, and fold operations on a tree.
+    /// Gets the number of nodes at the given depth of this tree. Depth is zero-based,
+    /// so the root node is considered to be at depth zero. Nodes deeper than the
+    /// requested depth are never visited.
     ///
-    /// If you are only applying a single prune, map, or fold operation, just call the
-    /// associated method.
-    /// - [`prune_ref`](crate::prelude::BorrowedTreeNode::prune_ref)
-    /// - [`map_ref`](crate::prelude::BorrowedTreeNode::map_ref)
-    /// - [`fold_ref`](crate::prelude::BorrowedTreeNode::fold_ref)
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
     ///
-    /// If you are chaining many operations together, use into_pipeline. This will
-    /// be much more efficient in memory since it only maintains a single ancestor stack
-    /// of the tree at a time.
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(1, tree.width_at_depth(0));
+    /// assert_eq!(2, tree.width_at_depth(1));
+    /// assert_eq!(4, tree.width_at_depth(2));
+    /// assert_eq!(0, tree.width_at_depth(7));
+    /// ```
+    fn width_at_depth(&'a self, depth: usize) -> usize {
+        crate::metrics::width_at_depth(self.into_pipeline_ref(), depth)
+    }
+
+    /// Annotates every node in this tree with the [`Metrics`] of the subtree rooted at
+    /// that node. All of the metrics are calculated in a single depth first postorder
+    /// pass over the tree, so this is much cheaper than calling [`size`](BorrowedBinaryTreeNode::size),
+    /// [`height`](BorrowedBinaryTreeNode::height), etc. on every node.
     ///
-    /// ### Example Usage:
+    /// ### Example Usage
     /// ```rust
     /// use tree_iterators_rs::{
-    ///     examples::create_example_tree,
-    ///     prelude::{Tree, BorrowedTreeNode, TreeIteratorBase, TreeIterator}
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, Metrics}
     /// };
     ///
-    /// let tree = create_example_tree();
-    /// let result = tree.into_pipeline_ref()
-    ///     .prune_depth(2)
-    ///     .map_tree(|value| *value + 200)
-    ///     .collect_tree()
-    ///     .expect("all non-prune methods to collect into a Some()");
+    /// let tree = create_example_binary_tree();
+    /// let annotated = tree.annotate_metrics_ref();
+    ///
+    /// assert_eq!(
+    ///     Metrics {
+    ///         size: 11,
+    ///         height: 6,
+    ///         leaf_count: 4,
+    ///         max_branching_factor: 2,
+    ///     },
+    ///     annotated.value.1
+    /// );
+    ///
+    /// // the subtree rooted at 1
+    /// assert_eq!(3, annotated.left.as_ref().unwrap().value.1.size);
+    /// ```
+    fn annotate_metrics_ref(&'a self) -> BinaryTree<(Self::BorrowedValue, Metrics)> {
+        self.fold_ref(|[left, right], value| {
+            let metrics = Metrics::from_children(
+                [left.as_ref(), right.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .map(|child: &BinaryTree<(Self::BorrowedValue, Metrics)>| &child.value.1),
+            );
+
+            BinaryTree {
+                value: (value, metrics),
+                left: left.map(Box::new),
+                right: right.map(Box::new),
+            }
+        })
+    }
+
+    /// Computes a tidy layout of this binary tree for drawing, pairing each value
+    /// with the [`Position`] of its node. See
+    /// [`BorrowedTreeNode::layout_ref`] for more details. A lone child is placed as if
+    /// it had a sibling of its own width in the missing slot, so left children are
+    /// always drawn to the left of their parent and right children to the right.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode, LayoutOptions, Position};
+    ///
+    /// let leaf = |value| Some(Box::new(BinaryTree { value, left: None, right: None }));
+    /// let tree = BinaryTree { value: 0, left: None, right: leaf(1) };
+    /// let laid_out = tree.layout_ref(&LayoutOptions::new());
+    ///
+    /// assert_eq!(Position { x: 50.0, y: 0.0 }, laid_out.value.1);
+    /// assert_eq!(Position { x: 80.0, y: 60.0 }, laid_out.right.unwrap().value.1);
+    /// ```
+    fn layout_ref(
+        &'a self,
+        options: &LayoutOptions<'_, Self::BorrowedValue>,
+    ) -> BinaryTree<(Self::BorrowedValue, Position)> {
+        let mut layout = crate::layout::Layout::new(options);
+        let root = self.fold_ref(|children, value| layout.binary_node(children, value));
+        layout.finish(root)
+    }
+
+    /// Computes a Merkle-style hash for every subtree of this tree. Each node's hash is
+    /// computed in a depth first postorder pass from the node's value and the hashes of
+    /// its children, so two subtrees with the same structure and values will always have
+    /// the same hash. Empty child slots are hashed as well, so a node with only a left
+    /// child will not hash the same as a node with only a right child.
+    ///
+    /// Hashing is done using the given [`BuildHasher`]. Since equal hashes do not
+    /// guarantee equal subtrees, use [`find_duplicate_subtrees`](BorrowedBinaryTreeNode::find_duplicate_subtrees)
+    /// if you need to know which subtrees are actually duplicates of each other.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use std::collections::hash_map::RandomState;
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree {
+    ///     value: 0,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    /// };
+    ///
+    /// let hashes = tree.subtree_hashes_ref(&RandomState::new());
+    /// assert_eq!(hashes.left.unwrap().value, hashes.right.unwrap().value);
+    /// ```
+    fn subtree_hashes_ref<S>(&'a self, build_hasher: &S) -> BinaryTree<u64>
+    where
+        S: BuildHasher,
+        Self::BorrowedValue: Hash,
+    {
+        self.fold_ref(|[left, right], value| BinaryTree {
+            value: crate::hashing::hash_node(
+                build_hasher,
+                &value,
+                [left.as_ref(), right.as_ref()]
+                    .into_iter()
+                    .map(|child: Option<&BinaryTree<u64>>| child.map(|child| child.value)),
+            ),
+            left: left.map(Box::new),
+            right: right.map(Box::new),
+        })
+    }
+
+    /// Finds all groups of structurally equal subtrees in this tree. Two subtrees are
+    /// structurally equal if they have the same shape (including which child slots are
+    /// empty) and equal values at every position.
+    ///
+    /// Each group contains the paths (see [`current_path`](TreeIteratorBase::current_path)
+    /// for more details) of the roots of the equal subtrees. Subtrees that are not equal
+    /// to any other subtree are not included in the result. The groups, and the paths
+    /// within each group, are ordered by where they would appear in a depth first
+    /// preorder traversal.
+    ///
+    /// Subtrees are first bucketed by the hashes from
+    /// [`subtree_hashes_ref`](BorrowedBinaryTreeNode::subtree_hashes_ref) using the given
+    /// [`BuildHasher`], then compared to each other to rule out hash collisions.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use std::collections::hash_map::RandomState;
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree {
+    ///     value: 0,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: Some(Box::new(BinaryTree {
+    ///             value: 2,
+    ///             left: None,
+    ///             right: None,
+    ///         })),
+    ///         right: None,
+    ///     })),
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: Some(Box::new(BinaryTree {
+    ///             value: 2,
+    ///             left: None,
+    ///             right: None,
+    ///         })),
+    ///         right: None,
+    ///     })),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         vec![vec![0], vec![1]],
+    ///         vec![vec![0, 0], vec![1, 0]],
+    ///     ],
+    ///     tree.find_duplicate_subtrees(&RandomState::new())
+    /// );
+    /// ```
+    fn find_duplicate_subtrees<S>(&'a self, build_hasher: &S) -> Vec<Vec<Vec<usize>>>
+    where
+        S: BuildHasher,
+        Self::BorrowedValue: Hash + PartialEq,
+    {
+        let mut hashes_and_paths = Vec::new();
+        self.fold_path_ref(|children, path, value| {
+            let hash = crate::hashing::hash_node(build_hasher, &value, children.into_iter());
+            hashes_and_paths.push((hash, path.to_vec()));
+            hash
+        });
+
+        crate::hashing::group_duplicates(hashes_and_paths, |left, right| {
+            crate::hashing::pipelines_eq(
+                self.at_path_ref(left).unwrap().into_pipeline_ref(),
+                self.at_path_ref(right).unwrap().into_pipeline_ref(),
+            )
+        })
+    }
+
+    /// Checks whether this tree and `other` have the same shape and equal values at
+    /// every position according to `eq`. When `ordered` is `false`, the left and right
+    /// children of any node are also allowed to be swapped (sometimes called flip
+    /// equivalence).
+    ///
+    /// When `ordered` is `true`, this is done with a single iterative traversal of each
+    /// tree. When `ordered` is `false`, every subtree of both trees is given a canonical
+    /// id made from its value's class and the sorted ids of its children, and the trees
+    /// match if their roots get the same id. This takes O(n log n) time and no recursion,
+    /// plus the calls to `eq` used to sort values into classes. `eq` is only called on
+    /// nodes whose subtrees have the same shape, comparing each node against one node of
+    /// each class found so far, so it is called O(n * k) times for k distinct values.
+    /// Since values are grouped into classes, `eq` is expected to be an equivalence
+    /// relation when `ordered` is `false`.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree {
+    ///     value: 0,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    ///     right: None,
+    /// };
+    ///
+    /// let flipped = BinaryTree {
+    ///     value: "0",
+    ///     left: None,
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: "1",
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    /// };
+    ///
+    /// let eq = |left: &&usize, right: &&&str| left.to_string() == **right;
+    /// assert!(!tree.is_isomorphic(&flipped, eq, true));
+    /// assert!(tree.is_isomorphic(&flipped, eq, false));
+    /// ```
+    fn is_isomorphic<'b, Other, F>(&'a self, other: &'b Other, mut eq: F, ordered: bool) -> bool
+    where
+        Other: BorrowedBinaryTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Other::BorrowedValue) -> bool,
+    {
+        if ordered {
+            crate::isomorphism::pipelines_eq_by(
+                self.into_pipeline_ref(),
+                other.into_pipeline_ref(),
+                eq,
+            )
+        } else {
+            crate::isomorphism::binary_unordered_eq(self, other, &mut eq)
+        }
+    }
+
+    /// Finds every subtree of this tree that is isomorphic to `pattern` (see
+    /// [`is_isomorphic`](BorrowedBinaryTreeNode::is_isomorphic) with `ordered` set to `true`).
+    /// Returns the path of the root of each matching subtree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details), in the order
+    /// they would be visited by a depth first preorder traversal.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+    ///
+    /// let tree = BinaryTree {
+    ///     value: 0,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: Some(Box::new(BinaryTree {
+    ///             value: 2,
+    ///             left: None,
+    ///             right: None,
+    ///         })),
+    ///         right: None,
+    ///     })),
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: Some(Box::new(BinaryTree {
+    ///             value: 2,
+    ///             left: None,
+    ///             right: None,
+    ///         })),
+    ///     })),
+    /// };
+    ///
+    /// let pattern = BinaryTree {
+    ///     value: 1,
+    ///     left: Some(Box::new(BinaryTree {
+    ///         value: 2,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    ///     right: None,
+    /// };
+    ///
+    /// assert_eq!(vec![vec![0]], tree.find_subtree(&pattern, |a, b| a == b));
+    /// assert_eq!(
+    ///     vec![vec![0], vec![1]],
+    ///     tree.find_subtree_unordered(&pattern, |a, b| a == b)
+    /// );
+    /// ```
+    fn find_subtree<'b, Pattern, F>(&'a self, pattern: &'b Pattern, eq: F) -> Vec<Vec<usize>>
+    where
+        Pattern: BorrowedBinaryTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+    {
+        crate::isomorphism::binary_find_subtree(self, pattern, eq, true)
+    }
+
+    /// Identical to [`find_subtree`](BorrowedBinaryTreeNode::find_subtree) except that the
+    /// left and right children of any node are allowed to be swapped (see
+    /// [`is_isomorphic`](BorrowedBinaryTreeNode::is_isomorphic) with `ordered` set to `false`).
+    fn find_subtree_unordered<'b, Pattern, F>(
+        &'a self,
+        pattern: &'b Pattern,
+        eq: F,
+    ) -> Vec<Vec<usize>>
+    where
+        Pattern: BorrowedBinaryTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+    {
+        crate::isomorphism::binary_find_subtree(self, pattern, eq, false)
+    }
+
+    /// Computes the tree edit distance between this tree and `other`. See
+    /// [`BorrowedTreeNode::tree_edit_distance`] for more details. Children stay in
+    /// order, and a missing child is simply skipped, so a node with only a right
+    /// child can be mapped onto a node with only a left child. Paths use the slot
+    /// of each child (0 for left, 1 for right).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, BorrowedBinaryTreeNode, EditCosts};
+    ///
+    /// let leaf = |value| Some(Box::new(BinaryTree { value, left: None, right: None }));
+    /// let left = BinaryTree { value: 0, left: leaf(1), right: leaf(2) };
+    /// let right = BinaryTree { value: 0, left: None, right: leaf(2) };
+    ///
+    /// let result = left.tree_edit_distance(&right, &EditCosts::new());
+    /// assert_eq!(1, result.distance);
+    /// assert_eq!(vec![vec![0]], result.deleted);
+    /// assert_eq!(vec![(vec![], vec![]), (vec![1], vec![1])], result.mapping);
+    /// ```
+    fn tree_edit_distance<'b, Other>(
+        &'a self,
+        other: &'b Other,
+        costs: &EditCosts<'_, Self::BorrowedValue, Other::BorrowedValue>,
+    ) -> TreeEditDistance
+    where
+        Other: BorrowedBinaryTreeNode<'b>,
+    {
+        crate::edit_distance::tree_edit_distance(
+            self,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_binary_iter();
+                (value, crate::search::binary_slots(children))
+            },
+            other,
+            |node: &'b Other| {
+                let (value, children) = node.get_value_and_children_binary_iter();
+                (value, crate::search::binary_slots(children))
+            },
+            costs,
+        )
+    }
+
+    /// Identical to [`BorrowedTreeNode::find_ref`] except that each path segment
+    /// is the slot of the child (0 for left, 1 for right).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// let is_positive_even = |value: &&usize| **value > 0 && **value % 2 == 0;
+    /// assert_eq!(
+    ///     Some((&4, vec![0, 1])),
+    ///     tree.find_ref(SearchOrder::DepthFirst, is_positive_even)
+    /// );
+    /// assert_eq!(
+    ///     Some((&2, vec![1])),
+    ///     tree.find_ref(SearchOrder::BreadthFirst, is_positive_even)
+    /// );
+    /// ```
+    fn find_ref<F>(
+        &'a self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<(Self::BorrowedValue, Vec<usize>)>
+    where
+        F: FnMut(&Self::BorrowedValue) -> bool,
+    {
+        crate::search::search(
+            self,
+            order,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_binary_iter();
+                (value, crate::search::binary_slots(children))
+            },
+            |value| predicate(value),
+            |value, path| Some((value, path)),
+        )
+    }
+
+    /// Identical to [`BorrowedTreeNode::find_all_paths`] except that each path
+    /// segment is the slot of the child (0 for left, 1 for right).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(
+    ///     vec![vec![], vec![0, 1], vec![1, 1, 0, 1]],
+    ///     tree.find_all_paths(SearchOrder::BreadthFirst, |value| **value % 4 == 0)
+    /// );
+    /// ```
+    fn find_all_paths<F>(&'a self, order: SearchOrder, mut predicate: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&Self::BorrowedValue) -> bool,
+    {
+        let mut paths = Vec::new();
+        crate::search::search::<_, _, _, ()>(
+            self,
+            order,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_binary_iter();
+                (value, crate::search::binary_slots(children))
+            },
+            |value| predicate(value),
+            |_value, path| {
+                paths.push(path);
+                None
+            },
+        );
+        paths
+    }
+
+    /// Returns true if any node in this tree has a value equal to `value`.
+    /// The search stops at the first match.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BorrowedBinaryTreeNode
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// assert!(tree.contains_value(&7));
+    /// assert!(!tree.contains_value(&11));
+    /// ```
+    fn contains_value<V>(&'a self, value: &V) -> bool
+    where
+        V: PartialEq + ?Sized,
+        Self::BorrowedValue: Borrow<V>,
+    {
+        self.dfs_preorder_iter()
+            .any(|node_value| node_value.borrow() == value)
+    }
+
+    /// Identical to [`BorrowedTreeNode::find_with_ancestors`].
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BorrowedBinaryTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// assert_eq!(
+    ///     Some(vec![&0, &2, &6, &7]),
+    ///     tree.find_with_ancestors(SearchOrder::BreadthFirst, |ancestors| {
+    ///         **ancestors.last().unwrap() == 7
+    ///     })
+    /// );
+    /// ```
+    fn find_with_ancestors<F>(
+        &'a self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<Vec<Self::BorrowedValue>>
+    where
+        F: FnMut(&[Self::BorrowedValue]) -> bool,
+        Self::BorrowedValue: Clone,
+    {
+        match order {
+            SearchOrder::DepthFirst => streaming_iterator::StreamingIterator::find(
+                &mut self.dfs_preorder_iter().attach_ancestors(),
+                |ancestors| predicate(ancestors),
+            )
+            .map(<[_]>::to_vec),
+            SearchOrder::BreadthFirst => streaming_iterator::StreamingIterator::find(
+                &mut self.bfs_iter().attach_ancestors(),
+                |ancestors| predicate(ancestors),
+            )
+            .map(<[_]>::to_vec),
+        }
+    }
+}
+
+/// A tree node where getting its children borrows its value.
+pub trait BorrowedTreeNode<'a>
+where
+    Self: Sized + 'a,
+{
+    /// A reference to the value of each node in the tree.
+    type BorrowedValue: Sized;
+    /// The type of iterator that can be used to iterate over each node's children
+    /// collection.
+    type BorrowedChildren: IntoIterator<Item = &'a Self, IntoIter: FusedIterator>;
+
+    /// This method gets the value and children from this node, consuming it
+    /// in the process. The other methods of this trait assume that the 'Children'
+    /// list does not contain and circular references back to parent nodes.
+    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, Self::BorrowedChildren);
+
+    #[doc = include_str!("../doc_files/at_path.md")]
+    #[doc = include_str!("../doc_files/at_path_tree_example.md")]
+    fn at_path_ref(&'a self, path: &[usize]) -> Option<&'a Self> {
+        let mut current = self;
+        for path_segment in path {
+            current = current
+                .get_value_and_children_iter()
+                .1
+                .into_iter()
+                .nth(*path_segment)?;
+        }
+        Some(current)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Breadth First (Queue - specifically VecDeque-based) searches of a tree.
+    ///
+    /// A Breadth First Search (BFS) is defined as:
+    ///
+    /// A tree traversal that involves breadth-first searching a tree
+    /// from the top down. Given a tree of the following shape, this
+    /// traversal type would traverse the elements in the order
+    /// 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10.
+    ///
+    /// In this traversal, we scan each level of the tree from left to
+    /// right before going down to the next level.
+    /// ```text
+    ///        0
+    ///       / \
+    ///      1   2
+    ///     / \ / \
+    ///    3  4 5  6
+    ///           /
+    ///          7
+    ///           \
+    ///            8
+    ///           /
+    ///          9
+    ///           \
+    ///           10
+    /// ```
+    #[must_use]
+    fn bfs_iter(&'a self) -> BorrowedBFSIterator<'a, Self> {
+        BorrowedBFSIterator::new(self)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Depth First Preorder searches of a tree.
+    ///
+    /// A Depth First Preorder search is defined as:
+    ///
+    /// A tree traversal that involves depth-first searching a tree
+    /// from the top down. Given a tree of the following shape, this
+    /// traversal type would traverse the elements in the order
+    /// 0, 1, 3, 4, 2, 5, 6, 7, 8, 9, 10.
+    ///
+    /// In this traversal, each node will only be traversed before any
+    /// of its children have been traversed.
+    /// ```text
+    ///        0
+    ///       / \
+    ///      1   2
+    ///     / \ / \
+    ///    3  4 5  6
+    ///           /
+    ///          7
+    ///           \
+    ///            8
+    ///           /
+    ///          9
+    ///           \
+    ///           10
+    /// ```
+    #[must_use]
+    fn dfs_preorder_iter(&'a self) -> BorrowedDFSPreorderIterator<'a, Self> {
+        BorrowedDFSPreorderIterator::new(self)
+    }
+
+    /// This method retrieves an iterator that can be used to perform
+    /// Depth First Postorder searches of a tree.
+    ///
+    /// A Depth First Postorder search (referred to as DFS Postorder)
+    /// is defined as:
+    ///
+    /// A tree traversal that involves depth-first searching a tree
+    /// from the bottom up. Given a tree of the following shape, this
+    /// traversal type would traverse the elements in the order
+    /// 3, 4, 1, 5, 10, 9, 8, 7, 6, 2, 0.
+    ///
+    /// In this traversal, each node will only be traversed after all
+    /// of its children have been traversed.
+    /// ```text
+    ///        0
+    ///       / \
+    ///      1   2
+    ///     / \ / \
+    ///    3  4 5  6
+    ///           /
+    ///          7
+    ///           \
+    ///            8
+    ///           /
+    ///          9
+    ///           \
+    ///           10
+    /// ```
+    ///
+    /// This traversal type guarantees that getChildren() will only be
+    /// called once per node of the tree.
+    #[must_use]
+    fn dfs_postorder_iter(&'a self) -> BorrowedDFSPostorderIterator<'a, Self> {
+        BorrowedDFSPostorderIterator::new(self)
+    }
+
+    #[doc = include_str!("../doc_files/events.md")]
+    #[must_use]
+    fn events_iter(&'a self) -> BorrowedEventsIterator<'a, Self> {
+        BorrowedEventsIterator::new(self)
+    }
+
+    /// This method converts the current TreeNode into a TreeIterator.
+    ///
+    /// TreeIterators have 2 purposes:
+    /// 1. they serve as the internal piping of tree_iterators_rs
+    /// 2. they can efficiently chain the prune, map, and fold operations on a tree.
+    ///
+    /// If you are only applying a single prune, map, or fold operation, just call the
+    /// associated method.
+    /// - [`prune_ref`](crate::prelude::BorrowedTreeNode::prune_ref)
+    /// - [`map_ref`](crate::prelude::BorrowedTreeNode::map_ref)
+    /// - [`fold_ref`](crate::prelude::BorrowedTreeNode::fold_ref)
+    ///
+    /// If you are chaining many operations together, use into_pipeline. This will
+    /// be much more efficient in memory since it only maintains a single ancestor stack
+    /// of the tree at a time.
+    ///
+    /// ### Example Usage:
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{Tree, BorrowedTreeNode, TreeIteratorBase, TreeIterator}
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// let result = tree.into_pipeline_ref()
+    ///     .prune_depth(2)
+    ///     .map_tree(|value| *value + 200)
+    ///     .collect_tree()
+    ///     .expect("all non-prune methods to collect into a Some()");
+    ///
+    /// assert_eq!(
+    ///     Tree {
+    ///        value: 200,
+    ///        children: vec![
+    ///            Tree {
+    ///                value: 201,
+    ///                children: vec![
+    ///                    Tree {
+    ///                        value: 203,
+    ///                        children: vec![],
+    ///                    },
+    ///                    Tree {
+    ///                        value: 204,
+    ///                        children: vec![],
+    ///                    },
+    ///                ],
+    ///            },
+    ///            Tree {
+    ///                value: 202,
+    ///                children: vec![
+    ///                    Tree {
+    ///                        value: 205,
+    ///                        children: vec![],
+    ///                    },
+    ///                    Tree {
+    ///                        value: 206,
+    ///                        children: vec![],
+    ///                    },
+    ///                ],
+    ///            },
+    ///        ],
+    ///     },
+    ///     result);
+    /// ```
+    #[must_use]
+    fn into_pipeline_ref(
+        &'a self,
+    ) -> impl TreeIterator<Self::BorrowedValue, Self::BorrowedChildren> {
+        BorrowedDFSPreorderIteratorWithPathTracking::new(self, Vec::new())
+    }
+
+    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
+    ///
+    /// Uses the given closure to determine if each subtree in this tree should be pruned.
+    ///
+    /// Given an element the closure must return true or false. Any nodes in the tree for
+    /// which this evaluates to true will be pruned out of the resulting tree. If the root
+    /// node is pruned, this will return [`None`].
+    ///
+    /// The closure is called on the nodes in a depth first preorder traversal order (see
+    /// [`dfs_preorder`](crate::prelude::OwnedTreeNode::dfs_preorder) for more details). If a
+    /// node is determined to be pruned, its entire subtree will be pruned without calling the
+    /// closure on its descendent nodes.
+    ///
+    /// ### Basic usage:
+    ///
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
+    ///
+    /// let tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![Tree {
+    ///                 value: 3,
+    ///                 children: Vec::new(),
+    ///             }],
+    ///         },
+    ///         Tree {
+    ///             value: 2,
+    ///             children: Vec::new()
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Some(
+    ///         Tree {
+    ///             value: &0,
+    ///             children: vec![
+    ///                 Tree {
+    ///                     value: &2,
+    ///                     children: Vec::new(),
+    ///                 }
+    ///             ],
+    ///         },
+    ///     ),
+    ///     tree.prune_ref(|value| {
+    ///         /// The output for this code would be the following. A couple notes about
+    ///         /// this output:
+    ///         /// 1. the node with a value of '1' has been removed
+    ///         /// 2. the closure is never called on the node with a value of '3' since
+    ///         ///    it is already determined to be pruned once '1' has been evaluated.
+    ///         /// ```
+    ///         /// 0
+    ///         /// 1
+    ///         /// 2
+    ///         /// ```
+    ///         println!("{value:?}");
+    ///         **value == 1
+    ///     })
+    /// );
+    /// ```
+    fn prune_ref<F>(&'a self, f: F) -> Option<Tree<Self::BorrowedValue>>
+    where
+        F: FnMut(&Self::BorrowedValue) -> bool,
+    {
+        self.into_pipeline_ref().prune(f).collect_tree()
+    }
+
+    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
+    ///
+    /// Uses the given closure to determine if each subtree in this tree should be pruned.
+    ///
+    /// Given the path of an element and the element's value, the closure must return true or
+    /// false. Any nodes in the tree for which this evaluates to true will be pruned out of
+    /// the resulting tree. If the root node is pruned, this will return [`None`].
+    ///
+    /// The closure is called on the nodes in a depth first preorder traversal order (see
+    /// [`dfs_preorder`](crate::prelude::OwnedTreeNode::dfs_preorder) for more details). If a
+    /// node is determined to be pruned, its entire subtree will be pruned without calling the
+    /// closure on its descendent nodes.
+    ///
+    /// ### Basic usage:
+    ///
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
+    ///
+    /// let tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![Tree {
+    ///                 value: 3,
+    ///                 children: Vec::new(),
+    ///             }],
+    ///         },
+    ///         Tree {
+    ///             value: 2,
+    ///             children: Vec::new()
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Some(
+    ///         Tree {
+    ///             value: &0,
+    ///             children: vec![
+    ///                 Tree {
+    ///                     value: &2,
+    ///                     children: Vec::new(),
+    ///                 }
+    ///             ],
+    ///         },
+    ///     ),
+    ///     tree.prune_path_ref(|path, value| {
+    ///         /// The output for this code would be the following. A couple notes about
+    ///         /// this output:
+    ///         /// 1. the node with a value of '1' has been removed
+    ///         /// 2. the closure is never called on the node with a value of '3' since
+    ///         ///    it is already determined to be pruned once '1' has been evaluated.
+    ///         /// ```
+    ///         /// 0
+    ///         /// 1
+    ///         /// 2
+    ///         /// ```
+    ///         println!("{value:?}");
+    ///         matches!(path.get(0), Some(0))
+    ///     })
+    /// );
+    /// ```
+    fn prune_path_ref<F>(&'a self, f: F) -> Option<Tree<Self::BorrowedValue>>
+    where
+        F: FnMut(&[usize], &Self::BorrowedValue) -> bool,
+    {
+        self.into_pipeline_ref().prune_path(f).collect_tree()
+    }
+
+    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
+    ///
+    /// Uses the depth of each subtree to determine if the subtree should be pruned.
+    /// Any node with a depth that is strictly greater than the max_depth parameter
+    /// will be pruned from the tree.
+    ///
+    /// Depth is zero-based, so the root node is considered to be at depth zero.
+    ///
+    /// Ex. given a tree like the following, the depths would be as labeled.
+    ///
+    /// ```text
+    ///        0       <- depth: 0
+    ///       / \
+    ///      1   2     <- depth: 1
+    ///     / \ / \
+    ///    3  4 5  6   <- depth: 2
+    ///           /
+    ///          7     <- depth: 3
+    ///           \
+    ///            8   <- depth: 4
+    ///           /
+    ///          9     <- depth: 5
+    ///           \
+    ///           10   <- depth: 6
+    /// ```
+    ///
+    /// ### Basic usage:
+    ///
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{Tree, BorrowedTreeNode};
+    ///
+    /// let mut tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![Tree {
+    ///                 value: 3,
+    ///                 children: vec![],
+    ///             }],
+    ///         },
+    ///         Tree {
+    ///             value: 2,
+    ///             children: vec![],
+    ///         },
+    ///     ],
+    /// };
     ///
     /// assert_eq!(
     ///     Tree {
-    ///        value: 200,
-    ///        children: vec![
-    ///            Tree {
-    ///                value: 201,
-    ///                children: vec![
-    ///                    Tree {
-    ///                        value: 203,
-    ///                        children: vec![],
-    ///                    },
-    ///                    Tree {
-    ///                        value: 204,
-    ///                        children: vec![],
-    ///                    },
-    ///                ],
-    ///            },
-    ///            Tree {
-    ///                value: 202,
-    ///                children: vec![
-    ///                    Tree {
-    ///                        value: 205,
-    ///                        children: vec![],
-    ///                    },
-    ///                    Tree {
-    ///                        value: 206,
-    ///                        children: vec![],
-    ///                    },
-    ///                ],
-    ///            },
-    ///        ],
+    ///         value: &0,
+    ///         children: vec![],
+    ///     },
+    ///     tree.prune_depth_ref(0)
+    /// );
+    /// ```
+    fn prune_depth_ref(&'a self, max_depth: usize) -> Tree<Self::BorrowedValue> {
+        self.into_pipeline_ref()
+            .prune_depth(max_depth)
+            .collect_tree()
+            .unwrap()
+    }
+
+    /// map is a tree-based analog to [map](core::iter::Iterator::map).
+    ///
+    /// Takes a closure and applies that closure to each node's value in the tree.
+    ///
+    /// map() transforms one tree into another, by means of its argument: something that
+    /// implements FnMut. It produces a new tree which calls this closure on each node of
+    /// the original tree.
+    ///
+    /// If you are good at thinking in types, you can think of map() like this: If you
+    /// have a tree that has elements of some type A, and you want a tree of some other
+    /// type B, you can use map(), passing a closure that takes an A and returns a B.
+    ///
+    /// ### Example Usage
+    ///
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{Tree, BorrowedTreeNode}
+    /// };
+    ///
+    /// let mut tree = Tree {
+    ///     value: "0-0",
+    ///     children: vec![
+    ///         Tree {
+    ///             value: "1-1",
+    ///             children: vec![],
+    ///         },
+    ///         Tree {
+    ///             value: "2-2",
+    ///             children: vec![],
+    ///         }
+    ///     ],
+    /// };
+    ///
+    /// let result = tree.map_ref(|value: &&'static str| {
+    ///     value.split("-").nth(1).unwrap().to_string()
+    /// });
+    ///
+    /// assert_eq!(
+    ///     Tree {
+    ///         value: "0".to_string(),
+    ///         children: vec![
+    ///             Tree {
+    ///                 value: "1".to_string(),
+    ///                 children: vec![],
+    ///             },
+    ///             Tree {
+    ///                 value: "2".to_string(),
+    ///                 children: vec![],
+    ///             },
+    ///         ],
+    ///     },
+    ///     result);
+    /// ```
+    fn map_ref<Output, F>(&'a self, f: F) -> Tree<Output>
+    where
+        F: FnMut(Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().map_tree(f).collect_tree().unwrap()
+    }
+
+    /// Identical to [`map_ref`](BorrowedTreeNode::map_ref) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn map_path_ref<Output, F>(&'a self, f: F) -> Tree<Output>
+    where
+        F: FnMut(&[usize], Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().map_path(f).collect_tree().unwrap()
+    }
+
+    /// fold is a tree-based analog to [fold](core::iter::Iterator::fold).
+    ///
+    /// Folds every element into an accumulation by applying an operation, returning the
+    /// final result.
+    ///
+    /// fold() takes one argument: a closure with two arguments: the result of accumulating
+    /// all children of the current tree node, and an element. The closure returns the value
+    /// that the accumulator should have for the parent node's accumulation.
+    ///
+    /// After applying this closure to every node of the tree, fold() returns the accumulation.
+    ///
+    /// This operation is sometimes called ‘reduce’ or ‘inject’.
+    ///
+    /// Folding is useful whenever you have a tree of something, and want to produce a single
+    /// value from it.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let mut tree = create_example_tree();
+    /// let accumulation = tree.fold_ref(|child_accumulations: Vec<usize>, value| {
+    ///     child_accumulations
+    ///         .into_iter()
+    ///         .sum::<usize>()
+    ///     + *value
+    /// });
+    ///
+    /// assert_eq!(55, accumulation);
+    /// ```
+    fn fold_ref<Output, F>(&'a self, f: F) -> Output
+    where
+        F: FnMut(Vec<Output>, Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().fold_tree(f).unwrap()
+    }
+
+    /// Identical to [`fold_ref`](BorrowedTreeNode::fold_ref) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn fold_path_ref<Output, F>(&'a self, f: F) -> Output
+    where
+        F: FnMut(Vec<Output>, &[usize], Self::BorrowedValue) -> Output,
+    {
+        self.into_pipeline_ref().fold_path(f).unwrap()
+    }
+
+    /// Identical to [`canonicalize_by`](OwnedTreeNode::canonicalize_by) except that
+    /// the resulting tree holds references to the values of this tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, Tree}
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// let canonical = tree.canonicalize_by_ref(|left, right| right.cmp(left));
+    /// assert_eq!(
+    ///     vec![&0, &2, &6, &7, &8, &9, &10, &5, &1, &4, &3],
+    ///     canonical.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn canonicalize_by_ref<F>(&'a self, mut cmp: F) -> Tree<Self::BorrowedValue>
+    where
+        F: FnMut(&Self::BorrowedValue, &Self::BorrowedValue) -> Ordering,
+    {
+        let mut tree = crate::isomorphism::PostorderTree::new();
+        self.fold_ref(|children, value| tree.push(children, value));
+        crate::isomorphism::canonicalize(tree, &mut cmp)
+    }
+
+    /// Gets the number of nodes in this tree, including the root node.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(11, tree.size());
+    /// ```
+    fn size(&'a self) -> usize {
+        self.dfs_preorder_iter().count()
+    }
+
+    /// Gets the height of this tree. The height is the number of edges on the
+    /// longest path from the root down to a leaf, so a tree consisting of only
+    /// a root node has a height of zero.
+    ///
+    /// Ex. given a tree like the following, the height would be 6.
+    /// ```text
+    ///        0       <- depth: 0
+    ///       / \
+    ///      1   2     <- depth: 1
+    ///     / \ / \
+    ///    3  4 5  6   <- depth: 2
+    ///           /
+    ///          7     <- depth: 3
+    ///           \
+    ///            8   <- depth: 4
+    ///           /
+    ///          9     <- depth: 5
+    ///           \
+    ///           10   <- depth: 6
+    /// ```
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(6, tree.height());
+    /// ```
+    fn height(&'a self) -> usize {
+        crate::metrics::height(self.into_pipeline_ref())
+    }
+
+    /// Gets the number of leaves (nodes without any children) in this tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(4, tree.leaf_count());
+    /// ```
+    fn leaf_count(&'a self) -> usize {
+        self.dfs_preorder_iter().leaves().count()
+    }
+
+    /// Gets the largest number of nodes that are found at any single depth
+    /// of this tree.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// // depth 2 contains 3, 4, 5 and 6
+    /// assert_eq!(4, tree.max_width());
+    /// ```
+    fn max_width(&'a self) -> usize {
+        crate::metrics::widths(self.into_pipeline_ref())
+            .into_iter()
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Gets the number of nodes at the given depth of this tree. Depth is zero-based,
+    /// so the root node is considered to be at depth zero. Nodes deeper than the
+    /// requested depth are never visited.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(1, tree.width_at_depth(0));
+    /// assert_eq!(2, tree.width_at_depth(1));
+    /// assert_eq!(4, tree.width_at_depth(2));
+    /// assert_eq!(0, tree.width_at_depth(7));
+    /// ```
+    fn width_at_depth(&'a self, depth: usize) -> usize {
+        crate::metrics::width_at_depth(self.into_pipeline_ref(), depth)
+    }
+
+    /// Annotates every node in this tree with the [`Metrics`] of the subtree rooted at
+    /// that node. All of the metrics are calculated in a single depth first postorder
+    /// pass over the tree, so this is much cheaper than calling [`size`](BorrowedTreeNode::size),
+    /// [`height`](BorrowedTreeNode::height), etc. on every node.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, Metrics}
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// let annotated = tree.annotate_metrics_ref();
+    ///
+    /// assert_eq!(
+    ///     Metrics {
+    ///         size: 11,
+    ///         height: 6,
+    ///         leaf_count: 4,
+    ///         max_branching_factor: 2,
     ///     },
-    ///     result);
+    ///     annotated.value.1
+    /// );
+    ///
+    /// // the subtree rooted at 1
+    /// assert_eq!(3, annotated.children[0].value.1.size);
     /// ```
-    #[must_use]
-    fn into_pipeline_ref(
-        &'a self,
-    ) -> impl TreeIterator<Self::BorrowedValue, Self::BorrowedChildren> {
-        BorrowedDFSPreorderIteratorWithPathTracking::new(self, Vec::new())
+    fn annotate_metrics_ref(&'a self) -> Tree<(Self::BorrowedValue, Metrics)> {
+        self.fold_ref(
+            |children: Vec<Tree<(Self::BorrowedValue, Metrics)>>, value| Tree {
+                value: (
+                    value,
+                    Metrics::from_children(children.iter().map(|child| &child.value.1)),
+                ),
+                children,
+            },
+        )
     }
 
-    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
+    /// Computes a tidy layout of this tree for drawing, pairing each value with the
+    /// [`Position`] of its node. Nodes at the same depth are placed on the same
+    /// level, each parent is centered above its first and last children, and every
+    /// subtree is pushed as close to its left sibling as `options` allows, as in the
+    /// Reingold–Tilford algorithm. Identical subtrees are always drawn identically.
     ///
-    /// Uses the given closure to determine if each subtree in this tree should be pruned.
+    /// The width of each node, the height of every node and the gaps between nodes
+    /// are taken from `options` (see [`LayoutOptions`]). The result can be turned
+    /// into an SVG image with [`to_svg`](Tree::to_svg).
     ///
-    /// Given an element the closure must return true or false. Any nodes in the tree for
-    /// which this evaluates to true will be pruned out of the resulting tree. If the root
-    /// node is pruned, this will return [`None`].
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, LayoutOptions, Position}
+    /// };
     ///
-    /// The closure is called on the nodes in a depth first preorder traversal order (see
-    /// [`dfs_preorder`](crate::prelude::OwnedTreeNode::dfs_preorder) for more details). If a
-    /// node is determined to be pruned, its entire subtree will be pruned without calling the
-    /// closure on its descendent nodes.
+    /// let tree = create_example_tree();
+    /// let laid_out = tree.layout_ref(&LayoutOptions::new());
     ///
-    /// ### Basic usage:
+    /// // 1 is centered above 3 and 4
+    /// assert_eq!(Position { x: 50.0, y: 60.0 }, laid_out.children[0].value.1);
+    /// assert_eq!(Position { x: 20.0, y: 120.0 }, laid_out.children[0].children[0].value.1);
+    /// assert_eq!(Position { x: 80.0, y: 120.0 }, laid_out.children[0].children[1].value.1);
+    /// ```
+    fn layout_ref(
+        &'a self,
+        options: &LayoutOptions<'_, Self::BorrowedValue>,
+    ) -> Tree<(Self::BorrowedValue, Position)> {
+        let mut layout = crate::layout::Layout::new(options);
+        let root = self.fold_ref(|children, value| layout.node(children, value));
+        layout.finish(root)
+    }
+
+    /// Computes a Merkle-style hash for every subtree of this tree. Each node's hash is
+    /// computed in a depth first postorder pass from the node's value and the hashes of
+    /// its children, so two subtrees with the same structure and values will always have
+    /// the same hash.
     ///
+    /// Hashing is done using the given [`BuildHasher`]. Since equal hashes do not
+    /// guarantee equal subtrees, use [`find_duplicate_subtrees`](BorrowedTreeNode::find_duplicate_subtrees)
+    /// if you need to know which subtrees are actually duplicates of each other.
+    ///
+    /// ### Example Usage
     /// ```rust
+    /// use std::collections::hash_map::RandomState;
     /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
     ///
     /// let tree = Tree {
@@ -3174,272 +4505,478 @@ where
     ///     children: vec![
     ///         Tree {
     ///             value: 1,
-    ///             children: vec![Tree {
-    ///                 value: 3,
-    ///                 children: Vec::new(),
-    ///             }],
+    ///             children: vec![],
     ///         },
     ///         Tree {
-    ///             value: 2,
-    ///             children: Vec::new()
+    ///             value: 1,
+    ///             children: vec![],
     ///         },
     ///     ],
     /// };
     ///
-    /// assert_eq!(
-    ///     Some(
-    ///         Tree {
-    ///             value: &0,
-    ///             children: vec![
-    ///                 Tree {
-    ///                     value: &2,
-    ///                     children: Vec::new(),
-    ///                 }
-    ///             ],
-    ///         },
-    ///     ),
-    ///     tree.prune_ref(|value| {
-    ///         /// The output for this code would be the following. A couple notes about
-    ///         /// this output:
-    ///         /// 1. the node with a value of '1' has been removed
-    ///         /// 2. the closure is never called on the node with a value of '3' since
-    ///         ///    it is already determined to be pruned once '1' has been evaluated.
-    ///         /// ```
-    ///         /// 0
-    ///         /// 1
-    ///         /// 2
-    ///         /// ```
-    ///         println!("{value:?}");
-    ///         **value == 1
-    ///     })
-    /// );
+    /// let hashes = tree.subtree_hashes_ref(&RandomState::new());
+    /// assert_eq!(hashes.children[0].value, hashes.children[1].value);
+    /// assert_ne!(hashes.value, hashes.children[0].value);
     /// ```
-    fn prune_ref<F>(&'a self, f: F) -> Option<Tree<Self::BorrowedValue>>
+    fn subtree_hashes_ref<S>(&'a self, build_hasher: &S) -> Tree<u64>
     where
-        F: FnMut(&Self::BorrowedValue) -> bool,
+        S: BuildHasher,
+        Self::BorrowedValue: Hash,
     {
-        self.into_pipeline_ref().prune(f).collect_tree()
+        self.fold_ref(|children: Vec<Tree<u64>>, value| Tree {
+            value: crate::hashing::hash_node(
+                build_hasher,
+                &value,
+                children.iter().map(|child| Some(child.value)),
+            ),
+            children,
+        })
     }
 
-    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
-    ///
-    /// Uses the given closure to determine if each subtree in this tree should be pruned.
-    ///
-    /// Given the path of an element and the element's value, the closure must return true or
-    /// false. Any nodes in the tree for which this evaluates to true will be pruned out of
-    /// the resulting tree. If the root node is pruned, this will return [`None`].
+    /// Finds all groups of structurally equal subtrees in this tree. Two subtrees are
+    /// structurally equal if they have the same shape and equal values at every position.
     ///
-    /// The closure is called on the nodes in a depth first preorder traversal order (see
-    /// [`dfs_preorder`](crate::prelude::OwnedTreeNode::dfs_preorder) for more details). If a
-    /// node is determined to be pruned, its entire subtree will be pruned without calling the
-    /// closure on its descendent nodes.
+    /// Each group contains the paths (see [`current_path`](TreeIteratorBase::current_path)
+    /// for more details) of the roots of the equal subtrees. Subtrees that are not equal
+    /// to any other subtree are not included in the result. The groups, and the paths
+    /// within each group, are ordered by where they would appear in a depth first
+    /// preorder traversal.
     ///
-    /// ### Basic usage:
+    /// Subtrees are first bucketed by the hashes from
+    /// [`subtree_hashes_ref`](BorrowedTreeNode::subtree_hashes_ref) using the given
+    /// [`BuildHasher`], then compared to each other to rule out hash collisions.
     ///
+    /// ### Example Usage
     /// ```rust
+    /// use std::collections::hash_map::RandomState;
     /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
     ///
     /// let tree = Tree {
-    ///     value: 0,
+    ///     value: "config",
     ///     children: vec![
     ///         Tree {
-    ///             value: 1,
+    ///             value: "block",
     ///             children: vec![Tree {
-    ///                 value: 3,
-    ///                 children: Vec::new(),
+    ///                 value: "enabled",
+    ///                 children: vec![],
     ///             }],
     ///         },
     ///         Tree {
-    ///             value: 2,
-    ///             children: Vec::new()
+    ///             value: "block",
+    ///             children: vec![Tree {
+    ///                 value: "enabled",
+    ///                 children: vec![],
+    ///             }],
+    ///         },
+    ///         Tree {
+    ///             value: "block",
+    ///             children: vec![],
     ///         },
     ///     ],
     /// };
     ///
     /// assert_eq!(
-    ///     Some(
-    ///         Tree {
-    ///             value: &0,
-    ///             children: vec![
-    ///                 Tree {
-    ///                     value: &2,
-    ///                     children: Vec::new(),
-    ///                 }
-    ///             ],
-    ///         },
-    ///     ),
-    ///     tree.prune_path_ref(|path, value| {
-    ///         /// The output for this code would be the following. A couple notes about
-    ///         /// this output:
-    ///         /// 1. the node with a value of '1' has been removed
-    ///         /// 2. the closure is never called on the node with a value of '3' since
-    ///         ///    it is already determined to be pruned once '1' has been evaluated.
-    ///         /// ```
-    ///         /// 0
-    ///         /// 1
-    ///         /// 2
-    ///         /// ```
-    ///         println!("{value:?}");
-    ///         matches!(path.get(0), Some(0))
-    ///     })
+    ///     vec![
+    ///         vec![vec![0], vec![1]],
+    ///         vec![vec![0, 0], vec![1, 0]],
+    ///     ],
+    ///     tree.find_duplicate_subtrees(&RandomState::new())
     /// );
     /// ```
-    fn prune_path_ref<F>(&'a self, f: F) -> Option<Tree<Self::BorrowedValue>>
+    fn find_duplicate_subtrees<S>(&'a self, build_hasher: &S) -> Vec<Vec<Vec<usize>>>
     where
-        F: FnMut(&[usize], &Self::BorrowedValue) -> bool,
+        S: BuildHasher,
+        Self::BorrowedValue: Hash + PartialEq,
     {
-        self.into_pipeline_ref().prune_path(f).collect_tree()
-    }
-
-    /// Prune is a tree-based analog to [`filter`](core::iter::Iterator::filter).
+        let mut hashes_and_paths = Vec::new();
+        self.fold_path_ref(|children: Vec<u64>, path, value| {
+            let hash =
+                crate::hashing::hash_node(build_hasher, &value, children.into_iter().map(Some));
+            hashes_and_paths.push((hash, path.to_vec()));
+            hash
+        });
+
+        crate::hashing::group_duplicates(hashes_and_paths, |left, right| {
+            crate::hashing::pipelines_eq(
+                self.at_path_ref(left).unwrap().into_pipeline_ref(),
+                self.at_path_ref(right).unwrap().into_pipeline_ref(),
+            )
+        })
+    }
+
+    /// Checks whether this tree and `other` have the same shape and equal values at
+    /// every position according to `eq`. When `ordered` is `false`, the children of any
+    /// node are allowed to appear in a different order in the two trees.
+    ///
+    /// When `ordered` is `true`, this is done with a single iterative traversal of each
+    /// tree. When `ordered` is `false`, every subtree of both trees is given a canonical
+    /// id made from its value's class and the sorted ids of its children, and the trees
+    /// match if their roots get the same id. This takes O(n log n) time and no recursion,
+    /// plus the calls to `eq` used to sort values into classes. `eq` is only called on
+    /// nodes whose subtrees have the same shape, comparing each node against one node of
+    /// each class found so far, so it is called O(n * k) times for k distinct values.
+    /// Since values are grouped into classes, `eq` is expected to be an equivalence
+    /// relation when `ordered` is `false`.
     ///
-    /// Uses the depth of each subtree to determine if the subtree should be pruned.
-    /// Any node with a depth that is strictly greater than the max_depth parameter
-    /// will be pruned from the tree.
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
     ///
-    /// Depth is zero-based, so the root node is considered to be at depth zero.
+    /// let tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![],
+    ///         },
+    ///         Tree {
+    ///             value: 2,
+    ///             children: vec![],
+    ///         },
+    ///     ],
+    /// };
     ///
-    /// Ex. given a tree like the following, the depths would be as labeled.
+    /// let reordered = Tree {
+    ///     value: "0",
+    ///     children: vec![
+    ///         Tree {
+    ///             value: "2",
+    ///             children: vec![],
+    ///         },
+    ///         Tree {
+    ///             value: "1",
+    ///             children: vec![],
+    ///         },
+    ///     ],
+    /// };
     ///
-    /// ```text
-    ///        0       <- depth: 0
-    ///       / \
-    ///      1   2     <- depth: 1
-    ///     / \ / \
-    ///    3  4 5  6   <- depth: 2
-    ///           /
-    ///          7     <- depth: 3
-    ///           \
-    ///            8   <- depth: 4
-    ///           /
-    ///          9     <- depth: 5
-    ///           \
-    ///           10   <- depth: 6
+    /// let eq = |left: &&usize, right: &&&str| left.to_string() == **right;
+    /// assert!(!tree.is_isomorphic(&reordered, eq, true));
+    /// assert!(tree.is_isomorphic(&reordered, eq, false));
     /// ```
+    fn is_isomorphic<'b, Other, F>(&'a self, other: &'b Other, mut eq: F, ordered: bool) -> bool
+    where
+        Other: BorrowedTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Other::BorrowedValue) -> bool,
+    {
+        if ordered {
+            crate::isomorphism::pipelines_eq_by(
+                self.into_pipeline_ref(),
+                other.into_pipeline_ref(),
+                eq,
+            )
+        } else {
+            crate::isomorphism::unordered_eq(self, other, &mut eq)
+        }
+    }
+
+    /// Finds every subtree of this tree that is isomorphic to `pattern` (see
+    /// [`is_isomorphic`](BorrowedTreeNode::is_isomorphic) with `ordered` set to `true`).
+    /// Returns the path of the root of each matching subtree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details), in the order
+    /// they would be visited by a depth first preorder traversal.
     ///
-    /// ### Basic usage:
-    ///
+    /// ### Example Usage
     /// ```rust
-    /// use tree_iterators_rs::prelude::{Tree, BorrowedTreeNode};
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
     ///
-    /// let mut tree = Tree {
-    ///     value: 0,
+    /// let tree = Tree {
+    ///     value: "fn",
     ///     children: vec![
     ///         Tree {
-    ///             value: 1,
-    ///             children: vec![Tree {
-    ///                 value: 3,
-    ///                 children: vec![],
-    ///             }],
+    ///             value: "+",
+    ///             children: vec![
+    ///                 Tree { value: "a", children: vec![] },
+    ///                 Tree { value: "b", children: vec![] },
+    ///             ],
     ///         },
     ///         Tree {
-    ///             value: 2,
-    ///             children: vec![],
+    ///             value: "+",
+    ///             children: vec![
+    ///                 Tree { value: "b", children: vec![] },
+    ///                 Tree { value: "a", children: vec![] },
+    ///             ],
     ///         },
     ///     ],
     /// };
     ///
+    /// let pattern = Tree {
+    ///     value: "+",
+    ///     children: vec![
+    ///         Tree { value: "a", children: vec![] },
+    ///         Tree { value: "b", children: vec![] },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(vec![vec![0]], tree.find_subtree(&pattern, |a, b| a == b));
     /// assert_eq!(
-    ///     Tree {
-    ///         value: &0,
-    ///         children: vec![],
-    ///     },
-    ///     tree.prune_depth_ref(0)
+    ///     vec![vec![0], vec![1]],
+    ///     tree.find_subtree_unordered(&pattern, |a, b| a == b)
     /// );
     /// ```
-    fn prune_depth_ref(&'a self, max_depth: usize) -> Tree<Self::BorrowedValue> {
-        self.into_pipeline_ref()
-            .prune_depth(max_depth)
-            .collect_tree()
-            .unwrap()
+    fn find_subtree<'b, Pattern, F>(&'a self, pattern: &'b Pattern, eq: F) -> Vec<Vec<usize>>
+    where
+        Pattern: BorrowedTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+    {
+        crate::isomorphism::find_subtree(self, pattern, eq, true)
     }
 
-    /// map is a tree-based analog to [map](core::iter::Iterator::map).
+    /// Identical to [`find_subtree`](BorrowedTreeNode::find_subtree) except that the
+    /// children of any node are allowed to appear in any order (see
+    /// [`is_isomorphic`](BorrowedTreeNode::is_isomorphic) with `ordered` set to `false`).
+    fn find_subtree_unordered<'b, Pattern, F>(
+        &'a self,
+        pattern: &'b Pattern,
+        eq: F,
+    ) -> Vec<Vec<usize>>
+    where
+        Pattern: BorrowedTreeNode<'b>,
+        F: FnMut(&Self::BorrowedValue, &Pattern::BorrowedValue) -> bool,
+    {
+        crate::isomorphism::find_subtree(self, pattern, eq, false)
+    }
+
+    /// Checks whether this tree and `other` are equal when the order of each
+    /// node's children is ignored. This gives the same result as
+    /// [`is_isomorphic`](BorrowedTreeNode::is_isomorphic) with `==` as the
+    /// comparison and `ordered` set to `false`, but values are grouped by sorting
+    /// them, so this always takes O(n log n) time.
     ///
-    /// Takes a closure and applies that closure to each node's value in the tree.
+    /// Every subtree of both trees is given a canonical id made from its value and
+    /// the sorted ids of its children, as in
+    /// [`canonicalize_by`](OwnedTreeNode::canonicalize_by), and the trees are equal
+    /// if their roots get the same id. No recursion is used, so this works on
+    /// arbitrarily deep trees.
     ///
-    /// map() transforms one tree into another, by means of its argument: something that
-    /// implements FnMut. It produces a new tree which calls this closure on each node of
-    /// the original tree.
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
     ///
-    /// If you are good at thinking in types, you can think of map() like this: If you
-    /// have a tree that has elements of some type A, and you want a tree of some other
-    /// type B, you can use map(), passing a closure that takes an A and returns a B.
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let tree = Tree { value: 0, children: vec![leaf(1), leaf(2)] };
+    /// let reordered = Tree { value: 0, children: vec![leaf(2), leaf(1)] };
+    /// let different = Tree { value: 0, children: vec![leaf(2), leaf(2)] };
+    ///
+    /// assert!(tree.unordered_eq(&reordered));
+    /// assert!(!tree.unordered_eq(&different));
+    /// ```
+    fn unordered_eq<'b, Other>(&'a self, other: &'b Other) -> bool
+    where
+        Other: BorrowedTreeNode<'b>,
+        Self::BorrowedValue: Ord + PartialOrd<Other::BorrowedValue>,
+    {
+        crate::isomorphism::sorted_unordered_eq(self, other)
+    }
+
+    /// Computes a hash of this tree that ignores the order of each node's children.
+    /// Each node's hash is computed from its value and the sorted hashes of its
+    /// children, so trees that are equal according to
+    /// [`unordered_eq`](BorrowedTreeNode::unordered_eq) always have the same hash.
+    /// Like any hash, equal hashes do not guarantee equal trees.
+    ///
+    /// Hashing is done using the given [`BuildHasher`].
     ///
     /// ### Example Usage
+    /// ```rust
+    /// use std::collections::hash_map::RandomState;
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let tree = Tree { value: 0, children: vec![leaf(1), leaf(2)] };
+    /// let reordered = Tree { value: 0, children: vec![leaf(2), leaf(1)] };
+    ///
+    /// let build_hasher = RandomState::new();
+    /// assert_eq!(
+    ///     tree.unordered_hash(&build_hasher),
+    ///     reordered.unordered_hash(&build_hasher)
+    /// );
+    /// ```
+    fn unordered_hash<S>(&'a self, build_hasher: &S) -> u64
+    where
+        S: BuildHasher,
+        Self::BorrowedValue: Hash,
+    {
+        self.fold_ref(|mut children: Vec<u64>, value| {
+            children.sort_unstable();
+            crate::hashing::hash_node(build_hasher, &value, children.into_iter().map(Some))
+        })
+    }
+
+    /// Computes the AHU (Aho, Hopcroft and Ullman) encoding of the shape of this
+    /// tree. Each node is encoded as its children's encodings, sorted and wrapped
+    /// in parentheses, so a leaf is encoded as `()`. Values are ignored, and two
+    /// trees have the same encoding if and only if they have the same shape once
+    /// the order of each node's children is ignored.
     ///
+    /// ### Example Usage
     /// ```rust
     /// use tree_iterators_rs::{
-    ///     examples::create_example_binary_tree,
-    ///     prelude::{Tree, BorrowedTreeNode}
+    ///     examples::create_example_tree,
+    ///     prelude::BorrowedTreeNode
     /// };
     ///
-    /// let mut tree = Tree {
-    ///     value: "0-0",
+    /// let tree = create_example_tree();
+    /// assert_eq!("((((((()))))())(()()))", tree.ahu_encoding());
+    /// ```
+    fn ahu_encoding(&'a self) -> String {
+        let mut tree = crate::isomorphism::PostorderTree::new();
+        self.fold_ref(|children, _value| tree.push(children, ()));
+        crate::isomorphism::ahu_encoding(tree)
+    }
+
+    /// Computes the tree edit distance between this tree and `other`: the total
+    /// cost of the cheapest series of node insertions, deletions and renames that
+    /// turns this tree into `other`, with the costs given by `costs` (see
+    /// [`EditCosts`] for the operations and the default unit costs). Children stay
+    /// in order, so swapping two subtrees is not free.
+    ///
+    /// Along with the distance, the result holds the optimal mapping between the
+    /// nodes of the two trees, identified by their paths (see
+    /// [`current_path`](TreeIteratorBase::current_path)), and the paths of the
+    /// nodes that are deleted from this tree or inserted from `other`.
+    ///
+    /// This uses the Zhang–Shasha algorithm, which takes O(n * m) space and, in the
+    /// worst case, O(n² * m²) time for trees of n and m nodes.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, EditCosts, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let left = Tree {
+    ///     value: 'a',
     ///     children: vec![
-    ///         Tree {
-    ///             value: "1-1",
-    ///             children: vec![],
-    ///         },
-    ///         Tree {
-    ///             value: "2-2",
-    ///             children: vec![],
-    ///         }
+    ///         Tree { value: 'b', children: vec![leaf('c'), leaf('d')] },
+    ///         leaf('e'),
     ///     ],
     /// };
+    /// let right = Tree {
+    ///     value: 'a',
+    ///     children: vec![leaf('c'), leaf('d'), leaf('f')],
+    /// };
     ///
-    /// let result = tree.map_ref(|value: &&'static str| {
-    ///     value.split("-").nth(1).unwrap().to_string()
-    /// });
-    ///
+    /// // delete 'b' and rename 'e' to 'f'
+    /// let result = left.tree_edit_distance(&right, &EditCosts::new());
+    /// assert_eq!(2, result.distance);
+    /// assert_eq!(vec![vec![0]], result.deleted);
+    /// assert!(result.inserted.is_empty());
     /// assert_eq!(
-    ///     Tree {
-    ///         value: "0".to_string(),
-    ///         children: vec![
-    ///             Tree {
-    ///                 value: "1".to_string(),
-    ///                 children: vec![],
-    ///             },
-    ///             Tree {
-    ///                 value: "2".to_string(),
-    ///                 children: vec![],
-    ///             },
-    ///         ],
-    ///     },
-    ///     result);
+    ///     vec![
+    ///         (vec![], vec![]),
+    ///         (vec![0, 0], vec![0]),
+    ///         (vec![0, 1], vec![1]),
+    ///         (vec![1], vec![2]),
+    ///     ],
+    ///     result.mapping
+    /// );
     /// ```
-    fn map_ref<Output, F>(&'a self, f: F) -> Tree<Output>
+    fn tree_edit_distance<'b, Other>(
+        &'a self,
+        other: &'b Other,
+        costs: &EditCosts<'_, Self::BorrowedValue, Other::BorrowedValue>,
+    ) -> TreeEditDistance
     where
-        F: FnMut(Self::BorrowedValue) -> Output,
+        Other: BorrowedTreeNode<'b>,
     {
-        self.into_pipeline_ref().map_tree(f).collect_tree().unwrap()
+        crate::edit_distance::tree_edit_distance(
+            self,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_iter();
+                (value, children.into_iter().enumerate())
+            },
+            other,
+            |node: &'b Other| {
+                let (value, children) = node.get_value_and_children_iter();
+                (value, children.into_iter().enumerate())
+            },
+            costs,
+        )
     }
 
-    /// Identical to [`map_ref`](BorrowedTreeNode::map_ref) except that the closure is passed
-    /// an additional parameter: the path of the current node in the tree (see
-    /// [`current_path`](TreeIteratorBase::current_path) for more details).
-    fn map_path_ref<Output, F>(&'a self, f: F) -> Tree<Output>
+    /// Searches this tree for the first node whose value matches `predicate`,
+    /// visiting the nodes in the given [`SearchOrder`]. Returns the value of the
+    /// matching node along with its path (see [`current_path`](TreeIteratorBase::current_path)
+    /// for more details). No nodes are visited after the first match.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// let is_positive_even = |value: &&usize| **value > 0 && **value % 2 == 0;
+    /// assert_eq!(
+    ///     Some((&4, vec![0, 1])),
+    ///     tree.find_ref(SearchOrder::DepthFirst, is_positive_even)
+    /// );
+    /// assert_eq!(
+    ///     Some((&2, vec![1])),
+    ///     tree.find_ref(SearchOrder::BreadthFirst, is_positive_even)
+    /// );
+    /// assert_eq!(None, tree.find_ref(SearchOrder::DepthFirst, |value| **value > 10));
+    /// ```
+    fn find_ref<F>(
+        &'a self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<(Self::BorrowedValue, Vec<usize>)>
     where
-        F: FnMut(&[usize], Self::BorrowedValue) -> Output,
+        F: FnMut(&Self::BorrowedValue) -> bool,
     {
-        self.into_pipeline_ref().map_path(f).collect_tree().unwrap()
+        crate::search::search(
+            self,
+            order,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_iter();
+                (value, children.into_iter().enumerate())
+            },
+            |value| predicate(value),
+            |value, path| Some((value, path)),
+        )
     }
 
-    /// fold is a tree-based analog to [fold](core::iter::Iterator::fold).
-    ///
-    /// Folds every element into an accumulation by applying an operation, returning the
-    /// final result.
-    ///
-    /// fold() takes one argument: a closure with two arguments: the result of accumulating
-    /// all children of the current tree node, and an element. The closure returns the value
-    /// that the accumulator should have for the parent node's accumulation.
-    ///
-    /// After applying this closure to every node of the tree, fold() returns the accumulation.
+    /// Gets the path of every node whose value matches `predicate`, in the
+    /// order the given [`SearchOrder`] visits them.
     ///
-    /// This operation is sometimes called ‘reduce’ or ‘inject’.
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, SearchOrder}
+    /// };
     ///
-    /// Folding is useful whenever you have a tree of something, and want to produce a single
-    /// value from it.
+    /// let tree = create_example_tree();
+    /// assert_eq!(
+    ///     vec![vec![], vec![0, 1], vec![1, 1, 0, 0]],
+    ///     tree.find_all_paths(SearchOrder::DepthFirst, |value| **value % 4 == 0)
+    /// );
+    /// ```
+    fn find_all_paths<F>(&'a self, order: SearchOrder, mut predicate: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&Self::BorrowedValue) -> bool,
+    {
+        let mut paths = Vec::new();
+        crate::search::search::<_, _, _, ()>(
+            self,
+            order,
+            |node: &'a Self| {
+                let (value, children) = node.get_value_and_children_iter();
+                (value, children.into_iter().enumerate())
+            },
+            |value| predicate(value),
+            |_value, path| {
+                paths.push(path);
+                None
+            },
+        );
+        paths
+    }
+
+    /// Returns true if any node in this tree has a value equal to `value`.
+    /// The search stops at the first match.
     ///
     /// ### Example Usage
     /// ```rust
@@ -3448,31 +4985,63 @@ where
     ///     prelude::BorrowedTreeNode
     /// };
     ///
-    /// let mut tree = create_example_tree();
-    /// let accumulation = tree.fold_ref(|child_accumulations: Vec<usize>, value| {
-    ///     child_accumulations
-    ///         .into_iter()
-    ///         .sum::<usize>()
-    ///     + *value
-    /// });
-    ///
-    /// assert_eq!(55, accumulation);
+    /// let tree = create_example_tree();
+    /// assert!(tree.contains_value(&7));
+    /// assert!(!tree.contains_value(&11));
     /// ```
-    fn fold_ref<Output, F>(&'a self, f: F) -> Output
+    fn contains_value<V>(&'a self, value: &V) -> bool
     where
-        F: FnMut(Vec<Output>, Self::BorrowedValue) -> Output,
+        V: PartialEq + ?Sized,
+        Self::BorrowedValue: Borrow<V>,
     {
-        self.into_pipeline_ref().fold_tree(f).unwrap()
+        self.dfs_preorder_iter()
+            .any(|node_value| node_value.borrow() == value)
     }
 
-    /// Identical to [`fold_ref`](BorrowedTreeNode::fold_ref) except that the closure is passed
-    /// an additional parameter: the path of the current node in the tree (see
-    /// [`current_path`](TreeIteratorBase::current_path) for more details).
-    fn fold_path_ref<Output, F>(&'a self, f: F) -> Output
+    /// Searches this tree for the first node whose ancestor stack matches
+    /// `predicate`, visiting the nodes in the given [`SearchOrder`]. The
+    /// predicate is passed the values of every node from the root down to the
+    /// current node (see [`attach_ancestors`](crate::dfs_preorder_iterators::borrow::BorrowedDFSPreorderIterator::attach_ancestors)),
+    /// and the matching ancestor stack is returned. Searching breadth first uses
+    /// iterative deepening to track the ancestors, so it revisits the nodes
+    /// above the match.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::{BorrowedTreeNode, SearchOrder}
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(
+    ///     Some(vec![&0, &2, &6, &7]),
+    ///     tree.find_with_ancestors(SearchOrder::DepthFirst, |ancestors| {
+    ///         **ancestors.last().unwrap() == 7
+    ///     })
+    /// );
+    /// ```
+    fn find_with_ancestors<F>(
+        &'a self,
+        order: SearchOrder,
+        mut predicate: F,
+    ) -> Option<Vec<Self::BorrowedValue>>
     where
-        F: FnMut(Vec<Output>, &[usize], Self::BorrowedValue) -> Output,
+        F: FnMut(&[Self::BorrowedValue]) -> bool,
+        Self::BorrowedValue: Clone,
     {
-        self.into_pipeline_ref().fold_path(f).unwrap()
+        match order {
+            SearchOrder::DepthFirst => streaming_iterator::StreamingIterator::find(
+                &mut self.dfs_preorder_iter().attach_ancestors(),
+                |ancestors| predicate(ancestors),
+            )
+            .map(<[_]>::to_vec),
+            SearchOrder::BreadthFirst => streaming_iterator::StreamingIterator::find(
+                &mut self.bfs_iter().attach_ancestors(),
+                |ancestors| predicate(ancestors),
+            )
+            .map(<[_]>::to_vec),
+        }
     }
 }
 
@@ -3847,6 +5416,160 @@ where
     {
         self.into_pipeline().fold_path(f)
     }
+
+    /// Merges this forest with `other`. Any two trees at the same level (two
+    /// roots, or two children of merged nodes) whose values produce equal keys
+    /// are unified into a single tree: their values are combined with `merge`
+    /// and their children are concatenated and merged the same way, recursively.
+    /// Trees keep the position of the first tree with their key, with the trees
+    /// of this forest coming before those of `other`.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{OwnedIntoIteratorOfTrees, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let left = vec![Tree {
+    ///     value: ("src", 1),
+    ///     children: vec![leaf(("lib.rs", 1)), leaf(("main.rs", 1))],
+    /// }];
+    /// let right = vec![
+    ///     Tree {
+    ///         value: ("src", 1),
+    ///         children: vec![leaf(("lib.rs", 1)), leaf(("forest.rs", 1))],
+    ///     },
+    ///     leaf(("Cargo.toml", 1)),
+    /// ];
+    ///
+    /// let merged = left.merge_forests_by_key(
+    ///     right,
+    ///     |(name, _)| *name,
+    ///     |(name, left), (_, right)| (name, left + right),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Tree {
+    ///             value: ("src", 2),
+    ///             children: vec![
+    ///                 leaf(("lib.rs", 2)),
+    ///                 leaf(("main.rs", 1)),
+    ///                 leaf(("forest.rs", 1)),
+    ///             ],
+    ///         },
+    ///         leaf(("Cargo.toml", 1)),
+    ///     ],
+    ///     merged
+    /// );
+    /// ```
+    fn merge_forests_by_key<Other, Key, KeyFn, MergeFn>(
+        self,
+        other: Other,
+        mut key: KeyFn,
+        mut merge: MergeFn,
+    ) -> Vec<Tree<T::OwnedValue>>
+    where
+        Other: IntoIterator,
+        Other::Item: OwnedTreeNode<OwnedValue = T::OwnedValue>,
+        Key: Ord,
+        KeyFn: FnMut(&T::OwnedValue) -> Key,
+        MergeFn: FnMut(T::OwnedValue, T::OwnedValue) -> T::OwnedValue,
+    {
+        let mut trees = self.map_each(|value| value).collect::<Vec<_>>();
+        trees.extend(other.into_iter().map(|tree| tree.map(|value| value)));
+        crate::forest::merge_by_key(trees, &mut key, &mut merge)
+    }
+
+    /// Nests the trees of this forest under one another. Each tree is appended
+    /// to the children of the first tree whose `key` matches the key returned by
+    /// `parent_of` for its root value, in the order the trees appear in the
+    /// forest. Trees without a parent key, or whose parent key doesn't match any
+    /// tree, are returned as the roots of the resulting forest. If the parent
+    /// keys form a cycle, the cycle is broken by making the first tree in it a
+    /// root.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{OwnedIntoIteratorOfTrees, OwnedTreeNode, Tree};
+    ///
+    /// // (id, parent id)
+    /// let rows = vec![(3, Some(1)), (1, None), (2, Some(1)), (4, Some(2))];
+    /// let forest = rows
+    ///     .into_iter()
+    ///     .map(|value| Tree { value, children: Vec::new() })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let tree = forest
+    ///     .group_into_tree(|(id, _)| *id, |(_, parent)| *parent)
+    ///     .pop()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     vec![1, 3, 2, 4],
+    ///     tree.dfs_preorder().map(|(id, _)| id).collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn group_into_tree<Key, KeyFn, ParentFn>(
+        self,
+        key: KeyFn,
+        parent_of: ParentFn,
+    ) -> Vec<Tree<T::OwnedValue>>
+    where
+        Key: Ord,
+        KeyFn: FnMut(&T::OwnedValue) -> Key,
+        ParentFn: FnMut(&T::OwnedValue) -> Option<Key>,
+    {
+        crate::forest::group_into_tree(self.map_each(|value| value).collect(), key, parent_of)
+    }
+
+    /// Flattens this forest into a list of every node's value in depth first
+    /// preorder. Each value is paired with the index of its parent within the
+    /// returned list, or `None` for the root of each tree. This is the inverse
+    /// of [`group_into_tree`](OwnedIntoIteratorOfTrees::group_into_tree) when the
+    /// indexes are used as keys.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{OwnedIntoIteratorOfTrees, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let forest = vec![
+    ///     Tree { value: 'a', children: vec![leaf('b'), leaf('c')] },
+    ///     leaf('d'),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     vec![(None, 'a'), (Some(0), 'b'), (Some(0), 'c'), (None, 'd')],
+    ///     forest.flatten_forest()
+    /// );
+    /// ```
+    fn flatten_forest(self) -> Vec<(Option<usize>, T::OwnedValue)> {
+        crate::forest::flatten(self)
+    }
+
+    /// Gathers the trees of this forest under a virtual root node holding
+    /// `root_value`, so that the forest can be used with any of the
+    /// [`OwnedTreeNode`] APIs. Paths into the resulting tree are the same as the
+    /// paths used by [`at_path`](OwnedIntoIteratorOfTrees::at_path) on the forest.
+    ///
+    /// This takes O(1) time. Nothing is copied: the trees of the forest are only
+    /// taken apart as the resulting tree is traversed. To get a [`Tree`] instead,
+    /// call [`map`](OwnedTreeNode::map) with the identity function, which rebuilds
+    /// the whole forest in O(n) time.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{OwnedIntoIteratorOfTrees, OwnedTreeNode, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let forest = vec![Tree { value: 1, children: vec![leaf(2)] }, leaf(3)];
+    ///
+    /// let tree = forest.as_single_tree(0);
+    /// assert_eq!(vec![0, 1, 3, 2], tree.bfs().collect::<Vec<_>>());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_single_tree(self, root_value: T::OwnedValue) -> OwnedSingleTree<Self::IntoIter, T> {
+        OwnedSingleTree::new(root_value, self.into_iter())
+    }
 }
 
 pub trait OwnedIntoIteratorOfBinaryTrees<T>: IntoIterator<Item = T> + Sized
@@ -4399,6 +6122,32 @@ where
     {
         self.into_pipeline_mut().fold_path(f)
     }
+
+    /// Identical to [`as_single_tree`](OwnedIntoIteratorOfTrees::as_single_tree)
+    /// except that it mutably borrows the forest, so the resulting tree yields
+    /// mutable references to its values.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{MutBorrowedIntoIteratorOfTrees, OwnedTreeNode, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let mut forest = vec![Tree { value: 1, children: vec![leaf(2)] }, leaf(3)];
+    ///
+    /// let mut root = 0;
+    /// for value in forest.as_single_tree_mut(&mut root).dfs_preorder() {
+    ///     *value += 10;
+    /// }
+    /// assert_eq!(10, root);
+    /// assert_eq!(vec![leaf(12)], forest[0].children);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_single_tree_mut(
+        self,
+        root_value: T::MutBorrowedValue,
+    ) -> MutBorrowedSingleTree<'a, Self::IntoIter, T> {
+        MutBorrowedSingleTree::new(root_value, self.into_iter())
+    }
 }
 
 pub trait MutBorrowedIntoIteratorOfBinaryTrees<'a, T>:
@@ -4953,6 +6702,28 @@ where
     {
         self.into_pipeline_ref().fold_path(f)
     }
+
+    /// Identical to [`as_single_tree`](OwnedIntoIteratorOfTrees::as_single_tree)
+    /// except that it borrows the forest, so the resulting tree yields references
+    /// to its values.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BorrowedIntoIteratorOfTrees, OwnedTreeNode, Tree};
+    ///
+    /// let leaf = |value| Tree { value, children: Vec::new() };
+    /// let forest = vec![Tree { value: 1, children: vec![leaf(2)] }, leaf(3)];
+    ///
+    /// let tree = forest.as_single_tree_ref(&0);
+    /// assert_eq!(vec![&0, &1, &2, &3], tree.dfs_preorder().collect::<Vec<_>>());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_single_tree_ref(
+        self,
+        root_value: T::BorrowedValue,
+    ) -> BorrowedSingleTree<'a, Self::IntoIter, T> {
+        BorrowedSingleTree::new(root_value, self.into_iter())
+    }
 }
 
 pub trait BorrowedIntoIteratorOfBinaryTrees<'a, T>: IntoIterator<Item = &'a T> + Sized
@@ -5280,6 +7051,6 @@ impl<'a, T> BorrowedIntoIteratorOfBinaryTrees<'a, T> for Iter<'a, T> where
 {
 }
 
-fn opt_to_opt<T>(opt: Option<T>) -> Option<T> {
+pub(crate) fn opt_to_opt<T>(opt: Option<T>) -> Option<T> {
     opt
 }
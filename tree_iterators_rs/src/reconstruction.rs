@@ -0,0 +1,328 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+use streaming_iterator::StreamingIterator;
+
+use crate::prelude::{BinaryTree, BorrowedBinaryTreeNode};
+
+/// The reasons a [`BinaryTree`] could not be reconstructed from a set of
+/// serialized traversals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconstructionError {
+    /// The input did not contain a root node. Since a [`BinaryTree`] always
+    /// has a root, empty traversals (or a level order starting with a null)
+    /// cannot be reconstructed.
+    MissingRoot,
+    /// The two traversals are not the same length.
+    LengthMismatch,
+    /// A value appears more than once in the in order traversal, so the
+    /// traversals could describe more than one tree.
+    DuplicateValue,
+    /// The traversals do not describe the same tree.
+    InconsistentTraversals,
+    /// A level order entry has no empty child slot left to fill.
+    UnattachedNode,
+}
+
+impl Display for ReconstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::MissingRoot => "the traversal does not contain a root node",
+            Self::LengthMismatch => "the traversals are not the same length",
+            Self::DuplicateValue => "the in order traversal contains duplicate values",
+            Self::InconsistentTraversals => "the traversals do not describe the same tree",
+            Self::UnattachedNode => "a level order entry has no parent to attach to",
+        };
+        f.write_str(message)
+    }
+}
+
+impl core::error::Error for ReconstructionError {}
+
+impl<T> BinaryTree<T>
+where
+    T: Ord,
+{
+    /// Reconstructs a [`BinaryTree`] from its preorder and in order traversals
+    /// (see [`dfs_preorder`](crate::prelude::OwnedBinaryTreeNode::dfs_preorder) and
+    /// [`dfs_inorder`](crate::prelude::OwnedBinaryTreeNode::dfs_inorder)). The
+    /// values of the resulting tree are taken from `preorder`.
+    ///
+    /// The values in the tree must be unique, since otherwise the traversals can
+    /// describe more than one tree. This works on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BinaryTree, BorrowedBinaryTreeNode, ReconstructionError},
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// let preorder = tree.dfs_preorder_iter().copied().collect::<Vec<_>>();
+    /// let inorder = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(Ok(tree), BinaryTree::from_preorder_inorder(preorder, &inorder));
+    ///
+    /// // 3 is left of 1 in the in order traversal, so it must come before 2
+    /// // (which is right of 1) in the preorder traversal.
+    /// assert_eq!(
+    ///     Err(ReconstructionError::InconsistentTraversals),
+    ///     BinaryTree::from_preorder_inorder([1, 2, 3], &[3, 1, 2])
+    /// );
+    /// ```
+    pub fn from_preorder_inorder<I>(preorder: I, inorder: &[T]) -> Result<Self, ReconstructionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        reconstruct(preorder.into_iter().collect(), inorder, false)
+    }
+
+    /// Reconstructs a [`BinaryTree`] from its postorder and in order traversals
+    /// (see [`dfs_postorder`](crate::prelude::OwnedBinaryTreeNode::dfs_postorder)
+    /// and [`dfs_inorder`](crate::prelude::OwnedBinaryTreeNode::dfs_inorder)). The
+    /// values of the resulting tree are taken from `postorder`.
+    ///
+    /// The values in the tree must be unique, since otherwise the traversals can
+    /// describe more than one tree. This works on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::{BinaryTree, BorrowedBinaryTreeNode, ReconstructionError},
+    /// };
+    ///
+    /// let tree = create_example_binary_tree();
+    /// let postorder = tree.dfs_postorder_iter().copied().collect::<Vec<_>>();
+    /// let inorder = tree.dfs_inorder_iter().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(Ok(tree), BinaryTree::from_postorder_inorder(postorder, &inorder));
+    /// assert_eq!(
+    ///     Err(ReconstructionError::LengthMismatch),
+    ///     BinaryTree::from_postorder_inorder([0, 1], &[0])
+    /// );
+    /// ```
+    pub fn from_postorder_inorder<I>(
+        postorder: I,
+        inorder: &[T],
+    ) -> Result<Self, ReconstructionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // a reversed postorder traversal visits the root, then the right
+        // subtree, then the left subtree, which is a preorder traversal of
+        // the mirror image of the tree.
+        let mut values = postorder.into_iter().collect::<Vec<_>>();
+        values.reverse();
+        reconstruct(values, inorder, true)
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// Reconstructs a [`BinaryTree`] from a level order list of values where
+    /// empty child slots are represented by [`None`]. This is the format
+    /// commonly used by LeetCode, where `[1, null, 2]` describes a root of 1
+    /// with a right child of 2. Trailing [`None`]s may be omitted.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{BinaryTree, ReconstructionError};
+    ///
+    /// let tree = BinaryTree::from_level_order_with_nulls([Some(1), None, Some(2)]).unwrap();
+    /// assert_eq!(
+    ///     BinaryTree {
+    ///         value: 1,
+    ///         left: None,
+    ///         right: Some(Box::new(BinaryTree {
+    ///             value: 2,
+    ///             left: None,
+    ///             right: None,
+    ///         })),
+    ///     },
+    ///     tree
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Err(ReconstructionError::UnattachedNode),
+    ///     BinaryTree::from_level_order_with_nulls([Some(1), None, None, Some(2)])
+    /// );
+    /// ```
+    pub fn from_level_order_with_nulls<I>(level_order: I) -> Result<Self, ReconstructionError>
+    where
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let mut level_order = level_order.into_iter();
+        let root = level_order
+            .next()
+            .flatten()
+            .ok_or(ReconstructionError::MissingRoot)?;
+
+        let mut values = vec![root];
+        let mut children: Vec<[Option<usize>; 2]> = vec![[None, None]];
+        // each node has two slots to fill, in level order.
+        let mut slots = (0..).flat_map(|node| [(node, 0), (node, 1)]);
+        for value in level_order {
+            let (parent, slot) = slots
+                .next()
+                .filter(|(parent, _)| *parent < values.len())
+                .ok_or(ReconstructionError::UnattachedNode)?;
+
+            if let Some(value) = value {
+                children[parent][slot] = Some(values.len());
+                values.push(value);
+                children.push([None, None]);
+            }
+        }
+
+        Ok(assemble(values, &children, false))
+    }
+
+    /// Serializes this tree into a level order list of values where empty child
+    /// slots are represented by [`None`], omitting any trailing [`None`]s. This is
+    /// the inverse of [`from_level_order_with_nulls`](BinaryTree::from_level_order_with_nulls).
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_binary_tree,
+    ///     prelude::BinaryTree,
+    /// };
+    ///
+    /// let tree = BinaryTree {
+    ///     value: 1,
+    ///     left: None,
+    ///     right: Some(Box::new(BinaryTree {
+    ///         value: 2,
+    ///         left: None,
+    ///         right: None,
+    ///     })),
+    /// };
+    /// assert_eq!(vec![Some(&1), None, Some(&2)], tree.to_level_order_with_nulls());
+    ///
+    /// let tree = create_example_binary_tree();
+    /// let round_trip = BinaryTree::from_level_order_with_nulls(tree.to_level_order_with_nulls());
+    /// assert_eq!(Ok(tree.clone()), round_trip.map(|tree| tree.cloned()));
+    /// ```
+    pub fn to_level_order_with_nulls(&self) -> Vec<Option<&T>> {
+        let mut result = vec![Some(&self.value)];
+        let mut iter = self.bfs_iter().attach_context();
+        while let Some(context) = iter.next() {
+            result.extend(
+                context
+                    .children()
+                    .iter()
+                    .map(|child| child.map(|child| &child.value)),
+            );
+        }
+
+        while let Some(None) = result.last() {
+            result.pop();
+        }
+        result
+    }
+}
+
+/// Reconstructs a tree from a traversal in which every node comes before its
+/// descendants (a preorder traversal, or the reversed postorder traversal of the
+/// mirrored tree) and the tree's in order traversal.
+fn reconstruct<T>(
+    values: Vec<T>,
+    inorder: &[T],
+    mirrored: bool,
+) -> Result<BinaryTree<T>, ReconstructionError>
+where
+    T: Ord,
+{
+    if values.len() != inorder.len() {
+        return Err(ReconstructionError::LengthMismatch);
+    }
+
+    if values.is_empty() {
+        return Err(ReconstructionError::MissingRoot);
+    }
+
+    let mut positions = BTreeMap::new();
+    for (position, value) in inorder.iter().enumerate() {
+        if positions.insert(value, position).is_some() {
+            return Err(ReconstructionError::DuplicateValue);
+        }
+    }
+
+    // Each value's in order position acts as its key in a binary search tree,
+    // so the preorder traversal can be turned back into a tree using the
+    // standard stack-based BST preorder algorithm. This also verifies that
+    // every position is used exactly once and that the keys are a valid BST
+    // preorder traversal, which together guarantee the traversals agree.
+    let len = values.len();
+    let mut seen = vec![false; len];
+    let mut children: Vec<[Option<usize>; 2]> = vec![[None, None]; len];
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    let mut lower_bound = None;
+    for (index, value) in values.iter().enumerate() {
+        let position = *positions
+            .get(value)
+            .ok_or(ReconstructionError::InconsistentTraversals)?;
+        if core::mem::replace(&mut seen[position], true) {
+            return Err(ReconstructionError::InconsistentTraversals);
+        }
+
+        let key = if mirrored {
+            len - 1 - position
+        } else {
+            position
+        };
+        if lower_bound.is_some_and(|lower_bound| key < lower_bound) {
+            return Err(ReconstructionError::InconsistentTraversals);
+        }
+
+        match stack.last() {
+            Some(&(parent, parent_key)) if key < parent_key => {
+                children[parent][0] = Some(index);
+            }
+            _ => {
+                let mut parent = None;
+                while let Some(&(top, top_key)) = stack.last() {
+                    if top_key > key {
+                        break;
+                    }
+
+                    parent = Some(top);
+                    lower_bound = Some(top_key);
+                    stack.pop();
+                }
+
+                if let Some(parent) = parent {
+                    children[parent][1] = Some(index);
+                }
+            }
+        }
+
+        stack.push((index, key));
+    }
+
+    Ok(assemble(values, &children, mirrored))
+}
+
+/// Builds a tree out of `values` and the child indexes of each value. Every
+/// value's children must come after it in `values`, and the first value is the
+/// root. If `mirrored` is true, left and right children are swapped.
+pub(crate) fn assemble<T>(
+    mut values: Vec<T>,
+    children: &[[Option<usize>; 2]],
+    mirrored: bool,
+) -> BinaryTree<T> {
+    let mut nodes: Vec<Option<Box<BinaryTree<T>>>> = (0..values.len()).map(|_| None).collect();
+    for index in (0..values.len()).rev() {
+        let value = values.pop().expect("there to be one value per node");
+        let [mut left, mut right] =
+            children[index].map(|child| child.and_then(|child| nodes[child].take()));
+        if mirrored {
+            core::mem::swap(&mut left, &mut right);
+        }
+        nodes[index] = Some(Box::new(BinaryTree { value, left, right }));
+    }
+
+    let root = nodes[0].take().expect("the root to have been built");
+    *root
+}
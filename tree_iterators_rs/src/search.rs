@@ -0,0 +1,152 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+/// The order in which the search methods such as
+/// [`find_ref`](crate::prelude::BorrowedTreeNode::find_ref) visit the nodes of a
+/// tree. Either way, the search stops as soon as it has its answer, so nodes
+/// after the first match are never visited.
+///
+/// Ex. given a tree like the following, searching for an odd number greater
+/// than 2 finds 3 when searching depth first and 5 when searching breadth first,
+/// since 3 comes first in document order but 5 is closer to the root.
+/// ```text
+///        0
+///       / \
+///      2   5
+///     /
+///    3
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchOrder {
+    /// Visit the nodes in depth first preorder, so the first match is the first
+    /// one in document order.
+    DepthFirst,
+    /// Visit the nodes level by level, so the first match is the shallowest one.
+    /// Matches at the same depth are found from left to right.
+    BreadthFirst,
+}
+
+/// Visits the nodes of a tree in the given order, passing each node that
+/// matches `is_match` to `on_match` along with its path, until `on_match`
+/// returns `Some`. `get_value_and_children` yields each child along with the
+/// index it contributes to the child's path.
+pub(crate) fn search<Node, Value, Children, Output>(
+    root: Node,
+    order: SearchOrder,
+    get_value_and_children: impl FnMut(Node) -> (Value, Children),
+    is_match: impl FnMut(&Value) -> bool,
+    on_match: impl FnMut(Value, Vec<usize>) -> Option<Output>,
+) -> Option<Output>
+where
+    Children: IntoIterator<Item = (usize, Node)>,
+{
+    match order {
+        SearchOrder::DepthFirst => {
+            depth_first_search(root, get_value_and_children, is_match, on_match)
+        }
+        SearchOrder::BreadthFirst => {
+            breadth_first_search(root, get_value_and_children, is_match, on_match)
+        }
+    }
+}
+
+/// Searches depth first, keeping the path of the current node up to date as
+/// the search moves through the tree.
+fn depth_first_search<Node, Value, Children, Output>(
+    root: Node,
+    mut get_value_and_children: impl FnMut(Node) -> (Value, Children),
+    mut is_match: impl FnMut(&Value) -> bool,
+    mut on_match: impl FnMut(Value, Vec<usize>) -> Option<Output>,
+) -> Option<Output>
+where
+    Children: IntoIterator<Item = (usize, Node)>,
+{
+    let (value, children) = get_value_and_children(root);
+    if is_match(&value) {
+        let output = on_match(value, Vec::new());
+        if output.is_some() {
+            return output;
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut stack = vec![children.into_iter()];
+    loop {
+        let next = stack.last_mut()?.next();
+        match next {
+            Some((index, child)) => {
+                path.push(index);
+                let (value, children) = get_value_and_children(child);
+                if is_match(&value) {
+                    let output = on_match(value, path.clone());
+                    if output.is_some() {
+                        return output;
+                    }
+                }
+                stack.push(children.into_iter());
+            }
+            None => {
+                stack.pop();
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Searches breadth first. Rather than keeping the path of every node in the
+/// queue, each visited node remembers where its parent was visited, so a
+/// node's path is only built if it matches.
+fn breadth_first_search<Node, Value, Children, Output>(
+    root: Node,
+    mut get_value_and_children: impl FnMut(Node) -> (Value, Children),
+    mut is_match: impl FnMut(&Value) -> bool,
+    mut on_match: impl FnMut(Value, Vec<usize>) -> Option<Output>,
+) -> Option<Output>
+where
+    Children: IntoIterator<Item = (usize, Node)>,
+{
+    // the visit number of each node's parent and the node's index within it.
+    let mut visited: Vec<Option<(usize, usize)>> = Vec::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((root, None));
+
+    loop {
+        let (node, parent) = queue.pop_front()?;
+        let visit_number = visited.len();
+        visited.push(parent);
+
+        let (value, children) = get_value_and_children(node);
+        if is_match(&value) {
+            let output = on_match(value, build_path(&visited, visit_number));
+            if output.is_some() {
+                return output;
+            }
+        }
+
+        queue.extend(
+            children
+                .into_iter()
+                .map(|(index, child)| (child, Some((visit_number, index)))),
+        );
+    }
+}
+
+/// Builds the path of a node by following its parents back to the root.
+fn build_path(visited: &[Option<(usize, usize)>], mut visit_number: usize) -> Vec<usize> {
+    let mut path = Vec::new();
+    while let Some((parent, index)) = visited[visit_number] {
+        path.push(index);
+        visit_number = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Pairs each non-empty child slot of a binary node with its slot index.
+pub(crate) fn binary_slots<Node>(
+    children: [Option<Node>; 2],
+) -> impl Iterator<Item = (usize, Node)> {
+    children
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, child)| Some((index, child?)))
+}
@@ -0,0 +1,379 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use streaming_iterator::StreamingIterator;
+
+use crate::{
+    dfs_preorder_iterators::borrow::{
+        BorrowedBinaryDFSPreorderIteratorWithContext, BorrowedDFSPreorderIteratorWithContext,
+    },
+    prelude::{BorrowedBinaryTreeNode, BorrowedTreeNode, TreeContext},
+};
+
+/// A CSS-selector-like pattern that can be used to find nodes in a tree based on
+/// their values and the values of their ancestors.
+///
+/// A selector is made up of a series of steps. Each step is added with either
+/// [`child`](Selector::child) or [`descendant`](Selector::descendant) and further
+/// refined with [`filter`](Selector::filter), [`nth_child`](Selector::nth_child) and
+/// [`parent_matches`](Selector::parent_matches). A node matches the selector if the
+/// node matches the last step and its ancestors match the rest of the steps.
+///
+/// Selectors are evaluated relative to a virtual document node sitting above the root
+/// of the tree, so `Selector::new().child(..)` can only match the root node, while
+/// `Selector::new().descendant(..)` can match any node in the tree.
+///
+/// | CSS       | Selector                                                                   |
+/// | --------- | -------------------------------------------------------------------------- |
+/// | `a`       | `Selector::new().descendant(is_a)`                                         |
+/// | `a > b`   | `Selector::new().descendant(is_a).child(is_b)`                             |
+/// | `a b`     | `Selector::new().descendant(is_a).descendant(is_b)`                        |
+/// | `:root`   | `Selector::new().child(\|_\| true)`                                         |
+/// | `a > *:nth-child(2)` | `Selector::new().descendant(is_a).child(\|_\| true).nth_child(1)` |
+///
+/// ### Example Usage
+/// ```rust
+/// use tree_iterators_rs::prelude::{Selector, Tree};
+///
+/// let tree = Tree {
+///     value: "html",
+///     children: vec![
+///         Tree {
+///             value: "body",
+///             children: vec![
+///                 Tree {
+///                     value: "div",
+///                     children: vec![Tree {
+///                         value: "p",
+///                         children: vec![],
+///                     }],
+///                 },
+///                 Tree {
+///                     value: "p",
+///                     children: vec![],
+///                 },
+///             ],
+///         },
+///     ],
+/// };
+///
+/// // equivalent to the CSS selector "body > p"
+/// let selector = Selector::new()
+///     .descendant(|value: &&&str| **value == "body")
+///     .child(|value| **value == "p");
+///
+/// assert_eq!(
+///     vec![(vec![0, 1], &"p")],
+///     selector.select(&tree).collect::<Vec<_>>()
+/// );
+///
+/// // equivalent to the CSS selector "body p"
+/// let selector = Selector::new()
+///     .descendant(|value: &&&str| **value == "body")
+///     .descendant(|value| **value == "p");
+///
+/// assert_eq!(
+///     vec![(vec![0, 0, 0], &"p"), (vec![0, 1], &"p")],
+///     selector.select(&tree).collect::<Vec<_>>()
+/// );
+/// ```
+pub struct Selector<'f, Value> {
+    steps: Vec<Step<'f, Value>>,
+}
+
+enum Combinator {
+    Child,
+    Descendant,
+}
+
+struct Step<'f, Value> {
+    combinator: Combinator,
+    predicates: Vec<Box<dyn Fn(&Value) -> bool + 'f>>,
+    parent_predicates: Vec<Box<dyn Fn(&Value) -> bool + 'f>>,
+    nth_child: Option<usize>,
+}
+
+impl<'f, Value> Step<'f, Value> {
+    /// Checks if the node at the given index of the ancestors stack matches this step.
+    fn matches(&self, index: usize, ancestors: &[Value], path: &[usize]) -> bool {
+        if let Some(nth_child) = self.nth_child {
+            if index == 0 || path[index - 1] != nth_child {
+                return false;
+            }
+        }
+
+        if !self.parent_predicates.is_empty() {
+            if index == 0 {
+                return false;
+            }
+
+            let parent = &ancestors[index - 1];
+            if !self.parent_predicates.iter().all(|pred| pred(parent)) {
+                return false;
+            }
+        }
+
+        self.predicates.iter().all(|pred| pred(&ancestors[index]))
+    }
+}
+
+impl<Value> Default for Selector<'_, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'f, Value> Selector<'f, Value> {
+    /// Creates a new selector without any steps. A selector without any steps
+    /// does not match any nodes.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Adds a step that matches a node whose value satisfies the given predicate and
+    /// whose parent matched the previous step. If this is the first step, it can only
+    /// match the root of the tree. This is the equivalent of the `>` combinator in CSS.
+    #[must_use]
+    pub fn child<F>(self, f: F) -> Self
+    where
+        F: Fn(&Value) -> bool + 'f,
+    {
+        self.push_step(Combinator::Child, f)
+    }
+
+    /// Adds a step that matches a node whose value satisfies the given predicate and
+    /// that has an ancestor that matched the previous step. If this is the first step,
+    /// it can match any node in the tree. This is the equivalent of the ` ` (descendant)
+    /// combinator in CSS.
+    #[must_use]
+    pub fn descendant<F>(self, f: F) -> Self
+    where
+        F: Fn(&Value) -> bool + 'f,
+    {
+        self.push_step(Combinator::Descendant, f)
+    }
+
+    /// Further restricts the most recently added step so that it only matches
+    /// nodes whose value also satisfies the given predicate.
+    ///
+    /// ### Panics
+    /// Panics if no steps have been added to the selector yet.
+    #[must_use]
+    pub fn filter<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Value) -> bool + 'f,
+    {
+        self.last_step("filter").predicates.push(Box::new(f));
+        self
+    }
+
+    /// Further restricts the most recently added step so that it only matches nodes
+    /// that are at the given (zero-based) index among their siblings. This is the
+    /// same index that would be found at the end of the node's path (see
+    /// [`current_path`](crate::prelude::TreeIteratorBase::current_path) for more
+    /// details), so for binary trees, 0 is the left child and 1 is the right child.
+    /// The root node is not considered to be the child of any node.
+    ///
+    /// ### Panics
+    /// Panics if no steps have been added to the selector yet.
+    #[must_use]
+    pub fn nth_child(mut self, n: usize) -> Self {
+        self.last_step("nth_child").nth_child = Some(n);
+        self
+    }
+
+    /// Further restricts the most recently added step so that it only matches nodes
+    /// whose parent's value satisfies the given predicate. The root node does not
+    /// have a parent, so it will never match.
+    ///
+    /// ### Panics
+    /// Panics if no steps have been added to the selector yet.
+    #[must_use]
+    pub fn parent_matches<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Value) -> bool + 'f,
+    {
+        self.last_step("parent_matches")
+            .parent_predicates
+            .push(Box::new(f));
+        self
+    }
+
+    /// Checks whether the node at the end of the given ancestors stack matches
+    /// this selector. `ancestors` and `path` are expected in the same format
+    /// as [`TreeContext::ancestors`] and [`TreeContext::path`].
+    pub fn matches(&self, ancestors: &[Value], path: &[usize]) -> bool {
+        if self.steps.is_empty() || ancestors.is_empty() {
+            return false;
+        }
+
+        let (last_step, steps) = self.steps.split_last().unwrap();
+        let node_index = ancestors.len() - 1;
+        if !last_step.matches(node_index, ancestors, path) {
+            return false;
+        }
+
+        // matched[i] records whether the ancestor at index i matches the steps seen
+        // so far, with the most recent step landing on that ancestor. Each step is
+        // evaluated once per ancestor, so descendant steps never backtrack.
+        let mut matched = vec![false; node_index];
+        let mut previous = vec![false; node_index];
+        for (step_index, step) in steps.iter().enumerate() {
+            core::mem::swap(&mut matched, &mut previous);
+            let mut any_before = false;
+            let mut any_matched = false;
+            for index in 0..node_index {
+                let reachable = Self::is_reachable(step, step_index, index, &previous, any_before);
+                any_before |= previous[index];
+                matched[index] = reachable && step.matches(index, ancestors, path);
+                any_matched |= matched[index];
+            }
+
+            if !any_matched {
+                return false;
+            }
+        }
+
+        Self::is_reachable(
+            last_step,
+            steps.len(),
+            node_index,
+            &matched,
+            matched.iter().any(|matched| *matched),
+        )
+    }
+
+    /// Lazily finds every node in the tree that matches this selector. Matches are
+    /// yielded in depth first preorder along with their paths (see
+    /// [`current_path`](crate::prelude::TreeIteratorBase::current_path) for more
+    /// details).
+    pub fn select<'s, 'a, Node>(
+        &'s self,
+        root: &'a Node,
+    ) -> Select<
+        's,
+        'f,
+        Value,
+        Node::BorrowedChildren,
+        BorrowedDFSPreorderIteratorWithContext<'a, Node>,
+    >
+    where
+        Node: BorrowedTreeNode<'a, BorrowedValue = Value>,
+        Value: Clone,
+    {
+        Select::new(self, root.dfs_preorder_iter().attach_context())
+    }
+
+    /// Identical to [`select`](Selector::select) except that it works on binary trees.
+    pub fn select_binary<'s, 'a, Node>(
+        &'s self,
+        root: &'a Node,
+    ) -> Select<
+        's,
+        'f,
+        Value,
+        [Option<&'a Node>; 2],
+        BorrowedBinaryDFSPreorderIteratorWithContext<'a, Node>,
+    >
+    where
+        Node: BorrowedBinaryTreeNode<'a, BorrowedValue = Value>,
+        Value: Clone,
+    {
+        Select::new(self, root.dfs_preorder_iter().attach_context())
+    }
+
+    fn push_step<F>(mut self, combinator: Combinator, f: F) -> Self
+    where
+        F: Fn(&Value) -> bool + 'f,
+    {
+        let mut predicates: Vec<Box<dyn Fn(&Value) -> bool + 'f>> = Vec::new();
+        predicates.push(Box::new(f));
+        self.steps.push(Step {
+            combinator,
+            predicates,
+            parent_predicates: Vec::new(),
+            nth_child: None,
+        });
+        self
+    }
+
+    /// Checks whether the previous step matched in a position that allows the step
+    /// at `step_index` to match the ancestor at `index`. `any_before` is whether the
+    /// previous step matched any ancestor above `index`.
+    fn is_reachable(
+        step: &Step<'f, Value>,
+        step_index: usize,
+        index: usize,
+        matched: &[bool],
+        any_before: bool,
+    ) -> bool {
+        match step.combinator {
+            Combinator::Child => {
+                if step_index == 0 {
+                    index == 0
+                } else {
+                    index > 0 && matched[index - 1]
+                }
+            }
+            Combinator::Descendant => step_index == 0 || any_before,
+        }
+    }
+
+    fn last_step(&mut self, method: &str) -> &mut Step<'f, Value> {
+        match self.steps.last_mut() {
+            Some(step) => step,
+            None => panic!(
+                "Selector::{} must be called after Selector::child or Selector::descendant",
+                method
+            ),
+        }
+    }
+}
+
+/// An iterator over the nodes of a tree that match a [`Selector`]. See
+/// [`Selector::select`] for more details.
+pub struct Select<'s, 'f, Value, Children, Inner>
+where
+    Inner: StreamingIterator<Item = TreeContext<Value, Children>>,
+{
+    selector: &'s Selector<'f, Value>,
+    inner: Inner,
+    children: PhantomData<Children>,
+}
+
+impl<'s, 'f, Value, Children, Inner> Select<'s, 'f, Value, Children, Inner>
+where
+    Inner: StreamingIterator<Item = TreeContext<Value, Children>>,
+{
+    fn new(selector: &'s Selector<'f, Value>, inner: Inner) -> Self {
+        Self {
+            selector,
+            inner,
+            children: PhantomData,
+        }
+    }
+}
+
+impl<Value, Children, Inner> Iterator for Select<'_, '_, Value, Children, Inner>
+where
+    Inner: StreamingIterator<Item = TreeContext<Value, Children>>,
+    Value: Clone,
+{
+    type Item = (Vec<usize>, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(context) = self.inner.next() {
+            if self.selector.matches(context.ancestors(), context.path()) {
+                let value = context
+                    .ancestors()
+                    .last()
+                    .expect("ancestors() is guaranteed to be non-empty")
+                    .clone();
+
+                return Some((context.path().to_vec(), value));
+            }
+        }
+
+        None
+    }
+}
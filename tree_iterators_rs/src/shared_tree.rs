@@ -0,0 +1,186 @@
+use alloc::{collections::BTreeSet, rc::Rc, vec::Vec};
+use core::{cmp::Ordering, iter::Map, slice::Iter};
+
+use crate::prelude::{BorrowedTreeNode, OwnedTreeNode, Tree};
+
+/// A tree where structurally identical subtrees can be shared between
+/// multiple parents, turning the tree into a directed acyclic graph (DAG).
+/// This can greatly reduce the memory used by trees that contain many
+/// repeated subtrees.
+///
+/// A [`SharedTree`] is usually created using [`Tree::compress`] and can be
+/// turned back into a [`Tree`] using [`SharedTree::expand`]. Since it implements
+/// [`BorrowedTreeNode`], all of the borrowed traversal APIs work on it the same
+/// way they work on the expanded [`Tree`]. Shared subtrees are visited once for
+/// every parent that references them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SharedTree<T> {
+    /// This node's value
+    pub value: T,
+    /// The children of the current node. Children may be shared with other nodes.
+    pub children: Vec<Rc<SharedTree<T>>>,
+}
+
+/// Helper type to define the [`SharedTree`]'s Children iterator type.
+pub(crate) type SharedChildren<'a, T> =
+    Map<Iter<'a, Rc<SharedTree<T>>>, fn(&'a Rc<SharedTree<T>>) -> &'a SharedTree<T>>;
+
+impl<T> Tree<T>
+where
+    T: Ord,
+{
+    /// Compresses this tree into a [`SharedTree`] by interning all structurally
+    /// identical subtrees, so that each distinct subtree is only stored in memory once.
+    /// Two subtrees are structurally identical if they have the same shape and equal
+    /// values at every position.
+    ///
+    /// This is done in a single depth first postorder pass over the tree, so it works
+    /// on arbitrarily deep trees.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use tree_iterators_rs::prelude::{BorrowedTreeNode, Tree};
+    ///
+    /// let block = Tree {
+    ///     value: "block",
+    ///     children: vec![Tree {
+    ///         value: "enabled",
+    ///         children: vec![],
+    ///     }],
+    /// };
+    ///
+    /// let tree = Tree {
+    ///     value: "config",
+    ///     children: vec![block.clone(), block],
+    /// };
+    ///
+    /// let compressed = tree.clone().compress();
+    /// assert!(Rc::ptr_eq(&compressed.children[0], &compressed.children[1]));
+    ///
+    /// // all of the traversal APIs still see the full tree.
+    /// assert_eq!(
+    ///     vec!["config", "block", "enabled", "block", "enabled"],
+    ///     compressed.dfs_preorder_iter().copied().collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(tree, compressed.expand());
+    /// ```
+    pub fn compress(self) -> SharedTree<T> {
+        let mut interned: BTreeSet<InternedSubtree<T>> = BTreeSet::new();
+        let root = self.fold(|children: Vec<Rc<SharedTree<T>>>, value| {
+            let candidate = InternedSubtree(Rc::new(SharedTree { value, children }));
+            if let Some(existing) = interned.get(&candidate) {
+                return existing.0.clone();
+            }
+
+            let result = candidate.0.clone();
+            interned.insert(candidate);
+            result
+        });
+
+        // the root can't be structurally identical to any of its own
+        // descendents, so once the interning set is gone nothing else
+        // holds a reference to it.
+        drop(interned);
+        match Rc::try_unwrap(root) {
+            Ok(root) => root,
+            Err(_) => unreachable!("the interning set holds the only other reference to the root"),
+        }
+    }
+}
+
+impl<T> SharedTree<T> {
+    /// Expands this [`SharedTree`] back into a [`Tree`], cloning the values
+    /// of any shared subtrees once for each parent that references them.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::prelude::Tree;
+    ///
+    /// let tree = Tree {
+    ///     value: 0,
+    ///     children: vec![
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![],
+    ///         },
+    ///         Tree {
+    ///             value: 1,
+    ///             children: vec![],
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(tree, tree.clone().compress().expand());
+    /// ```
+    pub fn expand(&self) -> Tree<T>
+    where
+        T: Clone,
+    {
+        self.map_ref(|value| value.clone())
+    }
+}
+
+impl<'a, T> BorrowedTreeNode<'a> for SharedTree<T>
+where
+    T: 'a,
+{
+    type BorrowedValue = &'a T;
+    type BorrowedChildren = SharedChildren<'a, T>;
+
+    /// This method gets the value and children from this node. The other
+    /// methods of this trait assume that the 'Children' list does not contain
+    /// any circular references. If there are, an infinite loop will result.
+    fn get_value_and_children_iter(&'a self) -> (Self::BorrowedValue, Self::BorrowedChildren) {
+        (
+            &self.value,
+            self.children
+                .iter()
+                .map(Rc::as_ref as fn(&'a Rc<SharedTree<T>>) -> &'a SharedTree<T>),
+        )
+    }
+}
+
+/// A wrapper used to intern subtrees during compression. Since all children
+/// of an interned subtree have already been interned, two subtrees are
+/// structurally identical exactly when their values are equal and their
+/// children point to the same allocations.
+struct InternedSubtree<T>(Rc<SharedTree<T>>);
+
+impl<T> InternedSubtree<T> {
+    fn child_pointers(&self) -> impl Iterator<Item = *const SharedTree<T>> + '_ {
+        self.0.children.iter().map(Rc::as_ptr)
+    }
+}
+
+impl<T> PartialEq for InternedSubtree<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for InternedSubtree<T> where T: Ord {}
+
+impl<T> PartialOrd for InternedSubtree<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for InternedSubtree<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .value
+            .cmp(&other.0.value)
+            .then_with(|| self.child_pointers().cmp(other.child_pointers()))
+    }
+}
@@ -0,0 +1,117 @@
+//! Tree traversals don't know how many nodes are left in the tree without
+//! walking it, so the traversal iterators can only report a lower bound from
+//! their [`size_hint`](Iterator::size_hint). Tree nodes that know the size of
+//! their subtree can opt into [`SizedTreeNode`], which lets any traversal
+//! iterator be wrapped in an [`ExactSizeTreeIterator`] before it starts.
+
+use core::iter::FusedIterator;
+
+use crate::prelude::{BinaryTree, BorrowedBinaryTreeNode, BorrowedTreeNode, Tree};
+
+/// A tree node that knows how many nodes are in the tree rooted at it.
+///
+/// Implementing this trait for a node type unlocks the `exact_size()` method
+/// on the pre-, post-, in-order and breadth first iterators for that node
+/// type. Node types that keep a cached count of their descendants can return
+/// it here in constant time. [`Tree`] and [`BinaryTree`] do not cache their
+/// size, so their implementations walk the tree once to count the nodes.
+pub trait SizedTreeNode {
+    /// Gets the number of nodes in the tree rooted at this node, including
+    /// this node.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use tree_iterators_rs::{
+    ///     examples::create_example_tree,
+    ///     prelude::SizedTreeNode
+    /// };
+    ///
+    /// let tree = create_example_tree();
+    /// assert_eq!(11, tree.subtree_size());
+    /// assert_eq!(1, tree.children[0].children[0].subtree_size());
+    /// ```
+    fn subtree_size(&self) -> usize;
+}
+
+impl<T> SizedTreeNode for Tree<T> {
+    fn subtree_size(&self) -> usize {
+        BorrowedTreeNode::size(self)
+    }
+}
+
+impl<T> SizedTreeNode for BinaryTree<T> {
+    fn subtree_size(&self) -> usize {
+        BorrowedBinaryTreeNode::size(self)
+    }
+}
+
+/// An iterator that tracks exactly how many items the wrapped traversal
+/// iterator has left to yield. This is created by calling `exact_size()` on
+/// one of the traversal iterators of a [`SizedTreeNode`].
+#[derive(Clone, Debug)]
+pub struct ExactSizeTreeIterator<Iter> {
+    iter: Iter,
+    remaining: usize,
+}
+
+impl<Iter> ExactSizeTreeIterator<Iter> {
+    pub(crate) fn new(iter: Iter, remaining: usize) -> Self {
+        Self { iter, remaining }
+    }
+}
+
+impl<Iter> Iterator for ExactSizeTreeIterator<Iter>
+where
+    Iter: Iterator,
+{
+    type Item = Iter::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next();
+        if next.is_some() {
+            self.remaining -= 1;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<Iter> ExactSizeIterator for ExactSizeTreeIterator<Iter> where Iter: Iterator {}
+
+impl<Iter> FusedIterator for ExactSizeTreeIterator<Iter> where Iter: FusedIterator {}
+
+macro_rules! exact_size_impl {
+    ($root: ident, $pending: ident) => {
+        #[doc = include_str!("../../doc_files/exact_size.md")]
+        pub fn exact_size(self) -> ExactSizeTreeIterator<Self>
+        where
+            Node: SizedTreeNode,
+        {
+            let remaining = match self.$root.as_ref() {
+                Some(root) if self.$pending.is_empty() => root.subtree_size(),
+                _ => panic!("Attempted to get the exact size of an iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            ExactSizeTreeIterator::new(self, remaining)
+        }
+    };
+    (inorder) => {
+        #[doc = include_str!("../../doc_files/exact_size.md")]
+        pub fn exact_size(self) -> ExactSizeTreeIterator<Self>
+        where
+            Node: SizedTreeNode,
+        {
+            let remaining = match (self.moved, self.right_stack.last()) {
+                (false, Some(Some(root))) => root.subtree_size(),
+                _ => panic!("Attempted to get the exact size of an iterator in the middle of a tree traversal. This is forbidden."),
+            };
+
+            ExactSizeTreeIterator::new(self, remaining)
+        }
+    };
+}
+
+pub(crate) use exact_size_impl;
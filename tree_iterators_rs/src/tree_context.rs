@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::vec::{self, Vec};
 
 #[derive(Clone, Debug)]
 pub struct TreeContext<Value, Children> {
@@ -10,8 +10,124 @@ pub struct TreeContext<Value, Children> {
 
     #[doc = include_str!("../doc_files/tree_context_children.md")]
     pub(crate) children: Option<Children>,
+
+    /// The position of each of the ancestors among its siblings.
+    pub(crate) siblings: Vec<SiblingPosition>,
+
+    /// The zero-based index of the current node in the iterator's traversal order.
+    pub(crate) index: usize,
+}
+
+/// The position of a node among its siblings. Sibling positions ignore
+/// empty child slots, so they don't always line up with the node's path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SiblingPosition {
+    pub(crate) index: usize,
+    pub(crate) count: usize,
+}
+
+impl SiblingPosition {
+    /// The position of the root node, which has no siblings.
+    pub(crate) const ROOT: Self = Self { index: 0, count: 1 };
+
+    /// Gets the position of a binary tree node from the slot it occupies and
+    /// the number of non-empty slots its parent has.
+    pub(crate) fn binary(slot: usize, count: usize) -> Self {
+        Self {
+            index: if slot == 0 {
+                0
+            } else {
+                count.saturating_sub(1)
+            },
+            count,
+        }
+    }
+
+    /// Gets the position of a node in a tree with fixed child slots from the
+    /// number of non-empty slots its parent has and the slots that come after
+    /// the node's own slot.
+    pub(crate) fn slot<T>(count: usize, remaining_slots: &[Option<T>]) -> Self {
+        Self {
+            index: count - 1 - remaining_slots.iter().flatten().count(),
+            count,
+        }
+    }
+}
+
+/// The children of a node that an iterator with context is working through.
+/// The number of children that are left is needed to find the
+/// [`SiblingPosition`] of each child, so children whose iterator can't report
+/// its exact length are buffered up front. The children of every tree type in
+/// this crate report their exact length.
+pub(crate) enum Siblings<Iter>
+where
+    Iter: Iterator,
+{
+    Exact(Iter),
+    Buffered(vec::IntoIter<Iter::Item>),
+}
+
+impl<Iter> Siblings<Iter>
+where
+    Iter: Iterator,
+{
+    pub(crate) fn new<Children>(children: Children) -> Self
+    where
+        Children: IntoIterator<IntoIter = Iter>,
+    {
+        let children = children.into_iter();
+        match children.size_hint() {
+            (lower, Some(upper)) if lower == upper => Self::Exact(children),
+            _ => Self::Buffered(children.collect::<Vec<_>>().into_iter()),
+        }
+    }
+}
+
+impl<Iter> Default for Siblings<Iter>
+where
+    Iter: Iterator,
+{
+    fn default() -> Self {
+        Self::Buffered(Vec::new().into_iter())
+    }
+}
+
+impl<Iter> Clone for Siblings<Iter>
+where
+    Iter: Iterator + Clone,
+    Iter::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Exact(children) => Self::Exact(children.clone()),
+            Self::Buffered(children) => Self::Buffered(children.clone()),
+        }
+    }
+}
+
+impl<Iter> Iterator for Siblings<Iter>
+where
+    Iter: Iterator,
+{
+    type Item = Iter::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Exact(children) => children.next(),
+            Self::Buffered(children) => children.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Exact(children) => children.size_hint(),
+            Self::Buffered(children) => children.size_hint(),
+        }
+    }
 }
 
+impl<Iter> ExactSizeIterator for Siblings<Iter> where Iter: Iterator {}
+
 impl<Value, Children> TreeContext<Value, Children> {
     /// Gets the depth of the current node in the tree. This is zero-based,
     /// so the root node is at depth zero.
@@ -57,6 +173,99 @@ impl<Value, Children> TreeContext<Value, Children> {
         self.children.as_ref().unwrap()
     }
 
+    /// Gets the zero-based index of the current node among its siblings. Empty
+    /// child slots in binary trees are not counted, so a right child without a
+    /// left sibling has a sibling index of 0. The root node always has a sibling
+    /// index of 0, including the roots of each tree in a collection.
+    ///
+    /// Ex. given a tree like the following, the sibling indexes would be as labeled.
+    /// ```text
+    ///        0         <- 0
+    ///       / \
+    ///      1   2       <- 0, 1
+    ///     / \ / \
+    ///    3  4 5  6     <- 0, 1, 0, 1
+    ///           /
+    ///          7       <- 0
+    ///           \
+    ///            8     <- 0
+    /// ```
+    pub fn sibling_index(&self) -> usize {
+        self.siblings[self.depth()].index
+    }
+
+    /// Gets the number of children the current node's parent has, including the
+    /// current node. Empty child slots in binary trees are not counted. The root node
+    /// is treated as having no siblings, so it has a sibling count of 1.
+    pub fn sibling_count(&self) -> usize {
+        self.siblings[self.depth()].count
+    }
+
+    /// Returns true if the current node is the last of its parent's children.
+    /// This is always true for the root node.
+    pub fn is_last_sibling(&self) -> bool {
+        !self.ancestor_has_next_sibling(self.depth())
+    }
+
+    /// Gets the zero-based index of the current node in the order the iterator
+    /// visits nodes, so this is the node's preorder index for the dfs_preorder
+    /// iterators, its breadth first index for the bfs iterators, etc. When iterating
+    /// over a collection of trees, the index keeps counting across trees.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns true if the ancestor at the given depth has any siblings after it.
+    /// A depth of [`depth`](TreeContext::depth) refers to the current node. This
+    /// is useful for drawing the guide lines of a tree, since a vertical line needs
+    /// to continue past a node at every depth where the ancestor still has siblings
+    /// left to visit.
+    ///
+    /// ### Panics
+    /// Panics if `depth` is greater than the depth of the current node.
+    ///
+    /// ### Example Usage
+    /// ```rust
+    /// use streaming_iterator::StreamingIterator;
+    /// use tree_iterators_rs::{examples::create_example_tree, prelude::*};
+    ///
+    /// let tree = create_example_tree();
+    /// let mut lines = Vec::new();
+    /// let mut iter = tree.dfs_preorder_iter().attach_context();
+    /// while let Some(context) = iter.next() {
+    ///     let mut line = String::new();
+    ///     for depth in 1..context.depth() {
+    ///         line += if context.ancestor_has_next_sibling(depth) { "│  " } else { "   " };
+    ///     }
+    ///     if context.depth() > 0 {
+    ///         line += if context.is_last_sibling() { "└─ " } else { "├─ " };
+    ///     }
+    ///     line += &context.ancestors()[context.depth()].to_string();
+    ///     lines.push(line);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "0",
+    ///         "├─ 1",
+    ///         "│  ├─ 3",
+    ///         "│  └─ 4",
+    ///         "└─ 2",
+    ///         "   ├─ 5",
+    ///         "   └─ 6",
+    ///         "      └─ 7",
+    ///         "         └─ 8",
+    ///         "            └─ 9",
+    ///         "               └─ 10",
+    ///     ],
+    ///     lines
+    /// );
+    /// ```
+    pub fn ancestor_has_next_sibling(&self, depth: usize) -> bool {
+        let position = self.siblings[depth];
+        position.index + 1 < position.count
+    }
+
     #[doc = include_str!("../doc_files/tree_context_children.md")]
     pub fn children_mut(&mut self) -> &mut Children {
         // children should always be populated unless the iterator is in the middle of its .next() method.
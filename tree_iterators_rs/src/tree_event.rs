@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+/// An event produced by the iterators returned from `events()`,
+/// `events_iter_mut()` and `events_iter()`. Every node in the tree produces
+/// exactly one `Enter` event before any of its descendants are visited and
+/// exactly one `Exit` event after all of them have been visited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TreeEvent<Value> {
+    /// The traversal has reached this node, but none of its descendants have
+    /// been visited yet.
+    Enter(Value),
+    /// The traversal has visited all of the descendants of this node and is
+    /// about to move back up to its parent.
+    Exit(Value),
+}
+
+impl<Value> TreeEvent<Value> {
+    /// Returns true if this event marks the start of a node's subtree.
+    pub fn is_enter(&self) -> bool {
+        matches!(self, TreeEvent::Enter(_))
+    }
+
+    /// Returns true if this event marks the end of a node's subtree.
+    pub fn is_exit(&self) -> bool {
+        matches!(self, TreeEvent::Exit(_))
+    }
+
+    /// Gets a reference to the value of the node this event belongs to.
+    pub fn value(&self) -> &Value {
+        match self {
+            TreeEvent::Enter(value) | TreeEvent::Exit(value) => value,
+        }
+    }
+
+    /// Gets a mutable reference to the value of the node this event belongs to.
+    pub fn value_mut(&mut self) -> &mut Value {
+        match self {
+            TreeEvent::Enter(value) | TreeEvent::Exit(value) => value,
+        }
+    }
+
+    /// Consumes the event, returning the value of the node it belongs to.
+    pub fn into_value(self) -> Value {
+        match self {
+            TreeEvent::Enter(value) | TreeEvent::Exit(value) => value,
+        }
+    }
+}
+
+/// The item yielded by the event iterators. It pairs the current
+/// [`TreeEvent`] with the position of the node it belongs to.
+#[derive(Clone, Debug)]
+pub struct TreeEventContext<Value> {
+    pub(crate) path: Vec<usize>,
+    pub(crate) event: Option<TreeEvent<Value>>,
+}
+
+impl<Value> TreeEventContext<Value> {
+    pub(crate) fn new() -> Self {
+        Self {
+            path: Vec::new(),
+            event: None,
+        }
+    }
+
+    /// Gets the current event.
+    pub fn event(&self) -> &TreeEvent<Value> {
+        self.event.as_ref().unwrap()
+    }
+
+    /// Gets a mutable reference to the current event. Changes made to the
+    /// value during a node's `Enter` event are kept for its `Exit` event.
+    pub fn event_mut(&mut self) -> &mut TreeEvent<Value> {
+        self.event.as_mut().unwrap()
+    }
+
+    #[doc = include_str!("../doc_files/events_path.md")]
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Gets the depth of the node the current event belongs to. This is
+    /// zero-based, so the events of the root node are at depth zero.
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+}
@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use super::{BinaryTreeIterator, TreeIterator, TreeIteratorBase};
+use super::{BinaryTreeIterator, KaryTreeIterator, TreeIterator, TreeIteratorBase};
 
 pub struct Map<Value, Children, InnerIter, F, Output>
 where
@@ -70,3 +70,11 @@ where
     F: FnMut(Value) -> Output,
 {
 }
+
+impl<Value, Children, InnerIter, F, Output, const N: usize> KaryTreeIterator<Output, (), N>
+    for Map<Value, Children, InnerIter, F, Output>
+where
+    InnerIter: KaryTreeIterator<Value, Children, N>,
+    F: FnMut(Value) -> Output,
+{
+}
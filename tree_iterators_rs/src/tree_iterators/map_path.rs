@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use super::{BinaryTreeIterator, TreeIterator, TreeIteratorBase};
+use super::{BinaryTreeIterator, KaryTreeIterator, TreeIterator, TreeIteratorBase};
 
 pub struct MapPath<Value, Children, InnerIter, F, Output>
 where
@@ -37,11 +37,8 @@ where
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // SAFETY: self.f cannot borrow path mutably since path is our internal state.
-        let path = self.current_path() as *const [usize];
-        self.inner
-            .next()
-            .map(|value| (self.f)(unsafe { &*path }, value))
+        let value = self.inner.next()?;
+        Some((self.f)(self.inner.current_path(), value))
     }
 }
 
@@ -75,3 +72,11 @@ where
     F: FnMut(&[usize], Value) -> Output,
 {
 }
+
+impl<Value, Children, InnerIter, F, Output, const N: usize> KaryTreeIterator<Output, (), N>
+    for MapPath<Value, Children, InnerIter, F, Output>
+where
+    InnerIter: KaryTreeIterator<Value, Children, N>,
+    F: FnMut(&[usize], Value) -> Output,
+{
+}
@@ -1,6 +1,6 @@
 use alloc::{boxed::Box, vec::Vec};
 
-use crate::prelude::{BinaryTree, Tree};
+use crate::prelude::{BinaryTree, KaryTree, Tree};
 
 mod map;
 mod map_path;
@@ -715,3 +715,151 @@ where
             .map(|root| f(folded_so_far.pop().unwrap_or_default(), &[], root))
     }
 }
+
+pub trait KaryTreeIterator<Value, Children, const N: usize>:
+    TreeIteratorBase<Value, Children>
+where
+    Self: Sized,
+{
+    /// Identical to [`BinaryTreeIterator::prune`] except that it works on trees
+    /// with N child slots. Empty slots are preserved, so pruning a node never
+    /// changes the paths of its siblings.
+    ///
+    /// ### Basic usage:
+    /// ```rust
+    /// use tree_iterators_rs::prelude::{KaryTree, KaryTreeIterator, OwnedKaryTreeNode};
+    ///
+    /// let tree = KaryTree::<usize, 3> {
+    ///     value: 0,
+    ///     children: [
+    ///         Some(Box::new(KaryTree::new(1))),
+    ///         None,
+    ///         Some(Box::new(KaryTree::new(2))),
+    ///     ],
+    /// };
+    ///
+    /// let result = tree.into_pipeline()
+    ///     .prune(|value| *value == 1)
+    ///     .collect_tree();
+    ///
+    /// assert_eq!(
+    ///     Some(KaryTree {
+    ///         value: 0,
+    ///         children: [None, None, Some(Box::new(KaryTree::new(2)))],
+    ///     }),
+    ///     result
+    /// );
+    /// ```
+    #[must_use]
+    fn prune<F>(self, f: F) -> BinaryPrune<Value, Children, Self, F>
+    where
+        F: FnMut(&Value) -> bool,
+    {
+        BinaryPrune::new(self, f)
+    }
+
+    /// Identical to [`prune`](KaryTreeIterator::prune) except that the closure is passed
+    /// an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    #[must_use]
+    fn prune_path<F>(self, f: F) -> BinaryPrunePath<Value, Children, Self, F>
+    where
+        F: FnMut(&[usize], &Value) -> bool,
+    {
+        BinaryPrunePath::new(self, f)
+    }
+
+    /// Collects the current KaryTreeIterator back into a [`KaryTree`], placing
+    /// each node in the child slot given by the last segment of its path.
+    ///
+    /// If the root node was pruned, this returns [`None`].
+    fn collect_tree(mut self) -> Option<KaryTree<Value, N>> {
+        let mut keeping_stack: Vec<(usize, KaryTree<Value, N>)> = Vec::new();
+        let mut next = self.next();
+        while let Some(item) = next {
+            while keeping_stack.len() > self.current_depth() {
+                let popped = keeping_stack
+                    .pop()
+                    .expect("the keeping stack to always have an item");
+
+                let last_keeping_children = keeping_stack
+                    .last_mut()
+                    .expect("there to always be an item in the keeping stack.");
+
+                last_keeping_children.1.children[popped.0] = Some(Box::new(popped.1));
+            }
+
+            let index = self.current_path().last().copied().unwrap_or_default();
+            keeping_stack.push((index, KaryTree::new(item)));
+            next = self.next();
+        }
+
+        while keeping_stack.len() > 1 {
+            let popped = keeping_stack
+                .pop()
+                .expect("the keeping stack to always have an item");
+
+            let last_keeping_children = keeping_stack
+                .last_mut()
+                .expect("there to always be an item in the keeping stack.");
+
+            last_keeping_children.1.children[popped.0] = Some(Box::new(popped.1));
+        }
+
+        keeping_stack.pop().map(|tuple| tuple.1)
+    }
+
+    /// Identical to [`BinaryTreeIterator::fold_tree`] except that the closure is
+    /// passed the folded results of all N child slots.
+    fn fold_tree<F, Output>(self, mut f: F) -> Option<Output>
+    where
+        F: FnMut([Option<Output>; N], Value) -> Output,
+    {
+        self.fold_path(|acc, _, value| f(acc, value))
+    }
+
+    /// Identical to [`fold_tree`](KaryTreeIterator::fold_tree) except that the closure
+    /// is passed an additional parameter: the path of the current node in the tree (see
+    /// [`current_path`](TreeIteratorBase::current_path) for more details).
+    fn fold_path<F, Output>(mut self, mut f: F) -> Option<Output>
+    where
+        F: FnMut([Option<Output>; N], &[usize], Value) -> Output,
+    {
+        let mut inversion_stack = Vec::new();
+        let mut folded_so_far: Vec<[Option<Output>; N]> = Vec::new();
+        let mut paths = Vec::new();
+        while let Some(item) = self.next() {
+            while folded_so_far.len() > self.current_depth() {
+                let items = folded_so_far.pop().unwrap();
+                let value_to_fold = inversion_stack.pop().unwrap();
+                let folded = f(items, &paths, value_to_fold);
+                let path_segment = paths.pop().unwrap();
+                folded_so_far.last_mut().unwrap()[path_segment] = Some(folded);
+            }
+
+            inversion_stack.push(item);
+            folded_so_far.push(core::array::from_fn(|_| None));
+            if paths.len() < self.current_depth() {
+                paths.push(self.current_path().last().copied().unwrap());
+            }
+        }
+
+        while folded_so_far.len() > 1 {
+            let items = folded_so_far.pop().unwrap();
+            let value_to_fold = inversion_stack.pop().unwrap();
+            let folded = f(items, &paths, value_to_fold);
+            let path_segment = paths.pop().unwrap();
+            folded_so_far.last_mut().unwrap()[path_segment] = Some(folded);
+        }
+
+        inversion_stack.pop().map(|root| {
+            f(
+                folded_so_far
+                    .pop()
+                    .unwrap_or_else(|| core::array::from_fn(|_| None)),
+                &[],
+                root,
+            )
+        })
+    }
+}
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use alloc::vec::Vec;
 
-use super::{BinaryTreeIterator, TreeIterator, TreeIteratorBase};
+use super::{BinaryTreeIterator, KaryTreeIterator, TreeIterator, TreeIteratorBase};
 
 pub struct Prune<Value, Children, InnerIter, F>
 where
@@ -122,7 +122,7 @@ where
 
 impl<Value, Children, InnerIter, F> BinaryPrune<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&Value) -> bool,
 {
     pub(crate) fn new(iter: InnerIter, f: F) -> Self {
@@ -137,7 +137,7 @@ where
 
 impl<Value, Children, InnerIter, F> Iterator for BinaryPrune<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&Value) -> bool,
 {
     type Item = Value;
@@ -159,7 +159,7 @@ where
 impl<Value, Children, InnerIter, F> TreeIteratorBase<Value, Children>
     for BinaryPrune<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&Value) -> bool,
 {
     fn current_path(&self) -> &[usize] {
@@ -178,3 +178,11 @@ where
     F: FnMut(&Value) -> bool,
 {
 }
+
+impl<Value, Children, InnerIter, F, const N: usize> KaryTreeIterator<Value, Children, N>
+    for BinaryPrune<Value, Children, InnerIter, F>
+where
+    InnerIter: KaryTreeIterator<Value, Children, N>,
+    F: FnMut(&Value) -> bool,
+{
+}
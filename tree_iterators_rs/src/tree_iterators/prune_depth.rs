@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use super::{BinaryTreeIterator, TreeIterator, TreeIteratorBase};
+use super::{BinaryTreeIterator, KaryTreeIterator, TreeIterator, TreeIteratorBase};
 
 pub struct PruneDepth<Value, Children, Inner>
 where
@@ -59,3 +59,10 @@ where
     Inner: BinaryTreeIterator<Value, Children>,
 {
 }
+
+impl<Value, Children, Inner, const N: usize> KaryTreeIterator<Value, Children, N>
+    for PruneDepth<Value, Children, Inner>
+where
+    Inner: KaryTreeIterator<Value, Children, N>,
+{
+}
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use alloc::vec::Vec;
 
-use super::{BinaryTreeIterator, TreeIterator, TreeIteratorBase};
+use super::{BinaryTreeIterator, KaryTreeIterator, TreeIterator, TreeIteratorBase};
 
 pub struct PrunePath<Value, Children, InnerIter, F>
 where
@@ -111,7 +111,7 @@ where
 
 pub struct BinaryPrunePath<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&[usize], &Value) -> bool,
 {
     phantom1: PhantomData<Value>,
@@ -122,7 +122,7 @@ where
 
 impl<Value, Children, InnerIter, F> BinaryPrunePath<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&[usize], &Value) -> bool,
 {
     pub(crate) fn new(iter: InnerIter, f: F) -> Self {
@@ -137,7 +137,7 @@ where
 
 impl<Value, Children, InnerIter, F> Iterator for BinaryPrunePath<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&[usize], &Value) -> bool,
 {
     type Item = Value;
@@ -160,7 +160,7 @@ where
 impl<Value, Children, InnerIter, F> TreeIteratorBase<Value, Children>
     for BinaryPrunePath<Value, Children, InnerIter, F>
 where
-    InnerIter: BinaryTreeIterator<Value, Children>,
+    InnerIter: TreeIteratorBase<Value, Children>,
     F: FnMut(&[usize], &Value) -> bool,
 {
     fn current_path(&self) -> &[usize] {
@@ -179,3 +179,11 @@ where
     F: FnMut(&[usize], &Value) -> bool,
 {
 }
+
+impl<Value, Children, InnerIter, F, const N: usize> KaryTreeIterator<Value, Children, N>
+    for BinaryPrunePath<Value, Children, InnerIter, F>
+where
+    InnerIter: KaryTreeIterator<Value, Children, N>,
+    F: FnMut(&[usize], &Value) -> bool,
+{
+}
@@ -0,0 +1,331 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use streaming_iterator::StreamingIterator;
+
+use crate::{
+    dfs_preorder_iterators::borrow::BorrowedDFSPreorderIteratorWithAncestors,
+    prelude::{BorrowedTreeNode, Tree},
+};
+
+/// The [`Tree`] that a [`Trie`] is stored in. Each node's value holds the key
+/// part leading to it (or [`None`] for the root) and the value stored for the
+/// key that ends at that node, if any.
+pub type TrieNode<K, V> = Tree<(Option<K>, Option<V>)>;
+
+/// A prefix tree mapping sequences of key parts to values.
+///
+/// The trie is stored in a [`Tree`] (see [`TrieNode`]) whose children are kept
+/// sorted by their key part, so all of the borrowed tree APIs can be used
+/// through [`root`](Trie::root). Calling
+/// [`attach_ancestors`](crate::dfs_preorder_iterators::borrow::BorrowedDFSPreorderIterator::attach_ancestors)
+/// on any of its traversals gives the key of each node.
+///
+/// ### Example Usage
+/// ```rust
+/// use streaming_iterator::StreamingIterator;
+/// use tree_iterators_rs::prelude::{BorrowedTreeNode, Trie};
+///
+/// let mut trie = Trie::new();
+/// for (i, word) in ["tea", "ten", "to", "inn"].into_iter().enumerate() {
+///     trie.insert(word.chars(), i);
+/// }
+///
+/// assert_eq!(Some(&1), trie.get("ten".chars()));
+/// assert_eq!(None, trie.get("te".chars()));
+/// assert_eq!(Some((3, &0)), trie.longest_prefix_match("teapot".chars()));
+/// assert_eq!(
+///     vec![("tea".to_string(), &0), ("ten".to_string(), &1)],
+///     trie.iter_prefix("te".chars())
+///         .map(|(key, value)| (key.into_iter().collect::<String>(), value))
+///         .collect::<Vec<_>>()
+/// );
+///
+/// // the ancestors of each node spell out its key.
+/// let mut words = Vec::new();
+/// let mut iter = trie.root().dfs_preorder_iter().attach_ancestors().leaves();
+/// while let Some(ancestors) = iter.next() {
+///     words.push(
+///         ancestors
+///             .iter()
+///             .filter_map(|(part, _)| *part)
+///             .collect::<String>(),
+///     );
+/// }
+/// assert_eq!(vec!["inn", "tea", "ten", "to"], words);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trie<K, V> {
+    root: TrieNode<K, V>,
+    len: usize,
+}
+
+impl<K, V> Trie<K, V> {
+    /// Creates an empty [`Trie`].
+    pub fn new() -> Self {
+        Self {
+            root: Tree {
+                value: (None, None),
+                children: Vec::new(),
+            },
+            len: 0,
+        }
+    }
+
+    /// Returns the number of keys in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the trie contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the root node of the trie, which represents the empty key.
+    pub fn root(&self) -> &TrieNode<K, V> {
+        &self.root
+    }
+}
+
+impl<K, V> Trie<K, V>
+where
+    K: Ord,
+{
+    /// Inserts a value for `key` into the trie. If the trie already had a
+    /// value for the key, it is replaced and the old value is returned.
+    pub fn insert<I>(&mut self, key: I, value: V) -> Option<V>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut node = &mut self.root;
+        for part in key {
+            let index = match find_child(node, &part) {
+                Ok(index) => index,
+                Err(index) => {
+                    node.children.insert(
+                        index,
+                        Tree {
+                            value: (Some(part), None),
+                            children: Vec::new(),
+                        },
+                    );
+                    index
+                }
+            };
+            node = &mut node.children[index];
+        }
+
+        let old = node.value.1.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Gets a reference to the value stored for `key`.
+    pub fn get<I>(&self, key: I) -> Option<&V>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        self.find(key)?.value.1.as_ref()
+    }
+
+    /// Returns true if the trie contains a value for `key`.
+    pub fn contains_key<I>(&self, key: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Removes the value stored for `key` from the trie and returns it. Any
+    /// nodes that no longer lead to a value are removed along with it.
+    pub fn remove<I>(&mut self, key: I) -> Option<V>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        let mut path = Vec::new();
+        // the depth of the deepest node that must be kept if the node for
+        // the key is removed.
+        let mut keep_depth = 0;
+        let mut node = &self.root;
+        for part in key {
+            if node.value.1.is_some() || node.children.len() > 1 {
+                keep_depth = path.len();
+            }
+
+            let index = find_child(node, part.borrow()).ok()?;
+            path.push(index);
+            node = &node.children[index];
+        }
+
+        node.value.1.as_ref()?;
+        let prune = node.children.is_empty() && !path.is_empty();
+
+        let mut node = &mut self.root;
+        for index in path.iter().copied() {
+            node = &mut node.children[index];
+        }
+        let removed = node.value.1.take();
+        self.len -= 1;
+
+        if prune {
+            let mut node = &mut self.root;
+            for index in path[..keep_depth].iter().copied() {
+                node = &mut node.children[index];
+            }
+            node.children.remove(path[keep_depth]);
+        }
+
+        removed
+    }
+
+    /// Finds the longest prefix of `key` that has a value in the trie,
+    /// returning the length of the prefix along with its value.
+    pub fn longest_prefix_match<I>(&self, key: I) -> Option<(usize, &V)>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        let mut node = &self.root;
+        let mut longest = node.value.1.as_ref().map(|value| (0, value));
+        for (i, part) in key.into_iter().enumerate() {
+            match find_child(node, part.borrow()) {
+                Ok(index) => node = &node.children[index],
+                Err(_) => break,
+            }
+
+            if let Some(value) = &node.value.1 {
+                longest = Some((i + 1, value));
+            }
+        }
+        longest
+    }
+
+    fn find<I>(&self, key: I) -> Option<&TrieNode<K, V>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        let mut node = &self.root;
+        for part in key {
+            let index = find_child(node, part.borrow()).ok()?;
+            node = &node.children[index];
+        }
+        Some(node)
+    }
+}
+
+impl<K, V> Trie<K, V>
+where
+    K: Ord + Clone,
+{
+    /// Creates an iterator over every key in the trie that starts with
+    /// `prefix` and its value, in key order.
+    pub fn iter_prefix<I>(&self, prefix: I) -> TrieIter<'_, K, V>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        let prefix = prefix
+            .into_iter()
+            .map(|part| part.borrow().clone())
+            .collect::<Vec<_>>();
+        let inner = self
+            .find(prefix.iter())
+            .map(|node| node.dfs_preorder_iter().attach_ancestors());
+        TrieIter { prefix, inner }
+    }
+
+    /// Creates an iterator over every key in the trie and its value, in key
+    /// order.
+    pub fn iter(&self) -> TrieIter<'_, K, V> {
+        self.iter_prefix(core::iter::empty::<K>())
+    }
+
+    /// Creates an iterator over every key in the trie, in key order.
+    pub fn keys(&self) -> impl Iterator<Item = Vec<K>> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> Default for Trie<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, I> FromIterator<(I, V)> for Trie<K, V>
+where
+    K: Ord,
+    I: IntoIterator<Item = K>,
+{
+    fn from_iter<T: IntoIterator<Item = (I, V)>>(iter: T) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+impl<K, V, I> Extend<(I, V)> for Trie<K, V>
+where
+    K: Ord,
+    I: IntoIterator<Item = K>,
+{
+    fn extend<T: IntoIterator<Item = (I, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An iterator over the keys and values of a [`Trie`]. See
+/// [`Trie::iter_prefix`].
+pub struct TrieIter<'a, K, V> {
+    prefix: Vec<K>,
+    inner: Option<BorrowedDFSPreorderIteratorWithAncestors<'a, TrieNode<K, V>>>,
+}
+
+impl<'a, K, V> Iterator for TrieIter<'a, K, V>
+where
+    K: Clone,
+{
+    type Item = (Vec<K>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ancestors = self.inner.as_mut()?.next()?;
+            let (_, value) = *ancestors.last()?;
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+
+            // the first ancestor is the node for the prefix itself.
+            let mut key = self.prefix.clone();
+            key.extend(ancestors[1..].iter().map(|(part, _)| {
+                part.clone()
+                    .expect("every node other than the root to have a key part")
+            }));
+            return Some((key, value));
+        }
+    }
+}
+
+fn find_child<K, V>(node: &TrieNode<K, V>, part: &K) -> Result<usize, usize>
+where
+    K: Ord,
+{
+    node.children.binary_search_by(|child| {
+        child
+            .value
+            .0
+            .as_ref()
+            .expect("every node other than the root to have a key part")
+            .cmp(part)
+    })
+}